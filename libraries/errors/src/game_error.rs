@@ -48,4 +48,7 @@ pub enum GameError {
 
     #[error("Canister call failed: {0}")]
     CanisterCallFailed(String),
+
+    #[error("chips not conserved: expected {expected}, found {actual}")]
+    ChipsNotConserved { expected: u64, actual: u64 },
 }
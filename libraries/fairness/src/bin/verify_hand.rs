@@ -0,0 +1,48 @@
+//! Verifies the shuffle of a hand from its exported JSON bundle.
+//!
+//! Usage: `verify_hand <bundle.json>`
+//!
+//! Exits with status 1 if anything in the bundle does not match the shuffle.
+
+use std::process::ExitCode;
+
+use fairness::{bundle::FairnessBundle, verify::verify_bundle};
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("Usage: verify_hand <bundle.json>");
+        return ExitCode::from(2);
+    };
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Could not read {}: {}", path, e);
+            return ExitCode::from(2);
+        }
+    };
+    let bundle = match FairnessBundle::from_json(&json) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            eprintln!("Could not parse {}: {}", path, e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let discrepancies = verify_bundle(&bundle);
+    if discrepancies.is_empty() {
+        println!(
+            "Round {} verified: deck hash {}",
+            bundle.rng_metadata.round_id, bundle.rng_metadata.deck_hash
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    println!(
+        "Round {} failed verification:",
+        bundle.rng_metadata.round_id
+    );
+    for discrepancy in discrepancies {
+        println!("- {}", discrepancy);
+    }
+    ExitCode::FAILURE
+}
@@ -0,0 +1,43 @@
+use poker_core::{Card, GameType};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use crate::{
+    merkle::CardProof,
+    types::{CardProvenance, RngMetadata},
+};
+
+/// Everything needed to verify a hand offline, as exported from a table
+/// once the hand is over.
+///
+/// Tables that keep mucked cards private hide the random bytes, so only the
+/// card proofs of their bundles can be checked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FairnessBundle {
+    /// The game type of the table, which decides the deck the hand is dealt from.
+    pub game_type: GameType,
+    /// The RNG metadata of the round, from `get_rng_metadata`.
+    pub rng_metadata: RngMetadata,
+    /// The provenance of the round's cards, from `get_card_provenance_by_round_id`.
+    pub card_provenance: Vec<CardProvenance>,
+    /// The cards the hand log shows were dealt, if they should be checked too.
+    pub hand_log: Option<HandLog>,
+    /// Proofs of single cards against the deck's Merkle root, from `get_card_proofs`.
+    pub card_proofs: Option<Vec<CardProof>>,
+}
+
+/// The cards dealt in a hand.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HandLog {
+    /// The hole cards of every player, in the order they were dealt.
+    pub hole_cards: Vec<(WalletPrincipalId, Vec<Card>)>,
+    /// The community cards of every board, in the order they were dealt.
+    pub community_cards: Vec<Card>,
+}
+
+impl FairnessBundle {
+    /// Reads a bundle from its JSON export.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
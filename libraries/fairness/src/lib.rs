@@ -0,0 +1,18 @@
+//! Offline verification of the shuffles table canisters deal from.
+//!
+//! A table publishes the random bytes and seeds of every round, the hash of the
+//! resulting deck and the provenance of each card. This crate recomputes the
+//! deck from them without calling the canister, so players and auditors can
+//! check a hand on their own machine.
+//!
+//! Tables that keep mucked cards private never reveal the deck. Their cards are
+//! proven one by one against the Merkle root the round committed to instead.
+
+pub mod bundle;
+pub mod merkle;
+pub mod shuffle;
+pub mod types;
+pub mod verify;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,164 @@
+use candid::CandidType;
+use poker_core::Card;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Prefixes that keep a leaf from passing for an internal node and vice versa.
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// A sibling hash on the way from a card up to the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, CandidType)]
+pub struct MerkleStep {
+    /// The hash of the sibling node.
+    pub sibling: Vec<u8>,
+    /// Whether the sibling is the left child.
+    pub is_left: bool,
+}
+
+/// Proof that a card sat at a position of the deck a round committed to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, CandidType)]
+pub struct CardProof {
+    pub round_id: u64,
+    pub card: Card,
+    /// The position of the card in the shuffled deck.
+    pub shuffled_position: u8,
+    /// The salt of the card's leaf.
+    pub salt: Vec<u8>,
+    /// The sibling hashes from the card's leaf up to the root.
+    pub path: Vec<MerkleStep>,
+}
+
+impl CardProof {
+    /// Whether the proof leads from the card up to `merkle_root`.
+    pub fn verify(&self, merkle_root: &str) -> bool {
+        let mut hash = card_leaf_hash(&self.salt, self.shuffled_position, &self.card);
+        for step in &self.path {
+            let Ok(sibling) = <[u8; 32]>::try_from(step.sibling.as_slice()) else {
+                return false;
+            };
+            hash = if step.is_left {
+                node_hash(&sibling, &hash)
+            } else {
+                node_hash(&hash, &sibling)
+            };
+        }
+        to_hex(&hash) == merkle_root
+    }
+}
+
+/// The Merkle tree a shuffled deck is committed to.
+///
+/// Every leaf hashes a card with its shuffled position and a salt of its own, so
+/// proving one card says nothing about the others. The salts are derived from the
+/// seed the deck was shuffled with, which keeps them secret for as long as the deck.
+pub struct DeckCommitment {
+    round_id: u64,
+    cards: Vec<Card>,
+    salts: Vec<[u8; 32]>,
+    /// The levels of the tree, from the leaves up to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl DeckCommitment {
+    /// Builds the tree over the `cards` of a deck shuffled with `shuffle_seed`.
+    ///
+    /// A node without a sibling is carried up to the next level as it is.
+    pub fn new(round_id: u64, shuffle_seed: &[u8], cards: &[Card]) -> Self {
+        let salts: Vec<[u8; 32]> = (0..cards.len())
+            .map(|position| card_salt(shuffle_seed, position as u8))
+            .collect();
+        let leaves = cards
+            .iter()
+            .zip(&salts)
+            .enumerate()
+            .map(|(position, (card, salt))| card_leaf_hash(salt, position as u8, card))
+            .collect();
+
+        let mut levels: Vec<Vec<[u8; 32]>> = vec![leaves];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next_level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| {
+                    pair.get(1)
+                        .map_or(pair[0], |right| node_hash(&pair[0], right))
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        DeckCommitment {
+            round_id,
+            cards: cards.to_vec(),
+            salts,
+            levels,
+        }
+    }
+
+    /// The hex encoded root published before the deck is dealt.
+    pub fn root(&self) -> String {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .map_or_else(String::new, |root| to_hex(root))
+    }
+
+    /// Proves the card at `shuffled_position`, or `None` if the deck is shorter.
+    pub fn prove(&self, shuffled_position: u8) -> Option<CardProof> {
+        let card = *self.cards.get(shuffled_position as usize)?;
+        let mut index = shuffled_position as usize;
+        let mut path = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = index ^ 1;
+            if let Some(sibling) = level.get(sibling_index) {
+                path.push(MerkleStep {
+                    sibling: sibling.to_vec(),
+                    is_left: sibling_index < index,
+                });
+            }
+            index /= 2;
+        }
+
+        Some(CardProof {
+            round_id: self.round_id,
+            card,
+            shuffled_position,
+            salt: self.salts[shuffled_position as usize].to_vec(),
+            path,
+        })
+    }
+}
+
+/// Derives the salt of the card at `shuffled_position` from the shuffle seed.
+///
+/// Hash format: SHA-256("card salt" || shuffle_seed || shuffled_position)
+fn card_salt(shuffle_seed: &[u8], shuffled_position: u8) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"card salt");
+    hasher.update(shuffle_seed);
+    hasher.update([shuffled_position]);
+    hasher.finalize().into()
+}
+
+/// Hash format: SHA-256(0x00 || salt || shuffled_position || card)
+fn card_leaf_hash(salt: &[u8], shuffled_position: u8, card: &Card) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(salt);
+    hasher.update([shuffled_position]);
+    hasher.update(format!("{:?}:{:?}", card.value, card.suit).as_bytes());
+    hasher.finalize().into()
+}
+
+/// Hash format: SHA-256(0x01 || left || right)
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
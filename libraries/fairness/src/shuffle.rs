@@ -0,0 +1,156 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use poker_core::{Card, FlatDeck, GameType};
+use sha2::{Digest, Sha256};
+
+use crate::types::{CardProvenance, PlayerSeed, RngMetadata};
+
+/// Reorders the random bytes with a hash of `seed` before they seed the shuffle.
+///
+/// The canister runs on wasm32, where `usize` is 32 bits wide, so both the index
+/// that is hashed and the hash it is reduced from are cut to a `u32`. That way the
+/// bytes come out the same on 64-bit machines. The hasher is the standard
+/// library's `DefaultHasher`, so verify with the Rust toolchain the table was built with.
+pub fn reshuffle_bytes_hash(bytes: &mut [u8], seed: u64) {
+    if bytes.len() <= 1 {
+        return;
+    }
+
+    // Create indices to shuffle
+    let mut indices: Vec<usize> = (0..bytes.len()).collect();
+
+    // Shuffle indices using hash-based approach
+    for i in (1..indices.len()).rev() {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        (i as u32).hash(&mut hasher);
+        let hash = hasher.finish();
+        let j = (hash as u32 as usize) % (i + 1);
+        indices.swap(i, j);
+    }
+
+    // Apply the shuffle to the original bytes
+    let original = bytes.to_vec();
+    for (new_pos, &old_pos) in indices.iter().enumerate() {
+        bytes[new_pos] = original[old_pos];
+    }
+}
+
+/// The commitment to the canister's randomness for a shuffle.
+///
+/// Hash format: SHA-256(random_bytes || time_seed as big-endian u64)
+pub fn shuffle_commitment(random_bytes: &[u8], time_seed: u64) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(random_bytes);
+    hasher.update(time_seed.to_be_bytes());
+    hasher.finalize().to_vec()
+}
+
+/// Mixes the revealed player seeds into the canister's random bytes.
+///
+/// Seeds are hashed in the order given, each prefixed with the player and its
+/// length. Players who committed but did not reveal contribute nothing, so the
+/// deck does not depend on whether they reveal.
+///
+/// Returns the 32 byte seed the deck is shuffled with.
+pub fn combine_shuffle_seeds(random_bytes: &[u8], player_seeds: &[PlayerSeed]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(random_bytes);
+    for player_seed in player_seeds {
+        let Some(seed) = &player_seed.seed else {
+            continue;
+        };
+        let principal = player_seed.user_principal.0.as_slice();
+        hasher.update([principal.len() as u8]);
+        hasher.update(principal);
+        hasher.update((seed.len() as u32).to_be_bytes());
+        hasher.update(seed);
+    }
+    hasher.finalize().to_vec()
+}
+
+/// Rebuilds the seed a round's deck was shuffled with out of its RNG metadata.
+pub fn recreate_shuffle_seed(rng_metadata: &RngMetadata) -> Vec<u8> {
+    let mut shuffled_bytes = rng_metadata.raw_random_bytes.clone();
+    reshuffle_bytes_hash(&mut shuffled_bytes, rng_metadata.time_seed);
+    if let Some(player_seeds) = &rng_metadata.player_seeds {
+        shuffled_bytes = combine_shuffle_seeds(&shuffled_bytes, player_seeds);
+    }
+    shuffled_bytes
+}
+
+/// Rebuilds the deck a round was dealt from out of its RNG metadata.
+pub fn recreate_deck(game_type: &GameType, rng_metadata: &RngMetadata) -> FlatDeck {
+    game_type.new_deck(recreate_shuffle_seed(rng_metadata))
+}
+
+/// Calculate SHA-256 hash of the deck for cryptographic verification
+///
+/// Uses SHA-256 for cryptographic security and transparency.
+/// This ensures hashes can be independently verified by users.
+pub fn calculate_deck_hash(deck: &FlatDeck) -> String {
+    let mut hasher = Sha256::new();
+
+    // Hash each card in the deck deterministically
+    for card in deck.cards() {
+        // Serialize card as value:suit bytes
+        let card_bytes = format!("{:?}:{:?}", card.value, card.suit);
+        hasher.update(card_bytes.as_bytes());
+    }
+
+    // Convert to hex string (64 chars for SHA-256)
+    let result = hasher.finalize();
+    format!("{:x}", result)
+}
+
+/// Calculate the SHA-256 hash identifying a card of a round
+///
+/// Hash format: SHA-256(round_id || card || shuffled_position)
+pub fn calculate_card_hash(round_id: u64, card: &Card, shuffled_position: u8) -> String {
+    let mut hasher = Sha256::new();
+
+    // Hash round_id (8 bytes, little-endian)
+    hasher.update(round_id.to_le_bytes());
+
+    // Hash card (deterministic serialization)
+    let card_bytes = format!("{:?}:{:?}", card.value, card.suit);
+    hasher.update(card_bytes.as_bytes());
+
+    // Hash position (1 byte)
+    hasher.update([shuffled_position]);
+
+    // Finalize to hex string
+    let result = hasher.finalize();
+    format!("{:x}", result)
+}
+
+/// Generate card provenance for all cards in the deck
+///
+/// Uses SHA-256 for each card hash to ensure cryptographic verification.
+/// Hash format: SHA-256(round_id || card || shuffled_position)
+pub fn generate_card_provenance(deck: &FlatDeck, round_id: u64) -> HashMap<String, CardProvenance> {
+    let mut provenance_map = HashMap::new();
+
+    for (shuffled_position, card) in deck.cards().iter().enumerate() {
+        let card_hash = calculate_card_hash(round_id, card, shuffled_position as u8);
+
+        // Create provenance record
+        let provenance = CardProvenance {
+            round_id,
+            card: *card,
+            original_position: shuffled_position as u8,
+            shuffled_position: shuffled_position as u8,
+            card_hash: card_hash.clone(),
+            dealt_to: None,
+            dealt_at_stage: None,
+            dealt_in_run: None,
+            is_revealed: None,
+        };
+
+        provenance_map.insert(card_hash, provenance);
+    }
+
+    provenance_map
+}
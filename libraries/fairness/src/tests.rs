@@ -0,0 +1,308 @@
+use candid::Principal;
+use poker_core::GameType;
+use sha2::{Digest, Sha256};
+use user::user::WalletPrincipalId;
+
+use crate::{
+    bundle::{FairnessBundle, HandLog},
+    merkle::DeckCommitment,
+    shuffle::{
+        calculate_deck_hash, generate_card_provenance, recreate_deck, recreate_shuffle_seed,
+        reshuffle_bytes_hash, shuffle_commitment,
+    },
+    types::{PlayerSeed, RngMetadata},
+    verify::{verify_bundle, Discrepancy},
+};
+
+/// Shuffles a round the way the table canister does and exports it.
+fn export_round(game_type: GameType, player_seeds: Option<Vec<PlayerSeed>>) -> FairnessBundle {
+    let mut rng_metadata = RngMetadata {
+        round_id: 7,
+        raw_random_bytes: (0..32).collect(),
+        time_seed: 1_700_000_000_000_000_000,
+        timestamp_ns: 1_700_000_000_000_000_000,
+        player_seeds,
+        ..Default::default()
+    };
+    let deck = recreate_deck(&game_type, &rng_metadata);
+    rng_metadata.deck_hash = calculate_deck_hash(&deck);
+    rng_metadata.shuffled_deck = deck.cards().to_vec();
+    rng_metadata.deck_merkle_root = Some(deck_commitment(&rng_metadata).root());
+
+    FairnessBundle {
+        game_type,
+        card_provenance: generate_card_provenance(&deck, rng_metadata.round_id)
+            .into_values()
+            .collect(),
+        rng_metadata,
+        hand_log: None,
+        card_proofs: None,
+    }
+}
+
+fn deck_commitment(rng_metadata: &RngMetadata) -> DeckCommitment {
+    DeckCommitment::new(
+        rng_metadata.round_id,
+        &recreate_shuffle_seed(rng_metadata),
+        &rng_metadata.shuffled_deck,
+    )
+}
+
+/// Deals two players two cards each and a flop from the bundle's deck.
+fn deal_hand(bundle: &mut FairnessBundle, players: &[WalletPrincipalId]) {
+    let mut deck = bundle.rng_metadata.shuffled_deck.clone();
+    let mut hand_log = HandLog {
+        hole_cards: players.iter().map(|player| (*player, Vec::new())).collect(),
+        community_cards: Vec::new(),
+    };
+    for _ in 0..2 {
+        for (_, cards) in hand_log.hole_cards.iter_mut() {
+            cards.push(deck.pop().unwrap());
+        }
+    }
+    deck.pop(); // Burn
+    for _ in 0..3 {
+        hand_log.community_cards.push(deck.pop().unwrap());
+    }
+
+    for provenance in bundle.card_provenance.iter_mut() {
+        provenance.dealt_to = hand_log
+            .hole_cards
+            .iter()
+            .find(|(_, cards)| cards.contains(&provenance.card))
+            .map(|(player, _)| *player);
+    }
+    bundle.hand_log = Some(hand_log);
+}
+
+#[test]
+fn test_reshuffle_is_a_deterministic_permutation() {
+    let bytes: Vec<u8> = (0..32).collect();
+    let mut reshuffled = bytes.clone();
+    reshuffle_bytes_hash(&mut reshuffled, 42);
+    assert_ne!(reshuffled, bytes);
+
+    let mut again = bytes.clone();
+    reshuffle_bytes_hash(&mut again, 42);
+    assert_eq!(again, reshuffled);
+
+    reshuffled.sort();
+    assert_eq!(reshuffled, bytes);
+}
+
+/// The reshuffled bytes and deck hash the canister produces for this round on
+/// wasm32, where only the low 32 bits of each hash pick the swap. Native
+/// verifiers have to rebuild exactly this order and deck.
+#[test]
+fn test_reshuffle_matches_the_canister() {
+    let rng_metadata = RngMetadata {
+        round_id: 7,
+        raw_random_bytes: (0..32).collect(),
+        time_seed: 1_700_000_000_000_000_000,
+        ..Default::default()
+    };
+
+    let mut reshuffled = rng_metadata.raw_random_bytes.clone();
+    reshuffle_bytes_hash(&mut reshuffled, rng_metadata.time_seed);
+    assert_eq!(
+        reshuffled,
+        vec![
+            12, 28, 24, 31, 9, 5, 27, 26, 14, 19, 17, 20, 3, 6, 4, 0, 7, 2, 13, 15, 30, 22, 10, 1,
+            21, 18, 11, 25, 8, 29, 23, 16
+        ]
+    );
+
+    let deck = recreate_deck(&GameType::NoLimit(1), &rng_metadata);
+    assert_eq!(
+        calculate_deck_hash(&deck),
+        "87c524778552bab8ec6d6089ce398b331ef22c3f52f16455a3347404f9cdca3c"
+    );
+}
+
+#[test]
+fn test_exported_round_verifies() {
+    let bundle = export_round(GameType::NoLimit(1), None);
+    assert_eq!(bundle.rng_metadata.shuffled_deck.len(), 52);
+    assert!(verify_bundle(&bundle).is_empty());
+
+    let bundle = export_round(GameType::ShortDeck(1), None);
+    assert_eq!(bundle.rng_metadata.shuffled_deck.len(), 36);
+    assert!(verify_bundle(&bundle).is_empty());
+}
+
+#[test]
+fn test_tampered_round_is_caught() {
+    let mut bundle = export_round(GameType::NoLimit(1), None);
+    bundle.rng_metadata.time_seed += 1;
+    let discrepancies = verify_bundle(&bundle);
+    assert!(discrepancies.contains(&Discrepancy::ShuffledDeckMismatch));
+    assert!(discrepancies
+        .iter()
+        .any(|discrepancy| matches!(discrepancy, Discrepancy::DeckHashMismatch { .. })));
+
+    let mut bundle = export_round(GameType::NoLimit(1), None);
+    let (first, second) = bundle.card_provenance.split_at_mut(1);
+    std::mem::swap(&mut first[0].card, &mut second[0].card);
+    let discrepancies = verify_bundle(&bundle);
+    assert_eq!(discrepancies.len(), 4);
+    assert!(discrepancies.iter().all(|discrepancy| matches!(
+        discrepancy,
+        Discrepancy::CardHashMismatch(_) | Discrepancy::CardPositionMismatch { .. }
+    )));
+}
+
+#[test]
+fn test_revealed_player_seeds_change_the_deck() {
+    let seed = b"my seed".to_vec();
+    let player_seed = PlayerSeed {
+        user_principal: WalletPrincipalId(Principal::from_slice(&[1])),
+        commitment: Sha256::digest(&seed).to_vec(),
+        seed: Some(seed),
+    };
+    let bundle = export_round(GameType::NoLimit(1), Some(vec![player_seed.clone()]));
+    assert!(verify_bundle(&bundle).is_empty());
+    assert_ne!(
+        bundle.rng_metadata.shuffled_deck,
+        export_round(GameType::NoLimit(1), None)
+            .rng_metadata
+            .shuffled_deck
+    );
+
+    let mut bundle = bundle;
+    bundle.rng_metadata.player_seeds = Some(vec![PlayerSeed {
+        seed: Some(b"another seed".to_vec()),
+        ..player_seed
+    }]);
+    let discrepancies = verify_bundle(&bundle);
+    assert!(discrepancies.contains(&Discrepancy::PlayerSeedMismatch(
+        Principal::from_slice(&[1]).to_text()
+    )));
+}
+
+#[test]
+fn test_randomness_must_match_the_shuffle_commitment() {
+    let mut bundle = export_round(GameType::NoLimit(1), None);
+    bundle.rng_metadata.shuffle_commitment = Some(shuffle_commitment(
+        &bundle.rng_metadata.raw_random_bytes,
+        bundle.rng_metadata.time_seed,
+    ));
+    assert!(verify_bundle(&bundle).is_empty());
+
+    bundle.rng_metadata.shuffle_commitment = Some(shuffle_commitment(
+        &bundle.rng_metadata.raw_random_bytes,
+        bundle.rng_metadata.time_seed + 1,
+    ));
+    assert_eq!(
+        verify_bundle(&bundle),
+        vec![Discrepancy::ShuffleCommitmentMismatch]
+    );
+}
+
+#[test]
+fn test_dealt_cards_come_from_the_top_of_the_deck() {
+    let players = [
+        WalletPrincipalId(Principal::from_slice(&[1])),
+        WalletPrincipalId(Principal::from_slice(&[2])),
+    ];
+    let mut bundle = export_round(GameType::NoLimit(1), None);
+    deal_hand(&mut bundle, &players);
+    assert!(verify_bundle(&bundle).is_empty());
+
+    // A flop card from the bottom of the deck was not dealt from it
+    let bottom_card = bundle.rng_metadata.shuffled_deck[0];
+    let mut tampered = bundle.clone();
+    tampered.hand_log.as_mut().unwrap().community_cards[1] = bottom_card;
+    assert_eq!(
+        verify_bundle(&tampered),
+        vec![Discrepancy::DealOrderMismatch(bottom_card.to_string())]
+    );
+
+    // Hole cards have to go to the player the provenance names
+    let mut tampered = bundle.clone();
+    let hand_log = tampered.hand_log.as_mut().unwrap();
+    let card = hand_log.hole_cards[0].1.remove(0);
+    hand_log.hole_cards[1].1.push(card);
+    assert_eq!(
+        verify_bundle(&tampered),
+        vec![Discrepancy::DealtToMismatch {
+            card: card.to_string(),
+            dealt_to: players[1].0.to_text(),
+        }]
+    );
+
+    // No card is dealt twice
+    let mut tampered = bundle;
+    let hand_log = tampered.hand_log.as_mut().unwrap();
+    let card = hand_log.hole_cards[0].1[0];
+    hand_log.community_cards.push(card);
+    assert_eq!(
+        verify_bundle(&tampered),
+        vec![Discrepancy::CardDealtTwice(card.to_string())]
+    );
+}
+
+#[test]
+fn test_every_card_of_the_deck_can_be_proven() {
+    for game_type in [GameType::NoLimit(1), GameType::ShortDeck(1)] {
+        let bundle = export_round(game_type, None);
+        let merkle_root = bundle.rng_metadata.deck_merkle_root.clone().unwrap();
+        let commitment = deck_commitment(&bundle.rng_metadata);
+
+        for (position, card) in bundle.rng_metadata.shuffled_deck.iter().enumerate() {
+            let card_proof = commitment.prove(position as u8).unwrap();
+            assert_eq!(card_proof.card, *card);
+            assert!(card_proof.verify(&merkle_root));
+
+            // The card cannot be swapped or moved
+            let deck_len = bundle.rng_metadata.shuffled_deck.len();
+            let mut forged = card_proof.clone();
+            forged.card = bundle.rng_metadata.shuffled_deck[(position + 1) % deck_len];
+            assert!(!forged.verify(&merkle_root));
+            let mut forged = card_proof;
+            forged.shuffled_position ^= 1;
+            assert!(!forged.verify(&merkle_root));
+        }
+        assert!(commitment
+            .prove(bundle.rng_metadata.shuffled_deck.len() as u8)
+            .is_none());
+    }
+}
+
+#[test]
+fn test_card_proofs_verify_without_the_deck() {
+    let mut bundle = export_round(GameType::NoLimit(1), None);
+    let commitment = deck_commitment(&bundle.rng_metadata);
+    bundle.card_proofs = Some(vec![
+        commitment.prove(51).unwrap(),
+        commitment.prove(49).unwrap(),
+    ]);
+    assert!(verify_bundle(&bundle).is_empty());
+
+    // A table that keeps mucked cards private reveals none of the shuffle
+    let mut private_bundle = bundle.clone();
+    private_bundle.rng_metadata.raw_random_bytes = Vec::new();
+    private_bundle.rng_metadata.time_seed = 0;
+    private_bundle.rng_metadata.timestamp_ns = 0;
+    private_bundle.rng_metadata.shuffled_deck = Vec::new();
+    private_bundle.card_provenance = Vec::new();
+    assert!(verify_bundle(&private_bundle).is_empty());
+
+    let hole_card = bundle.card_proofs.as_ref().unwrap()[0].card;
+    let other_card = bundle.card_proofs.as_ref().unwrap()[1].card;
+    private_bundle.card_proofs.as_mut().unwrap()[0].card = other_card;
+    assert_eq!(
+        verify_bundle(&private_bundle),
+        vec![Discrepancy::CardProofMismatch(other_card.to_string())]
+    );
+
+    // The root has to be the one the deck commits to
+    bundle.rng_metadata.deck_merkle_root = Some("0".repeat(64));
+    let discrepancies = verify_bundle(&bundle);
+    assert!(discrepancies.contains(&Discrepancy::CardProofMismatch(hole_card.to_string())));
+    assert!(discrepancies
+        .iter()
+        .any(|discrepancy| matches!(discrepancy, Discrepancy::MerkleRootMismatch { .. })));
+
+    bundle.rng_metadata.deck_merkle_root = None;
+    assert_eq!(verify_bundle(&bundle), vec![Discrepancy::MissingMerkleRoot]);
+}
@@ -0,0 +1,155 @@
+use candid::CandidType;
+use poker_core::{Card, DealStage, Suit, Value};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use user::user::WalletPrincipalId;
+
+/// A player's contribution to the entropy of the next shuffle.
+///
+/// The player commits to the SHA-256 hash of a seed of their own, then reveals
+/// the seed once the canister committed to its randomness for the shuffle. The
+/// canister cannot change its randomness after seeing the seeds, and the players
+/// never see it before the deck is dealt, so no one can steer the shuffle.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct PlayerSeed {
+    pub user_principal: WalletPrincipalId,
+    /// The SHA-256 hash of the seed.
+    pub commitment: Vec<u8>,
+    /// The seed, once it is revealed.
+    pub seed: Option<Vec<u8>>,
+}
+
+impl PlayerSeed {
+    /// Whether the revealed seed hashes to the commitment.
+    pub fn matches_commitment(&self) -> bool {
+        self.seed
+            .as_ref()
+            .is_some_and(|seed| Sha256::digest(seed).as_slice() == self.commitment.as_slice())
+    }
+}
+
+/// RNG Metadata for transparency and verification
+///
+/// This structure stores all information needed to verify the fairness
+/// of card dealing in a poker game, including the raw random bytes from
+/// the Internet Computer's management canister, time seeds, and the
+/// resulting shuffled deck.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, CandidType)]
+pub struct RngMetadata {
+    /// The game round ID (incrementing counter for each new shuffle)
+    pub round_id: u64,
+
+    /// Raw random bytes from IC management canister (32 bytes)
+    /// This is the source of randomness from the blockchain's VRF
+    pub raw_random_bytes: Vec<u8>,
+
+    /// Time seed used for reshuffling (nanoseconds since Unix epoch)
+    /// Adds additional entropy to the shuffle process
+    pub time_seed: u64,
+
+    /// Timestamp of RNG generation (nanoseconds since Unix epoch)
+    pub timestamp_ns: u64,
+
+    /// Hash of the final shuffled deck (for quick verification)
+    /// SHA-256 hash of the shuffled card order
+    pub deck_hash: String,
+
+    /// Transaction/call ID from IC management canister (if available)
+    /// Can be used to trace back to the on-chain randomness call
+    pub ic_transaction_id: Option<String>,
+
+    /// The shuffled card order (52 cards)
+    /// The complete deck after Fisher-Yates shuffle
+    pub shuffled_deck: Vec<Card>,
+
+    /// The seeds seated players committed to on commit-reveal tables, in seat order
+    /// The deck is shuffled with the random bytes combined with every revealed seed
+    pub player_seeds: Option<Vec<PlayerSeed>>,
+
+    /// The commitment to the random bytes and time seed, published before the player seeds were revealed
+    pub shuffle_commitment: Option<Vec<u8>>,
+
+    /// Root of the Merkle tree over the salted cards of the shuffled deck
+    /// Published before dealing, so single cards can be proven without revealing the deck
+    pub deck_merkle_root: Option<String>,
+}
+
+/// Card Provenance tracks the journey of a single card through the shuffle
+///
+/// This allows players to verify exactly where each card came from and
+/// how it ended up in their hand, providing complete transparency.
+///
+/// **IMPORTANT:** `shuffled_position` is the position in the ORIGINAL
+/// shuffled deck (0-51), NOT the current deck state after dealing.
+/// This position remains constant throughout the game and is used for
+/// cryptographic hash verification. As cards are dealt from the deck,
+/// the remaining cards' positions in the current deck state change,
+/// but their `shuffled_position` values remain fixed.
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
+pub struct CardProvenance {
+    /// Round ID this card belongs to
+    pub round_id: u64,
+
+    /// The card (e.g., Ace of Spades)
+    pub card: Card,
+
+    /// Original position in unshuffled deck (0-51)
+    /// Standard order: A♠, 2♠, ..., K♠, A♥, ..., K♦
+    pub original_position: u8,
+
+    /// Position after Fisher-Yates shuffle (0-51)
+    ///
+    /// **CRITICAL:** This is the position in the ORIGINAL shuffled deck,
+    /// before any cards were dealt. This position is used for hash calculation
+    /// and does NOT change as cards are dealt from the deck.
+    ///
+    /// Example: If a card is at position 10 in the original shuffled deck,
+    /// it remains at `shuffled_position = 10` even if 5 cards are dealt
+    /// and it's now at position 5 in the current deck state.
+    pub shuffled_position: u8,
+
+    /// Hash identifier for this specific card in this game
+    /// Format: SHA-256(round_id || card || shuffled_position)
+    ///
+    /// The hash uses `shuffled_position` (original position), not the
+    /// current deck position, ensuring consistent verification.
+    pub card_hash: String,
+
+    /// Which player received this card (if dealt)
+    pub dealt_to: Option<WalletPrincipalId>,
+
+    /// When was this card dealt (PreFlop, Flop, Turn, River)
+    pub dealt_at_stage: Option<DealStage>,
+
+    /// Which run of the board this card was dealt in (1, 2 or 3)
+    /// when the players agreed to run it more than once.
+    /// `dealt_at_stage` still says which street of that run the card came on.
+    pub dealt_in_run: Option<u8>,
+
+    /// Whether the whole table saw this card off the board,
+    /// like a stud up card, a hole card shown after the hand or a rabbit hunted card
+    pub is_revealed: Option<bool>,
+}
+
+impl CardProvenance {
+    /// Whether everyone at the table saw the card, on the board or revealed.
+    pub fn is_seen_by_table(&self) -> bool {
+        self.is_revealed == Some(true) || (self.dealt_to.is_none() && self.dealt_at_stage.is_some())
+    }
+}
+
+impl Default for CardProvenance {
+    fn default() -> Self {
+        CardProvenance {
+            round_id: 0,
+            card: Card::new(Value::Two, Suit::Spade),
+            original_position: 0,
+            shuffled_position: 0,
+            card_hash: String::new(),
+            dealt_to: None,
+            dealt_at_stage: None,
+            dealt_in_run: None,
+            is_revealed: None,
+        }
+    }
+}
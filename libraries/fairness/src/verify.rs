@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use poker_core::Card;
+use thiserror::Error;
+use user::user::WalletPrincipalId;
+
+use crate::{
+    bundle::FairnessBundle,
+    merkle::DeckCommitment,
+    shuffle::{
+        calculate_card_hash, calculate_deck_hash, recreate_deck, recreate_shuffle_seed,
+        shuffle_commitment,
+    },
+};
+
+/// Something in a bundle that does not add up.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Discrepancy {
+    #[error("The seed revealed by {0} does not match their commitment")]
+    PlayerSeedMismatch(String),
+    #[error("The random bytes and time seed do not match the table's shuffle commitment")]
+    ShuffleCommitmentMismatch,
+    #[error("The published deck is not the deck the RNG metadata shuffles")]
+    ShuffledDeckMismatch,
+    #[error("The deck hash {published} does not match the recalculated {calculated}")]
+    DeckHashMismatch {
+        published: String,
+        calculated: String,
+    },
+    #[error("The provenance of {card} belongs to round {round_id}")]
+    WrongRound { card: String, round_id: u64 },
+    #[error("The card hash {0} does not match its card and position")]
+    CardHashMismatch(String),
+    #[error("{card} is not at position {position} of the shuffled deck")]
+    CardPositionMismatch { card: String, position: u8 },
+    #[error("{0} was dealt but is not in the shuffled deck")]
+    CardNotInDeck(String),
+    #[error("{0} was dealt more than once")]
+    CardDealtTwice(String),
+    #[error("{card} was dealt to {dealt_to} but its provenance does not say so")]
+    DealtToMismatch { card: String, dealt_to: String },
+    #[error("{0} was not dealt from the top of the deck in order")]
+    DealOrderMismatch(String),
+    #[error("The round did not publish a Merkle root to prove cards against")]
+    MissingMerkleRoot,
+    #[error("The Merkle root {published} does not match the recalculated {calculated}")]
+    MerkleRootMismatch {
+        published: String,
+        calculated: String,
+    },
+    #[error("The proof of {0} does not lead to the Merkle root")]
+    CardProofMismatch(String),
+}
+
+/// Checks a bundle against the shuffle its RNG metadata reproduces.
+///
+/// Every card proof must lead to the Merkle root the round published. If the
+/// random bytes were revealed, they must match the shuffle commitment the table
+/// published before the player seeds were revealed. The deck is then recomputed
+/// from them, the time seed and any revealed player seeds, and compared with the
+/// published deck, deck hash and Merkle root. Every card hash must match its
+/// card and shuffled position, and the cards of the hand log must have been
+/// dealt from the top of that deck, hole cards first.
+///
+/// Returns every discrepancy found, none if the hand was dealt fairly.
+pub fn verify_bundle(bundle: &FairnessBundle) -> Vec<Discrepancy> {
+    let rng_metadata = &bundle.rng_metadata;
+    let mut discrepancies = Vec::new();
+
+    for player_seed in rng_metadata.player_seeds.iter().flatten() {
+        if player_seed.seed.is_some() && !player_seed.matches_commitment() {
+            discrepancies.push(Discrepancy::PlayerSeedMismatch(
+                player_seed.user_principal.0.to_text(),
+            ));
+        }
+    }
+
+    let card_proofs = bundle.card_proofs.as_deref().unwrap_or_default();
+    match &rng_metadata.deck_merkle_root {
+        Some(merkle_root) => {
+            for card_proof in card_proofs {
+                if card_proof.round_id != rng_metadata.round_id {
+                    discrepancies.push(Discrepancy::WrongRound {
+                        card: card_proof.card.to_string(),
+                        round_id: card_proof.round_id,
+                    });
+                } else if !card_proof.verify(merkle_root) {
+                    discrepancies.push(Discrepancy::CardProofMismatch(card_proof.card.to_string()));
+                }
+            }
+        }
+        None if !card_proofs.is_empty() => discrepancies.push(Discrepancy::MissingMerkleRoot),
+        None => {}
+    }
+
+    // The deck of a table that keeps mucked cards private is never revealed
+    if rng_metadata.raw_random_bytes.is_empty() {
+        return discrepancies;
+    }
+
+    if let Some(commitment) = &rng_metadata.shuffle_commitment {
+        if *commitment != shuffle_commitment(&rng_metadata.raw_random_bytes, rng_metadata.time_seed)
+        {
+            discrepancies.push(Discrepancy::ShuffleCommitmentMismatch);
+        }
+    }
+
+    let deck = recreate_deck(&bundle.game_type, rng_metadata);
+    if !rng_metadata.shuffled_deck.is_empty() && rng_metadata.shuffled_deck != deck.cards() {
+        discrepancies.push(Discrepancy::ShuffledDeckMismatch);
+    }
+    let deck_hash = calculate_deck_hash(&deck);
+    if deck_hash != rng_metadata.deck_hash {
+        discrepancies.push(Discrepancy::DeckHashMismatch {
+            published: rng_metadata.deck_hash.clone(),
+            calculated: deck_hash,
+        });
+    }
+    if let Some(merkle_root) = &rng_metadata.deck_merkle_root {
+        let calculated = DeckCommitment::new(
+            rng_metadata.round_id,
+            &recreate_shuffle_seed(rng_metadata),
+            deck.cards(),
+        )
+        .root();
+        if calculated != *merkle_root {
+            discrepancies.push(Discrepancy::MerkleRootMismatch {
+                published: merkle_root.clone(),
+                calculated,
+            });
+        }
+    }
+
+    for provenance in &bundle.card_provenance {
+        if provenance.round_id != rng_metadata.round_id {
+            discrepancies.push(Discrepancy::WrongRound {
+                card: provenance.card.to_string(),
+                round_id: provenance.round_id,
+            });
+            continue;
+        }
+        if calculate_card_hash(
+            provenance.round_id,
+            &provenance.card,
+            provenance.shuffled_position,
+        ) != provenance.card_hash
+        {
+            discrepancies.push(Discrepancy::CardHashMismatch(provenance.card_hash.clone()));
+        }
+        if deck.cards().get(provenance.shuffled_position as usize) != Some(&provenance.card) {
+            discrepancies.push(Discrepancy::CardPositionMismatch {
+                card: provenance.card.to_string(),
+                position: provenance.shuffled_position,
+            });
+        }
+    }
+
+    if let Some(hand_log) = &bundle.hand_log {
+        let hole_cards: Vec<(Option<WalletPrincipalId>, Card)> = hand_log
+            .hole_cards
+            .iter()
+            .flat_map(|(user_principal, cards)| {
+                cards.iter().map(|card| (Some(*user_principal), *card))
+            })
+            .collect();
+        let community_cards: Vec<(Option<WalletPrincipalId>, Card)> = hand_log
+            .community_cards
+            .iter()
+            .map(|card| (None, *card))
+            .collect();
+        discrepancies.extend(verify_dealt_cards(
+            bundle,
+            deck.cards(),
+            &hole_cards,
+            &community_cards,
+        ));
+    }
+
+    discrepancies
+}
+
+/// Checks the dealt cards against the deck and their provenance.
+///
+/// Cards are dealt from the end of the shuffled deck, so the hole cards have
+/// to be its last cards and the community cards have to follow below them in the
+/// order they were dealt, with burnt cards in between.
+fn verify_dealt_cards(
+    bundle: &FairnessBundle,
+    deck: &[Card],
+    hole_cards: &[(Option<WalletPrincipalId>, Card)],
+    community_cards: &[(Option<WalletPrincipalId>, Card)],
+) -> Vec<Discrepancy> {
+    let mut discrepancies = Vec::new();
+    let mut dealt = HashSet::new();
+    let mut hole_positions = Vec::new();
+    let mut previous_community_position = None;
+
+    for (index, (dealt_to, card)) in hole_cards.iter().chain(community_cards).enumerate() {
+        if !dealt.insert(*card) {
+            discrepancies.push(Discrepancy::CardDealtTwice(card.to_string()));
+            continue;
+        }
+        let Some(position) = deck.iter().position(|deck_card| deck_card == card) else {
+            discrepancies.push(Discrepancy::CardNotInDeck(card.to_string()));
+            continue;
+        };
+
+        if let Some(provenance) = bundle.card_provenance.iter().find(|provenance| {
+            provenance.round_id == bundle.rng_metadata.round_id && provenance.card == *card
+        }) {
+            if provenance.dealt_to != *dealt_to {
+                discrepancies.push(Discrepancy::DealtToMismatch {
+                    card: card.to_string(),
+                    dealt_to: dealt_to.map_or("the board".to_string(), |user_principal| {
+                        user_principal.0.to_text()
+                    }),
+                });
+            }
+        }
+
+        if index < hole_cards.len() {
+            hole_positions.push(position);
+            if position < deck.len().saturating_sub(hole_cards.len()) {
+                discrepancies.push(Discrepancy::DealOrderMismatch(card.to_string()));
+            }
+        } else {
+            // At most one card is burnt before each community card
+            let lowest_position = deck
+                .len()
+                .saturating_sub(hole_cards.len() + 2 * community_cards.len());
+            let in_order = position >= lowest_position
+                && hole_positions.iter().all(|hole| position < *hole)
+                && previous_community_position.is_none_or(|previous| position < previous);
+            if in_order {
+                previous_community_position = Some(position);
+            } else {
+                discrepancies.push(Discrepancy::DealOrderMismatch(card.to_string()));
+            }
+        }
+    }
+    discrepancies
+}
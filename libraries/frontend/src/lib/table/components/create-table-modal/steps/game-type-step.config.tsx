@@ -126,6 +126,75 @@ const BlindsInput = memo<{
           disabled
         />
       </List>,
+    PotLimitOmahaHiLo4: (small_blind) =>
+      <List>
+        <CurrencyInputComponent
+          label="Small Blind"
+          value={small_blind}
+          min={0n}
+          onChange={(v) => onChange({ PotLimitOmahaHiLo4: v })}
+          currencyType={currencyType}
+        />
+        <CurrencyInputComponent
+          label="Big Blind"
+          value={small_blind * 2n}
+          onChange={(v) => onChange({ PotLimitOmahaHiLo4: v })}
+          currencyType={currencyType}
+          disabled
+        />
+      </List>,
+    PotLimitOmahaHiLo5: (small_blind) =>
+      <List>
+        <CurrencyInputComponent
+          label="Small Blind"
+          value={small_blind}
+          min={0n}
+          onChange={(v) => onChange({ PotLimitOmahaHiLo5: v })}
+          currencyType={currencyType}
+        />
+        <CurrencyInputComponent
+          label="Big Blind"
+          value={small_blind * 2n}
+          onChange={(v) => onChange({ PotLimitOmahaHiLo5: v })}
+          currencyType={currencyType}
+          disabled
+        />
+      </List>,
+    ShortDeck: (small_blind) =>
+      <List>
+        <CurrencyInputComponent
+          label="Small Blind"
+          value={small_blind}
+          min={0n}
+          onChange={(v) => onChange({ ShortDeck: v })}
+          currencyType={currencyType}
+        />
+        <CurrencyInputComponent
+          label="Big Blind"
+          value={small_blind * 2n}
+          min={0n}
+          onChange={(v) => onChange({ ShortDeck: v })}
+          currencyType={currencyType}
+          disabled
+        />
+      </List>,
+    SevenCardStud: ([small_bet, big_bet]) =>
+      <List>
+        <CurrencyInputComponent
+          label="Small Bet"
+          value={small_bet}
+          min={0n}
+          onChange={(v) => onChange({ SevenCardStud: [v, big_bet] })}
+          currencyType={currencyType}
+        />
+        <CurrencyInputComponent
+          label="Big Bet"
+          value={big_bet}
+          min={0n}
+          onChange={(v) => onChange({ SevenCardStud: [small_bet, v] })}
+          currencyType={currencyType}
+        />
+      </List>,
   });
 },
   (prevProps, nextProps) =>
@@ -145,12 +214,18 @@ export const GameTypeStepComponent = memo<StepComponentProps<Value> & { hideFake
     PotLimit: 0n,
     PotLimitOmaha4: 0n,
     PotLimitOmaha5: 0n,
+    PotLimitOmahaHiLo4: 0n,
+    PotLimitOmahaHiLo5: 0n,
+    ShortDeck: 0n,
     FixedLimit: (data.game_type && "FixedLimit" in data.game_type
       ? [data.game_type.FixedLimit[0], data.game_type.FixedLimit[1]]
       : []) as [bigint | undefined, bigint | undefined],
     SpreadLimit: (data.game_type && "SpreadLimit" in data.game_type
       ? [data.game_type.SpreadLimit[0], data.game_type.SpreadLimit[1]]
       : []) as [bigint | undefined, bigint | undefined],
+    SevenCardStud: (data.game_type && "SevenCardStud" in data.game_type
+      ? [data.game_type.SevenCardStud[0], data.game_type.SevenCardStud[1]]
+      : []) as [bigint | undefined, bigint | undefined],
   });
 
   const propagate = useCallback(
@@ -171,6 +246,15 @@ export const GameTypeStepComponent = memo<StepComponentProps<Value> & { hideFake
         case "PotLimitOmaha5":
           game_type = { PotLimitOmaha5: nLimits.PotLimitOmaha5 as bigint };
           break;
+        case "PotLimitOmahaHiLo4":
+          game_type = { PotLimitOmahaHiLo4: nLimits.PotLimitOmahaHiLo4 as bigint };
+          break;
+        case "PotLimitOmahaHiLo5":
+          game_type = { PotLimitOmahaHiLo5: nLimits.PotLimitOmahaHiLo5 as bigint };
+          break;
+        case "ShortDeck":
+          game_type = { ShortDeck: nLimits.ShortDeck as bigint };
+          break;
         case "SpreadLimit":
           if (
             nLimits.SpreadLimit?.[0] === undefined ||
@@ -191,6 +275,16 @@ export const GameTypeStepComponent = memo<StepComponentProps<Value> & { hideFake
           }
           game_type = { FixedLimit: nLimits.FixedLimit as [bigint, bigint] };
           break;
+        case "SevenCardStud":
+          if (
+            nLimits.SevenCardStud?.[0] === undefined ||
+            nLimits.SevenCardStud?.[1] === undefined
+          ) {
+            game_type = { SevenCardStud: [0n, 0n] };
+            break;
+          }
+          game_type = { SevenCardStud: nLimits.SevenCardStud as [bigint, bigint] };
+          break;
       }
       patch({
         game_type,
@@ -228,6 +322,10 @@ export const GameTypeStepComponent = memo<StepComponentProps<Value> & { hideFake
           { value: "PotLimit", label: "Pot limit" },
           { value: "PotLimitOmaha4", label: "PLO 4-card" },
           { value: "PotLimitOmaha5", label: "PLO 5-card" },
+          { value: "PotLimitOmahaHiLo4", label: "PLO Hi-Lo 4-card" },
+          { value: "PotLimitOmahaHiLo5", label: "PLO Hi-Lo 5-card" },
+          { value: "ShortDeck", label: "Short deck" },
+          { value: "SevenCardStud", label: "Seven card stud" },
         ]}
       />
       <BlindsInput
@@ -261,6 +359,18 @@ export const Config: SteppedModalStep<Value> = {
       if (game_type.PotLimitOmaha5 <= 0) return ["Bets must be greater than 0"];
       return true;
     }
+    if ("PotLimitOmahaHiLo4" in game_type) {
+      if (game_type.PotLimitOmahaHiLo4 <= 0) return ["Bets must be greater than 0"];
+      return true;
+    }
+    if ("PotLimitOmahaHiLo5" in game_type) {
+      if (game_type.PotLimitOmahaHiLo5 <= 0) return ["Bets must be greater than 0"];
+      return true;
+    }
+    if ("ShortDeck" in game_type) {
+      if (game_type.ShortDeck <= 0) return ["Bets must be greater than 0"];
+      return true;
+    }
     if ("SpreadLimit" in game_type) {
       const [min, max] = game_type.SpreadLimit;
       if (min < 0 || max < 0) return ["Bets must be greater than 0"];
@@ -273,6 +383,12 @@ export const Config: SteppedModalStep<Value> = {
       if (small >= big) return ["Big bet must be greater than small bet"];
       return true;
     }
+    if ("SevenCardStud" in game_type) {
+      const [small, big] = game_type.SevenCardStud;
+      if (small < 0 || big < 0) return ["Bets must be greater than 0"];
+      if (small >= big) return ["Big bet must be greater than small bet"];
+      return true;
+    }
     return ["Invalid game type"];
   },
   defaultValues: {
@@ -23,6 +23,11 @@ const PreviewStepComponent = memo<StepComponentProps<TableConfig>>(({ data }) =>
           data.game_type.FixedLimit[0],
           data.game_type.FixedLimit[0] / 2n,
         ];
+      if ("SevenCardStud" in data.game_type)
+        return [
+          data.game_type.SevenCardStud[0],
+          data.game_type.SevenCardStud[0] / 2n,
+        ];
     }
     return [0n, 0n];
   }, [data.game_type]);
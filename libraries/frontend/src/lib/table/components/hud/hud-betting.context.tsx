@@ -28,7 +28,7 @@ export type HUDContextType = {
     setShowInlineInput(show: boolean): void;
     isOpeningBet: boolean;
     actionLabel: string; // "Bet" or "Raise"
-    isPLO: boolean; // Is this a PLO4/PLO5 game?
+    isPLO: boolean; // Is this a PLO4/PLO5 (or hi-lo) game?
     canRaise: boolean; // Can player raise, or only all-in? (PLO only)
 
     cta: {
@@ -139,7 +139,9 @@ export const ProvideHUDBettingContext = memo<{ children: ReactNode }>(
 
       const isPotLimit = "PotLimit" in table.config.game_type ||
         "PotLimitOmaha4" in table.config.game_type ||
-        "PotLimitOmaha5" in table.config.game_type;
+        "PotLimitOmaha5" in table.config.game_type ||
+        "PotLimitOmahaHiLo4" in table.config.game_type ||
+        "PotLimitOmahaHiLo5" in table.config.game_type;
 
       const currentBet = user.data.current_total_bet;
       const callValue = table.highest_bet;
@@ -277,7 +279,9 @@ export const ProvideHUDBettingContext = memo<{ children: ReactNode }>(
     const [min, max] = useMemo(() => {
       const isPotLimit = "PotLimit" in table.config.game_type ||
         "PotLimitOmaha4" in table.config.game_type ||
-        "PotLimitOmaha5" in table.config.game_type;
+        "PotLimitOmaha5" in table.config.game_type ||
+        "PotLimitOmahaHiLo4" in table.config.game_type ||
+        "PotLimitOmahaHiLo5" in table.config.game_type;
 
       if (!user?.data || !table || !tableUser) return [0n, 0n];
 
@@ -480,11 +484,15 @@ export const ProvideHUDBettingContext = memo<{ children: ReactNode }>(
 
       const isPotLimit = "PotLimit" in table.config.game_type ||
         "PotLimitOmaha4" in table.config.game_type ||
-        "PotLimitOmaha5" in table.config.game_type;
+        "PotLimitOmaha5" in table.config.game_type ||
+        "PotLimitOmahaHiLo4" in table.config.game_type ||
+        "PotLimitOmahaHiLo5" in table.config.game_type;
 
-      // Check if this is PLO4 or PLO5 specifically (not just any pot limit)
+      // Check if this is an Omaha game specifically (not just any pot limit)
       const isPLO = "PotLimitOmaha4" in table.config.game_type ||
-        "PotLimitOmaha5" in table.config.game_type;
+        "PotLimitOmaha5" in table.config.game_type ||
+        "PotLimitOmahaHiLo4" in table.config.game_type ||
+        "PotLimitOmahaHiLo5" in table.config.game_type;
 
       // For pot limit: always show raise controls on your turn
       // For others: show when we have any raise actions
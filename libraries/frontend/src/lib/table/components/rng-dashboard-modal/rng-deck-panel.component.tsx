@@ -105,12 +105,15 @@ export const RngDeckPanelComponent = memo<{
     async function loadCardProvenance() {
       setLoading(true);
       try {
+        // A page holds a full deck by default
         const result = await tableActor.get_card_provenance_by_round_id(
-          roundId ? [roundId] : []
+          roundId ? [roundId] : [],
+          [],
+          []
         );
         if ("Ok" in result) {
           const provenanceMap = new Map<number, CardProvenance>();
-          result.Ok.forEach((prov) => {
+          result.Ok.provenance.forEach((prov) => {
             provenanceMap.set(prov.shuffled_position, prov);
           });
 
@@ -78,6 +78,12 @@ export const TableSettingsModalComponent = memo<{
               return <ListItem rightLabel="PLO 4-card">Game type</ListItem>;
             if ("PotLimitOmaha5" in table.config.game_type)
               return <ListItem rightLabel="PLO 5-card">Game type</ListItem>;
+            if ("PotLimitOmahaHiLo4" in table.config.game_type)
+              return <ListItem rightLabel="PLO Hi-Lo 4-card">Game type</ListItem>;
+            if ("PotLimitOmahaHiLo5" in table.config.game_type)
+              return <ListItem rightLabel="PLO Hi-Lo 5-card">Game type</ListItem>;
+            if ("ShortDeck" in table.config.game_type)
+              return <ListItem rightLabel="Short deck">Game type</ListItem>;
             if ("SpreadLimit" in table.config.game_type)
               return (
                 <>
@@ -134,6 +140,34 @@ export const TableSettingsModalComponent = memo<{
                   </ListItem>
                 </>
               );
+            if ("SevenCardStud" in table.config.game_type)
+              return (
+                <>
+                  <ListItem rightLabel="Seven Card Stud">Game type</ListItem>
+                  <ListItem
+                    rightLabel={
+                      <CurrencyComponent
+                        currencyType={currency}
+                        variant="inline"
+                        currencyValue={table.config.game_type.SevenCardStud[0]}
+                      />
+                    }
+                  >
+                    Small Bet
+                  </ListItem>
+                  <ListItem
+                    rightLabel={
+                      <CurrencyComponent
+                        currencyType={currency}
+                        variant="inline"
+                        currencyValue={table.config.game_type.SevenCardStud[1]}
+                      />
+                    }
+                  >
+                    Big Bet
+                  </ListItem>
+                </>
+              );
             return <ListItem rightLabel="Unknown">Game type</ListItem>;
           })()}
         </List>
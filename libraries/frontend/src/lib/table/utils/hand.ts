@@ -15,14 +15,17 @@ export const BuildHand = (
   if (!data?.cards) return [];
   if (isSelf) return data.cards;
   if ("Fresh" in table.deal_stage) return data.cards;
+  // In stud the canister only sends the cards other players have face up
+  if (table.config?.game_type && "SevenCardStud" in table.config.game_type)
+    return data.cards;
 
   // Determine number of cards based on game type
   const gameType: GameType | undefined = table.config?.game_type;
   let numCards = 2; // Default for Texas Hold'em
   if (gameType) {
-    if ("PotLimitOmaha4" in gameType) {
+    if ("PotLimitOmaha4" in gameType || "PotLimitOmahaHiLo4" in gameType) {
       numCards = 4;
-    } else if ("PotLimitOmaha5" in gameType) {
+    } else if ("PotLimitOmaha5" in gameType || "PotLimitOmahaHiLo5" in gameType) {
       numCards = 5;
     }
   }
@@ -99,6 +99,9 @@ export const IsSameGameType = (a?: GameType, b?: GameType) => {
   if ("PotLimit" in a) return "PotLimit" in b && a.PotLimit === b.PotLimit;
   if ("PotLimitOmaha4" in a) return "PotLimitOmaha4" in b && a.PotLimitOmaha4 === b.PotLimitOmaha4;
   if ("PotLimitOmaha5" in a) return "PotLimitOmaha5" in b && a.PotLimitOmaha5 === b.PotLimitOmaha5;
+  if ("PotLimitOmahaHiLo4" in a) return "PotLimitOmahaHiLo4" in b && a.PotLimitOmahaHiLo4 === b.PotLimitOmahaHiLo4;
+  if ("PotLimitOmahaHiLo5" in a) return "PotLimitOmahaHiLo5" in b && a.PotLimitOmahaHiLo5 === b.PotLimitOmahaHiLo5;
+  if ("ShortDeck" in a) return "ShortDeck" in b && a.ShortDeck === b.ShortDeck;
   if ("FixedLimit" in a)
     return (
       "FixedLimit" in b &&
@@ -113,5 +116,12 @@ export const IsSameGameType = (a?: GameType, b?: GameType) => {
       a.SpreadLimit[1] === b.SpreadLimit[1]
     );
 
+  if ("SevenCardStud" in a)
+    return (
+      "SevenCardStud" in b &&
+      a.SevenCardStud[0] === b.SevenCardStud[0] &&
+      a.SevenCardStud[1] === b.SevenCardStud[1]
+    );
+
   return false;
 };
@@ -19,6 +19,10 @@ export const GameTypeSerializer = CreateSerializer<GameType>(
       SpreadLimit: (pl): string => [3, pl.join("|")].join(":"),
       PotLimitOmaha4: (pl): string => [4, pl].join(":"),
       PotLimitOmaha5: (pl): string => [5, pl].join(":"),
+      PotLimitOmahaHiLo4: (pl): string => [6, pl].join(":"),
+      PotLimitOmahaHiLo5: (pl): string => [7, pl].join(":"),
+      ShortDeck: (pl): string => [8, pl].join(":"),
+      SevenCardStud: (pl): string => [9, pl.join("|")].join(":"),
     }),
   (value) => {
     const [type, limit] = value.split(":");
@@ -39,6 +43,16 @@ export const GameTypeSerializer = CreateSerializer<GameType>(
         return { PotLimitOmaha4: BigInt(limit) };
       case "5":
         return { PotLimitOmaha5: BigInt(limit) };
+      case "6":
+        return { PotLimitOmahaHiLo4: BigInt(limit) };
+      case "7":
+        return { PotLimitOmahaHiLo5: BigInt(limit) };
+      case "8":
+        return { ShortDeck: BigInt(limit) };
+      case "9": {
+        const SevenCardStud = limit.split("|").map(BigInt) as [bigint, bigint];
+        return { SevenCardStud };
+      }
       default:
         throw new Error(`Invalid game type: ${value}`);
     }
@@ -32,6 +32,10 @@ const SelectGameTypeComponent = memo<{
     PotLimit: bigint | undefined;
     PotLimitOmaha4: bigint | undefined;
     PotLimitOmaha5: bigint | undefined;
+    PotLimitOmahaHiLo4: bigint | undefined;
+    PotLimitOmahaHiLo5: bigint | undefined;
+    ShortDeck: bigint | undefined;
+    SevenCardStud: [bigint | undefined, bigint | undefined];
   }>({
     NoLimit: type && "NoLimit" in type ? type.NoLimit : undefined,
     SpreadLimit:
@@ -41,6 +45,11 @@ const SelectGameTypeComponent = memo<{
     PotLimit: type && "PotLimit" in type ? type.PotLimit : undefined,
     PotLimitOmaha4: type && "PotLimitOmaha4" in type ? type.PotLimitOmaha4 : undefined,
     PotLimitOmaha5: type && "PotLimitOmaha5" in type ? type.PotLimitOmaha5 : undefined,
+    PotLimitOmahaHiLo4: type && "PotLimitOmahaHiLo4" in type ? type.PotLimitOmahaHiLo4 : undefined,
+    PotLimitOmahaHiLo5: type && "PotLimitOmahaHiLo5" in type ? type.PotLimitOmahaHiLo5 : undefined,
+    ShortDeck: type && "ShortDeck" in type ? type.ShortDeck : undefined,
+    SevenCardStud:
+      type && "SevenCardStud" in type ? type.SevenCardStud : [undefined, undefined],
   });
 
   useEffect(() => {
@@ -58,6 +67,13 @@ const SelectGameTypeComponent = memo<{
       PotLimit: type && "PotLimit" in type ? type.PotLimit : undefined,
       PotLimitOmaha4: type && "PotLimitOmaha4" in type ? type.PotLimitOmaha4 : undefined,
       PotLimitOmaha5: type && "PotLimitOmaha5" in type ? type.PotLimitOmaha5 : undefined,
+      PotLimitOmahaHiLo4: type && "PotLimitOmahaHiLo4" in type ? type.PotLimitOmahaHiLo4 : undefined,
+      PotLimitOmahaHiLo5: type && "PotLimitOmahaHiLo5" in type ? type.PotLimitOmahaHiLo5 : undefined,
+      ShortDeck: type && "ShortDeck" in type ? type.ShortDeck : undefined,
+      SevenCardStud:
+        type && "SevenCardStud" in type
+          ? type.SevenCardStud
+          : [undefined, undefined],
     });
   }, [type && GameTypeSerializer.serialize(type)]);
 
@@ -86,6 +102,14 @@ const SelectGameTypeComponent = memo<{
             return;
           game_type = { FixedLimit: nLimits.FixedLimit as [bigint, bigint] };
           break;
+        case "SevenCardStud":
+          if (
+            nLimits.SevenCardStud?.[0] === undefined ||
+            nLimits.SevenCardStud?.[1] === undefined
+          )
+            return;
+          game_type = { SevenCardStud: nLimits.SevenCardStud as [bigint, bigint] };
+          break;
         case "PotLimit":
           game_type = { PotLimit: nLimits.PotLimit as bigint };
           break;
@@ -95,6 +119,15 @@ const SelectGameTypeComponent = memo<{
         case "PotLimitOmaha5":
           game_type = { PotLimitOmaha5: nLimits.PotLimitOmaha5 as bigint };
           break;
+        case "PotLimitOmahaHiLo4":
+          game_type = { PotLimitOmahaHiLo4: nLimits.PotLimitOmahaHiLo4 as bigint };
+          break;
+        case "PotLimitOmahaHiLo5":
+          game_type = { PotLimitOmahaHiLo5: nLimits.PotLimitOmahaHiLo5 as bigint };
+          break;
+        case "ShortDeck":
+          game_type = { ShortDeck: nLimits.ShortDeck as bigint };
+          break;
       }
       onChange(game_type);
     },
@@ -184,6 +217,87 @@ const SelectGameTypeComponent = memo<{
               PotLimitOmaha5: FloatToTokenAmount(values[0] as number, meta),
             }),
         };
+      case "PotLimitOmahaHiLo4":
+        return {
+          fields: [
+            {
+              label: "Small Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+            },
+            {
+              label: "Big Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+              disabled: true,
+            },
+          ],
+          values: [
+            TokenAmountToFloat(limits.PotLimitOmahaHiLo4, meta),
+            TokenAmountToFloat(
+              limits.PotLimitOmahaHiLo4 ? limits.PotLimitOmahaHiLo4 * 2n : undefined,
+              meta,
+            ),
+          ],
+          onChange: (values) =>
+            propagate("PotLimitOmahaHiLo4", {
+              PotLimitOmahaHiLo4: FloatToTokenAmount(values[0] as number, meta),
+            }),
+        };
+      case "PotLimitOmahaHiLo5":
+        return {
+          fields: [
+            {
+              label: "Small Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+            },
+            {
+              label: "Big Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+              disabled: true,
+            },
+          ],
+          values: [
+            TokenAmountToFloat(limits.PotLimitOmahaHiLo5, meta),
+            TokenAmountToFloat(
+              limits.PotLimitOmahaHiLo5 ? limits.PotLimitOmahaHiLo5 * 2n : undefined,
+              meta,
+            ),
+          ],
+          onChange: (values) =>
+            propagate("PotLimitOmahaHiLo5", {
+              PotLimitOmahaHiLo5: FloatToTokenAmount(values[0] as number, meta),
+            }),
+        };
+      case "ShortDeck":
+        return {
+          fields: [
+            {
+              label: "Small Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+            },
+            {
+              label: "Big Blind",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+              disabled: true,
+            },
+          ],
+          values: [
+            TokenAmountToFloat(limits.ShortDeck, meta),
+            TokenAmountToFloat(
+              limits.ShortDeck ? limits.ShortDeck * 2n : undefined,
+              meta,
+            ),
+          ],
+          onChange: (values) =>
+            propagate("ShortDeck", {
+              ShortDeck: FloatToTokenAmount(values[0] as number, meta),
+            }),
+        };
       case "NoLimit":
         return {
           fields: [
@@ -269,6 +383,35 @@ const SelectGameTypeComponent = memo<{
               ],
             }),
         };
+      case "SevenCardStud":
+        return {
+          fields: [
+            {
+              label: "Small Bet",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+            },
+            {
+              label: "Big Bet",
+              type: "number",
+              symbol: <CurrencyIconComponent currency={{ ICP: null }} />,
+            },
+          ],
+          values: limits.SevenCardStud.map((v) =>
+            v === undefined ? undefined : TokenAmountToFloat(v, meta),
+          ),
+          onChange: (values) =>
+            propagate("SevenCardStud", {
+              SevenCardStud: [
+                values[0]
+                  ? FloatToTokenAmount(values[0] as number, meta) ?? undefined
+                  : undefined,
+                values[1]
+                  ? FloatToTokenAmount(values[1] as number, meta) ?? undefined
+                  : undefined,
+              ],
+            }),
+        };
     }
   }, [limits, propagate, selectedGameType]);
 
@@ -290,6 +433,10 @@ const SelectGameTypeComponent = memo<{
           { value: "PotLimit", label: "Pot" },
           { value: "PotLimitOmaha4", label: "PLO 4" },
           { value: "PotLimitOmaha5", label: "PLO 5" },
+          { value: "PotLimitOmahaHiLo4", label: "PLO Hi-Lo 4" },
+          { value: "PotLimitOmahaHiLo5", label: "PLO Hi-Lo 5" },
+          { value: "ShortDeck", label: "Short deck" },
+          { value: "SevenCardStud", label: "Stud" },
         ]}
       />
       {formData && (
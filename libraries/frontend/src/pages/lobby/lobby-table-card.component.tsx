@@ -193,6 +193,12 @@ export const LobbyTableCardComponent = memo<
               return <ListItem rightLabel="PLO 4-card">Game type</ListItem>;
             if ("PotLimitOmaha5" in game_type)
               return <ListItem rightLabel="PLO 5-card">Game type</ListItem>;
+            if ("PotLimitOmahaHiLo4" in game_type)
+              return <ListItem rightLabel="PLO Hi-Lo 4-card">Game type</ListItem>;
+            if ("PotLimitOmahaHiLo5" in game_type)
+              return <ListItem rightLabel="PLO Hi-Lo 5-card">Game type</ListItem>;
+            if ("ShortDeck" in game_type)
+              return <ListItem rightLabel="Short deck">Game type</ListItem>;
             if ("SpreadLimit" in game_type)
               return (
                 <>
@@ -249,6 +255,34 @@ export const LobbyTableCardComponent = memo<
                   </ListItem>
                 </>
               );
+            if ("SevenCardStud" in game_type)
+              return (
+                <>
+                  <ListItem rightLabel="Seven Card Stud">Game type</ListItem>
+                  <ListItem
+                    rightLabel={
+                      <CurrencyComponent
+                        currencyType={config.currency_type}
+                        variant="inline"
+                        currencyValue={game_type.SevenCardStud[0]}
+                      />
+                    }
+                  >
+                    Small bet
+                  </ListItem>
+                  <ListItem
+                    rightLabel={
+                      <CurrencyComponent
+                        currencyType={config.currency_type}
+                        variant="inline"
+                        currencyValue={game_type.SevenCardStud[1]}
+                      />
+                    }
+                  >
+                    Big bet
+                  </ListItem>
+                </>
+              );
             return <ListItem rightLabel="Unknown">Game type</ListItem>;
           })()}
 
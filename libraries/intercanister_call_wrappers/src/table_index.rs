@@ -31,6 +31,34 @@ pub async fn update_table_player_count_wrapper(
     }
 }
 
+pub async fn update_table_waitlist_length_wrapper(
+    backend_principal: Principal,
+    table_id: Principal,
+    waitlist_length: usize,
+) -> Result<(), TableIndexError> {
+    let call_result =
+        ic_cdk::call::Call::unbounded_wait(backend_principal, "update_table_waitlist_length")
+            .with_args(&(table_id, waitlist_length))
+            .await;
+
+    match call_result {
+        Ok(res) => match res.candid() {
+            Ok(res) => res,
+            Err(err) => {
+                ic_cdk::println!("Error updating table waitlist length: {:?}", err);
+                Err(TableIndexError::CanisterCallError(format!(
+                    "Failed to decode update_table_waitlist_length response: {:?}",
+                    err
+                )))
+            }
+        },
+        Err(err) => {
+            ic_cdk::println!("Error in update_table_waitlist_length call: {:?}", err);
+            Err(TableIndexError::CanisterCallError(format!("{:?}", err)))
+        }
+    }
+}
+
 pub async fn get_rake_stats(table_id: TableId) -> Result<RakeStats, TableError> {
     let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "get_rake_stats").await;
 
@@ -66,7 +66,7 @@ impl Value {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Value;
+    /// use poker_core::Value;
     /// assert_eq!(Value::Four, Value::from_u8(Value::Four as u8));
     /// ```
     pub fn from_u8(v: u8) -> Self {
@@ -89,7 +89,7 @@ impl Value {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Value;
+    /// use poker_core::Value;
     ///
     /// assert_eq!(Value::Ace, Value::from_char('A').unwrap());
     /// ```
@@ -107,7 +107,7 @@ impl Value {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Value;
+    /// use poker_core::Value;
     /// assert_eq!(1, Value::Ace.gap(Value::King));
     /// ```
     pub fn gap(self, other: Self) -> u8 {
@@ -127,7 +127,7 @@ impl TryFrom<char> for Value {
     type Error = RSPokerError;
 
     /// ```
-    /// use table::poker::core::*;
+    /// use poker_core::*;
     /// use std::convert::TryFrom;
     ///
     /// assert_eq!(Value::Jack, Value::try_from('j').unwrap());
@@ -179,7 +179,7 @@ impl From<Value> for char {
 ///
 /// Ace is a high card in our counting so it's the max value 12
 /// ```
-/// use table::poker::core::Value;
+/// use poker_core::Value;
 /// let v = Value::try_from('A').unwrap();
 /// assert_eq!(Value::Ace, v);
 /// assert_eq!(12, u8::from(v));
@@ -187,7 +187,7 @@ impl From<Value> for char {
 ///
 /// Values are zero indexed with 2 being the lowest value.
 /// ```
-/// use table::poker::core::Value;
+/// use poker_core::Value;
 /// let v = Value::try_from('4').unwrap();
 /// assert_eq!(Value::Four, v);
 /// assert_eq!(2, u8::from(v));
@@ -227,7 +227,7 @@ impl Suit {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Suit;
+    /// use poker_core::Suit;
     /// let suits = Suit::suits();
     /// assert_eq!(4, suits.len());
     /// ```
@@ -240,7 +240,7 @@ impl Suit {
     ///
     /// #Examples
     /// ```
-    /// use table::poker::core::Suit;
+    /// use poker_core::Suit;
     /// let idx = Suit::Club as u8;
     /// assert_eq!(Suit::Club, Suit::from_u8(idx));
     /// ```
@@ -254,14 +254,14 @@ impl Suit {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Suit;
+    /// use poker_core::Suit;
     ///
     /// let s = Suit::from_char('s');
     /// assert_eq!(Some(Suit::Spade), s);
     /// ```
     ///
     /// ```
-    /// use table::poker::core::Suit;
+    /// use poker_core::Suit;
     ///
     /// let s = Suit::from_char('X');
     /// assert_eq!(None, s);
@@ -23,7 +23,7 @@ impl CardBitSet {
     /// Create a new empty bitset
     ///
     /// ```
-    /// use table::poker::core::CardBitSet;
+    /// use poker_core::CardBitSet;
     /// let cards = CardBitSet::new();
     /// assert!(cards.is_empty());
     /// ```
@@ -34,7 +34,7 @@ impl CardBitSet {
     /// This does what it says on the tin it insertes a card into the bitset
     ///
     /// ```
-    /// use table::poker::core::{Card, CardBitSet, Deck, Suit, Value};
+    /// use poker_core::{Card, CardBitSet, Deck, Suit, Value};
     /// let mut cards = CardBitSet::new();
     ///
     /// cards.insert(Card::new(Value::Six, Suit::Club));
@@ -49,7 +49,7 @@ impl CardBitSet {
     /// Remove a card from the bitset
     ///
     /// ```
-    /// use table::poker::core::{Card, CardBitSet, Deck, Suit, Value};
+    /// use poker_core::{Card, CardBitSet, Deck, Suit, Value};
     /// let mut cards = CardBitSet::new();
     /// cards.insert(Card::from(17));
     ///
@@ -71,7 +71,7 @@ impl CardBitSet {
     /// Is the card in the bitset ?
     ///
     /// ```
-    /// use table::poker::core::{Card, CardBitSet, Deck, Suit, Value};
+    /// use poker_core::{Card, CardBitSet, Deck, Suit, Value};
     ///
     /// let mut cards = CardBitSet::new();
     /// cards.insert(Card::from(17));
@@ -85,7 +85,7 @@ impl CardBitSet {
     /// Is the bitset empty ?
     ///
     /// ```
-    /// use table::poker::core::{Card, CardBitSet};
+    /// use poker_core::{Card, CardBitSet};
     ///
     /// let mut cards = CardBitSet::new();
     /// assert!(cards.is_empty());
@@ -100,7 +100,7 @@ impl CardBitSet {
     /// How many cards are in the bitset ?
     ///
     /// ```
-    /// use table::poker::core::{Card, CardBitSet};
+    /// use poker_core::{Card, CardBitSet};
     /// let mut cards = CardBitSet::new();
     ///
     /// assert_eq!(0, cards.count());
@@ -112,12 +112,33 @@ impl CardBitSet {
     pub fn count(&self) -> usize {
         self.cards.count_ones() as usize
     }
+
+    /// The values of the cards in each suit, one bit per value.
+    ///
+    /// ```
+    /// use poker_core::{Card, CardBitSet, Suit, Value};
+    ///
+    /// let mut cards = CardBitSet::new();
+    /// cards.insert(Card::new(Value::Two, Suit::Club));
+    /// cards.insert(Card::new(Value::Ace, Suit::Club));
+    ///
+    /// assert_eq!([0, 0b1_0000_0000_0001, 0, 0], cards.suit_value_sets());
+    /// ```
+    pub fn suit_value_sets(&self) -> [u32; 4] {
+        let value_mask = (1 << 13) - 1;
+        [
+            (self.cards & value_mask) as u32,
+            ((self.cards >> 13) & value_mask) as u32,
+            ((self.cards >> 26) & value_mask) as u32,
+            ((self.cards >> 39) & value_mask) as u32,
+        ]
+    }
 }
 
 impl Default for CardBitSet {
     /// Create a new bitset with all the cards in it
     /// ```
-    /// use table::poker::core::CardBitSet;
+    /// use poker_core::CardBitSet;
     ///
     /// let cards = CardBitSet::default();
     ///
@@ -131,6 +152,16 @@ impl Default for CardBitSet {
     }
 }
 
+impl FromIterator<Card> for CardBitSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut cards = Self::new();
+        for card in iter {
+            cards.insert(card);
+        }
+        cards
+    }
+}
+
 // Trait for converting a CardBitSet into a FlatDeck
 // Create the vec for storage and then return the flatdeck
 impl From<CardBitSet> for FlatDeck {
@@ -250,7 +281,7 @@ impl Iterator for CardBitSetIter {
 mod tests {
     // use std::collections::HashSet;
 
-    use crate::poker::core::Deck;
+    use crate::Deck;
 
     use super::*;
 
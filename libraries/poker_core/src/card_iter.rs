@@ -1,4 +1,4 @@
-use crate::poker::core::*;
+use crate::*;
 
 /// Given some cards create sets of possible groups of cards.
 #[derive(Debug)]
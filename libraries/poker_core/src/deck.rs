@@ -1,4 +1,4 @@
-use crate::poker::core::card::{Card, Suit, Value};
+use crate::card::{Card, Suit, Value};
 use std::collections::hash_set::{IntoIter, Iter};
 use std::collections::HashSet;
 
@@ -7,7 +7,7 @@ use std::collections::HashSet;
 /// # Examples
 ///
 /// ```
-/// use table::poker::core::{Card, Deck, Suit, Value};
+/// use poker_core::{Card, Deck, Suit, Value};
 ///
 /// // create a new deck
 /// let mut deck = Deck::new();
@@ -90,7 +90,7 @@ impl Default for Deck {
     /// Create the default 52 card deck
     ///
     /// ```
-    /// use table::poker::core::Deck;
+    /// use poker_core::Deck;
     ///
     /// assert_eq!(52, Deck::default().len());
     /// ```
@@ -0,0 +1,505 @@
+use candid::CandidType;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{Card, CardBitSet, CardIter, RSPokerError, Rank};
+
+/// Equities are given in hundredths of a percent, so 10_000 is 100%.
+pub const EQUITY_SCALE: u32 = 10_000;
+/// The most boards that are enumerated before falling back to Monte Carlo.
+pub const MAX_EXHAUSTIVE_BOARDS: u64 = 50_000;
+/// The number of boards sampled when there are too many to enumerate.
+pub const MONTE_CARLO_BOARDS: u64 = 10_000;
+/// The most hands equity is calculated for, as many as a full ring table.
+pub const MAX_EQUITY_HANDS: usize = 10;
+
+/// The games equities can be calculated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, CandidType)]
+pub enum EquityGame {
+    /// Two hole cards, using any five of the seven cards.
+    Holdem,
+    /// Four or five hole cards, using exactly two of them with three from the board.
+    Omaha,
+}
+
+/// How often one player wins the pot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, CandidType)]
+pub struct Equity {
+    /// The share of boards the player wins outright, in hundredths of a percent.
+    pub win: u32,
+    /// The share of boards the player ties for the best hand, in hundredths of a percent.
+    pub tie: u32,
+    /// The share of the pot the player can expect, with ties split, in hundredths of a percent.
+    pub equity: u32,
+    /// The cards that put the player ahead when they come next and they are behind now.
+    ///
+    /// Only filled in on the flop and turn.
+    pub outs: Vec<Card>,
+}
+
+/// The equities of every hand, in the order the hands were given.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, CandidType)]
+pub struct EquityResult {
+    pub equities: Vec<Equity>,
+    /// The number of boards that were looked at.
+    pub boards: u64,
+    /// Whether every possible board was looked at instead of a random sample.
+    pub is_exhaustive: bool,
+}
+
+/// A hand split into the cards it can be made up from.
+struct HoleCards {
+    /// Every set of hole cards the hand can use.
+    combinations: Vec<CardBitSet>,
+}
+
+impl HoleCards {
+    fn new(game: EquityGame, cards: &[Card]) -> Self {
+        let combinations = match game {
+            EquityGame::Holdem => vec![cards.iter().copied().collect()],
+            EquityGame::Omaha => CardIter::new(cards, 2)
+                .map(|pair| pair.into_iter().collect())
+                .collect(),
+        };
+        HoleCards { combinations }
+    }
+
+    /// The best rank the hand makes with any of the board's combinations.
+    fn rank(&self, board_combinations: &[CardBitSet]) -> Rank {
+        self.combinations
+            .iter()
+            .flat_map(|hole| board_combinations.iter().map(move |board| *hole | *board))
+            .map(|cards| cards.rank())
+            .max()
+            .unwrap_or(Rank::HighCard(0))
+    }
+}
+
+/// Every set of board cards a hand can use, worked out once per board for all the hands.
+fn board_combinations(game: EquityGame, board: &[Card]) -> Vec<CardBitSet> {
+    match game {
+        EquityGame::Omaha if board.len() > 3 => CardIter::new(board, 3)
+            .map(|triple| triple.into_iter().collect())
+            .collect(),
+        _ => vec![board.iter().copied().collect()],
+    }
+}
+
+/// The hands that make the best rank on a board.
+fn get_winners(game: EquityGame, hands: &[HoleCards], board: &[Card]) -> Vec<usize> {
+    let board_combinations = board_combinations(game, board);
+    let ranks: Vec<Rank> = hands
+        .iter()
+        .map(|hand| hand.rank(&board_combinations))
+        .collect();
+    let best = ranks.iter().max().copied();
+    ranks
+        .iter()
+        .enumerate()
+        .filter(|(_, rank)| Some(**rank) == best)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// The number of ways to pick `k` of `n` cards, capped at `u64::MAX`.
+fn combinations(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1u64, |total, i| {
+        total.saturating_mul((n - i) as u64) / (i as u64 + 1)
+    })
+}
+
+fn to_scale(count: f64, boards: u64) -> u32 {
+    if boards == 0 {
+        return 0;
+    }
+    (count * EQUITY_SCALE as f64 / boards as f64).round() as u32
+}
+
+/// Calculates how often each hand wins once the board is dealt out.
+///
+/// Every possible board is enumerated when there are at most
+/// [`MAX_EXHAUSTIVE_BOARDS`] of them. Otherwise [`MONTE_CARLO_BOARDS`] boards are
+/// sampled with a random number generator seeded with `seed`, so the same inputs
+/// always give the same result.
+///
+/// # Parameters
+///
+/// - `game` - The game the hands are played in
+/// - `hands` - The known hole cards of every player still in the hand
+/// - `board` - The community cards dealt so far
+/// - `dead_cards` - Cards that are known to be out of the deck, such as folded hands
+/// - `seed` - The seed for the Monte Carlo sample
+///
+/// # Errors
+///
+/// - [`RSPokerError::DuplicateCardInHand`] if a card is given more than once
+/// - [`RSPokerError::Other`] if there are fewer than two or more than [`MAX_EQUITY_HANDS`]
+///   hands, a hand has the wrong number of cards or the board is not a flop, turn or river
+///
+/// # Examples
+/// ```
+/// use poker_core::{calculate_equity, EquityGame, Hand};
+///
+/// let aces = Hand::new_from_str("AsAh").unwrap().iter().copied().collect();
+/// let kings = Hand::new_from_str("KsKh").unwrap().iter().copied().collect();
+/// let board: Vec<_> = Hand::new_from_str("2c7d9s").unwrap().iter().copied().collect();
+///
+/// let result = calculate_equity(EquityGame::Holdem, &[aces, kings], &board, &[], 0).unwrap();
+/// assert!(result.is_exhaustive);
+/// assert!(result.equities[0].equity > result.equities[1].equity);
+/// ```
+pub fn calculate_equity(
+    game: EquityGame,
+    hands: &[Vec<Card>],
+    board: &[Card],
+    dead_cards: &[Card],
+    seed: u64,
+) -> Result<EquityResult, RSPokerError> {
+    if hands.len() < 2 {
+        return Err(RSPokerError::Other(
+            "At least two hands are needed to calculate equity".to_string(),
+        ));
+    }
+    if hands.len() > MAX_EQUITY_HANDS {
+        return Err(RSPokerError::Other(format!(
+            "Equity can be calculated for at most {} hands",
+            MAX_EQUITY_HANDS
+        )));
+    }
+    for hand in hands {
+        let is_valid = match game {
+            EquityGame::Holdem => hand.len() == 2,
+            EquityGame::Omaha => hand.len() == 4 || hand.len() == 5,
+        };
+        if !is_valid {
+            return Err(RSPokerError::Other(format!(
+                "A hand of {} cards cannot be played in {:?}",
+                hand.len(),
+                game
+            )));
+        }
+    }
+    if !matches!(board.len(), 0 | 3 | 4 | 5) {
+        return Err(RSPokerError::Other(
+            "The board has to be empty or have three, four or five cards".to_string(),
+        ));
+    }
+
+    let mut seen = CardBitSet::new();
+    for card in hands.iter().flatten().chain(board).chain(dead_cards) {
+        if seen.contains(*card) {
+            return Err(RSPokerError::DuplicateCardInHand(*card));
+        }
+        seen.insert(*card);
+    }
+
+    // Built from the card indices rather than a `Deck` so the order, and with it
+    // the Monte Carlo sample, is the same every time.
+    let remaining: Vec<Card> = (0..52u8)
+        .map(Card::from)
+        .filter(|card| !seen.contains(*card))
+        .collect();
+    let missing = 5 - board.len();
+    if remaining.len() < missing {
+        return Err(RSPokerError::Other(
+            "Not enough cards left to deal the board".to_string(),
+        ));
+    }
+
+    let hole_cards: Vec<HoleCards> = hands
+        .iter()
+        .map(|hand| HoleCards::new(game, hand))
+        .collect();
+    let mut wins = vec![0u64; hands.len()];
+    let mut ties = vec![0u64; hands.len()];
+    let mut shares = vec![0f64; hands.len()];
+    let mut full_board = board.to_vec();
+    let mut tally = |full_board: &[Card]| {
+        let winners = get_winners(game, &hole_cards, full_board);
+        for &winner in &winners {
+            if winners.len() == 1 {
+                wins[winner] += 1;
+            } else {
+                ties[winner] += 1;
+            }
+            shares[winner] += 1.0 / winners.len() as f64;
+        }
+    };
+
+    let exhaustive_boards = combinations(remaining.len(), missing);
+    let is_exhaustive = exhaustive_boards <= MAX_EXHAUSTIVE_BOARDS;
+    let boards = if missing == 0 {
+        tally(&full_board);
+        1
+    } else if is_exhaustive {
+        for run_out in CardIter::new(&remaining, missing) {
+            full_board.truncate(board.len());
+            full_board.extend(run_out);
+            tally(&full_board);
+        }
+        exhaustive_boards
+    } else {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let mut deck = remaining.clone();
+        for _ in 0..MONTE_CARLO_BOARDS {
+            // Partial Fisher-Yates shuffle of the cards that are dealt
+            for i in 0..missing {
+                let j = rng.gen_range(i..deck.len());
+                deck.swap(i, j);
+            }
+            full_board.truncate(board.len());
+            full_board.extend_from_slice(&deck[..missing]);
+            tally(&full_board);
+        }
+        MONTE_CARLO_BOARDS
+    };
+
+    let outs = get_outs(game, &hole_cards, board, &remaining);
+    let equities = (0..hands.len())
+        .map(|index| Equity {
+            win: to_scale(wins[index] as f64, boards),
+            tie: to_scale(ties[index] as f64, boards),
+            equity: to_scale(shares[index], boards),
+            outs: outs[index].clone(),
+        })
+        .collect();
+
+    Ok(EquityResult {
+        equities,
+        boards,
+        is_exhaustive,
+    })
+}
+
+/// The cards that take each hand from behind to the best hand when they come next.
+///
+/// Outs are only counted on the flop and turn, when a single card is still to come
+/// before the next street.
+fn get_outs(
+    game: EquityGame,
+    hands: &[HoleCards],
+    board: &[Card],
+    remaining: &[Card],
+) -> Vec<Vec<Card>> {
+    let mut outs = vec![Vec::new(); hands.len()];
+    if board.len() != 3 && board.len() != 4 {
+        return outs;
+    }
+
+    let current_winners = get_winners(game, hands, board);
+    let mut next_board = board.to_vec();
+    for card in remaining {
+        next_board.truncate(board.len());
+        next_board.push(*card);
+        for winner in get_winners(game, hands, &next_board) {
+            if !current_winners.contains(&winner) {
+                outs[winner].push(*card);
+            }
+        }
+    }
+    outs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hand;
+
+    fn cards(cards: &str) -> Vec<Card> {
+        Hand::new_from_str(cards).unwrap().iter().copied().collect()
+    }
+
+    fn total_equity(result: &EquityResult) -> u32 {
+        result.equities.iter().map(|equity| equity.equity).sum()
+    }
+
+    #[test]
+    fn test_combinations() {
+        assert_eq!(combinations(44, 2), 946);
+        assert_eq!(combinations(48, 5), 1_712_304);
+        assert_eq!(combinations(10, 0), 1);
+        assert_eq!(combinations(2, 3), 0);
+    }
+
+    #[test]
+    fn test_complete_board_has_one_winner() {
+        let result = calculate_equity(
+            EquityGame::Holdem,
+            &[cards("AsAh"), cards("KsKh")],
+            &cards("2c7d9sJh3c"),
+            &[],
+            0,
+        )
+        .unwrap();
+        assert_eq!(result.boards, 1);
+        assert!(result.is_exhaustive);
+        assert_eq!(result.equities[0].win, EQUITY_SCALE);
+        assert_eq!(result.equities[1].equity, 0);
+    }
+
+    #[test]
+    fn test_river_outs_on_the_turn() {
+        // Kings need one of the two kings left in the deck
+        let result = calculate_equity(
+            EquityGame::Holdem,
+            &[cards("AsAh"), cards("KsKh")],
+            &cards("2c7d9sJh"),
+            &[],
+            0,
+        )
+        .unwrap();
+        assert_eq!(result.boards, 44);
+        assert_eq!(result.equities[1].win, to_scale(2.0, 44));
+        assert_eq!(result.equities[1].outs.len(), 2);
+        assert!(result.equities[0].outs.is_empty());
+        assert_eq!(total_equity(&result), EQUITY_SCALE);
+    }
+
+    #[test]
+    fn test_dead_cards_are_not_dealt() {
+        let result = calculate_equity(
+            EquityGame::Holdem,
+            &[cards("AsAh"), cards("KsKh")],
+            &cards("2c7d9sJh"),
+            &cards("KcKd"),
+            0,
+        )
+        .unwrap();
+        assert_eq!(result.boards, 42);
+        assert_eq!(result.equities[0].win, EQUITY_SCALE);
+        assert!(result.equities[1].outs.is_empty());
+    }
+
+    #[test]
+    fn test_split_pot_is_shared() {
+        // Both players play the broadway straight on the board
+        let result = calculate_equity(
+            EquityGame::Holdem,
+            &[cards("2s3h"), cards("2d3c")],
+            &cards("AcKdQhJsTc"),
+            &[],
+            0,
+        )
+        .unwrap();
+        for equity in &result.equities {
+            assert_eq!(equity.win, 0);
+            assert_eq!(equity.tie, EQUITY_SCALE);
+            assert_eq!(equity.equity, EQUITY_SCALE / 2);
+        }
+    }
+
+    #[test]
+    fn test_preflop_uses_seeded_monte_carlo() {
+        let hands = [cards("AsAh"), cards("7c2d")];
+        let result = calculate_equity(EquityGame::Holdem, &hands, &[], &[], 42).unwrap();
+        assert!(!result.is_exhaustive);
+        assert_eq!(result.boards, MONTE_CARLO_BOARDS);
+        // Aces are close to 88% against seven deuce
+        assert!((8_500..9_100).contains(&result.equities[0].equity));
+        assert_eq!(
+            calculate_equity(EquityGame::Holdem, &hands, &[], &[], 42).unwrap(),
+            result
+        );
+    }
+
+    #[test]
+    fn test_omaha_must_use_two_hole_cards() {
+        // Four spades on the board, but the first hand only holds one spade
+        let result = calculate_equity(
+            EquityGame::Omaha,
+            &[cards("As2h3d4c"), cards("KhKdQcJc")],
+            &cards("5s8s9sTsKc"),
+            &[],
+            0,
+        )
+        .unwrap();
+        assert_eq!(result.equities[1].win, EQUITY_SCALE);
+
+        let result = calculate_equity(
+            EquityGame::Omaha,
+            &[cards("As2s3d4c5h"), cards("KhKdQcJc")],
+            &cards("5s8s9sTd"),
+            &[],
+            0,
+        )
+        .unwrap();
+        assert!(result.is_exhaustive);
+        assert_eq!(total_equity(&result), EQUITY_SCALE);
+        assert!(result.equities[0]
+            .outs
+            .iter()
+            .all(|card| !cards("5s8s9sTd").contains(card)));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let board = cards("2c7d9s");
+        assert!(matches!(
+            calculate_equity(EquityGame::Holdem, &[cards("AsAh")], &board, &[], 0),
+            Err(RSPokerError::Other(_))
+        ));
+        assert!(matches!(
+            calculate_equity(
+                EquityGame::Holdem,
+                &[cards("AsAh"), cards("KsKhKd")],
+                &board,
+                &[],
+                0
+            ),
+            Err(RSPokerError::Other(_))
+        ));
+        assert!(matches!(
+            calculate_equity(
+                EquityGame::Omaha,
+                &[cards("AsAh"), cards("KsKh")],
+                &board,
+                &[],
+                0
+            ),
+            Err(RSPokerError::Other(_))
+        ));
+        assert!(matches!(
+            calculate_equity(
+                EquityGame::Holdem,
+                &[cards("AsAh"), cards("As2h")],
+                &board,
+                &[],
+                0
+            ),
+            Err(RSPokerError::DuplicateCardInHand(_))
+        ));
+        assert!(matches!(
+            calculate_equity(
+                EquityGame::Holdem,
+                &[cards("AsAh"), cards("KsKh")],
+                &board,
+                &cards("2c"),
+                0
+            ),
+            Err(RSPokerError::DuplicateCardInHand(_))
+        ));
+        assert!(matches!(
+            calculate_equity(
+                EquityGame::Holdem,
+                &[cards("AsAh"), cards("KsKh")],
+                &cards("2c7d"),
+                &[],
+                0
+            ),
+            Err(RSPokerError::Other(_))
+        ));
+
+        let hands: Vec<Vec<Card>> = (0..MAX_EQUITY_HANDS as u8 + 1)
+            .map(|index| vec![Card::from(index * 2), Card::from(index * 2 + 1)])
+            .collect();
+        assert!(matches!(
+            calculate_equity(EquityGame::Holdem, &hands, &[], &[], 0),
+            Err(RSPokerError::Other(_))
+        ));
+        assert!(calculate_equity(EquityGame::Holdem, &hands[1..], &[], &[], 0).is_ok());
+    }
+}
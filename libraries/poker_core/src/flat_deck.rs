@@ -1,5 +1,5 @@
-use crate::poker::core::card::{Card, Suit, Value};
-use crate::poker::core::deck::Deck;
+use crate::card::{Card, Suit, Value};
+use crate::deck::Deck;
 use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
 
 use candid::CandidType;
@@ -170,12 +170,33 @@ impl FlatDeck {
 
         fdeck
     }
+
+    /// Create a new shuffled 36 card short deck (sixes through aces) from random bytes.
+    ///
+    /// Cards are generated in the same deterministic sorted order as `new`,
+    /// just without the twos through fives.
+    pub fn new_short_deck(bytes: Vec<u8>) -> Self {
+        let mut cards = Vec::with_capacity(36);
+        for v in Value::values().iter().filter(|v| **v >= Value::Six) {
+            for s in &Suit::suits() {
+                cards.push(Card {
+                    value: *v,
+                    suit: *s,
+                });
+            }
+        }
+        // Cards are now in sorted order (SixSpade, SixClub, ..., AceDiamond)
+        let mut fdeck = Self { cards };
+        fdeck.shuffle(bytes);
+
+        fdeck
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::poker::core::card::{Suit, Value};
+    use crate::card::{Suit, Value};
 
     #[test]
     fn test_deck_from() {
@@ -189,6 +210,13 @@ mod tests {
         assert_eq!(52, fd.len());
     }
 
+    #[test]
+    fn test_short_deck_new() {
+        let fd: FlatDeck = FlatDeck::new_short_deck(vec![1, 2, 3, 4, 5]);
+        assert_eq!(36, fd.len());
+        assert!(fd.cards().iter().all(|c| c.value >= Value::Six));
+    }
+
     #[test]
     fn test_from_vec() {
         let c = Card {
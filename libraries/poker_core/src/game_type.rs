@@ -0,0 +1,77 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use crate::{EquityGame, FlatDeck};
+
+/// The different variants of Texas Hold'em.
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
+pub enum GameType {
+    /// Players can bet any amount.
+    NoLimit(u64),
+    /// Players can only bet a fixed amount.
+    FixedLimit(u64, u64), // (small_bet, big_bet)
+    // Players must bet between min_bet and max_bet
+    SpreadLimit(u64, u64), // (min_bet, max_bet)
+    // Players can bet up to the amount in the pot.
+    PotLimit(u64),
+    // Pot Limit Omaha 4-card
+    PotLimitOmaha4(u64),
+    // Pot Limit Omaha 5-card
+    PotLimitOmaha5(u64),
+    // Pot Limit Omaha 4-card Hi-Lo (eight or better)
+    PotLimitOmahaHiLo4(u64),
+    // Pot Limit Omaha 5-card Hi-Lo (eight or better)
+    PotLimitOmahaHiLo5(u64),
+    // No limit Short Deck (6+) Hold'em played with a 36-card deck
+    ShortDeck(u64),
+    // Fixed limit Seven Card Stud with a bring-in
+    SevenCardStud(u64, u64), // (small_bet, big_bet)
+}
+
+impl GameType {
+    /// Creates the shuffled deck this game type is dealt from.
+    pub fn new_deck(&self, bytes: Vec<u8>) -> FlatDeck {
+        match self {
+            GameType::ShortDeck(_) => FlatDeck::new_short_deck(bytes),
+            _ => FlatDeck::new(bytes),
+        }
+    }
+
+    /// The game equities are calculated for, if they can be for this game type.
+    ///
+    /// Hi-lo, short deck and stud hands are ranked differently, so they have none.
+    pub fn equity_game(&self) -> Option<EquityGame> {
+        match self {
+            GameType::NoLimit(_)
+            | GameType::FixedLimit(_, _)
+            | GameType::SpreadLimit(_, _)
+            | GameType::PotLimit(_) => Some(EquityGame::Holdem),
+            GameType::PotLimitOmaha4(_) | GameType::PotLimitOmaha5(_) => Some(EquityGame::Omaha),
+            GameType::PotLimitOmahaHiLo4(_)
+            | GameType::PotLimitOmahaHiLo5(_)
+            | GameType::ShortDeck(_)
+            | GameType::SevenCardStud(_, _) => None,
+        }
+    }
+}
+
+/// The different stages of a deal.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
+pub enum DealStage {
+    /// Start a new deal.
+    Fresh = 0,
+    /// Place the blinds.
+    Blinds = 1,
+    /// Deal 2 cards to each player.
+    Opening = 2,
+    /// Deal 3 community cards.
+    Flop = 3,
+    /// Deal 1 community card.
+    Turn = 4,
+    /// Deal 1 community card.
+    River = 5,
+    /// Showdown stage.
+    Showdown = 6,
+    /// Deal the last down card to each player in Seven Card Stud.
+    SeventhStreet = 7,
+}
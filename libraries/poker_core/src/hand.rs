@@ -1,7 +1,7 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 
-use crate::poker::core::card::*;
+use crate::card::*;
 use std::ops::Index;
 use std::ops::{RangeFrom, RangeFull, RangeTo};
 use std::slice::Iter;
@@ -25,14 +25,14 @@ impl Hand {
     /// # Examples
     ///
     /// ```
-    /// use table::poker::core::Hand;
+    /// use poker_core::Hand;
     /// let hand = Hand::new_from_str("AdKd").unwrap();
     /// ```
     ///
     /// Anything that can't be parsed will return an error.
     ///
     /// ```
-    /// use table::poker::core::Hand;
+    /// use poker_core::Hand;
     /// let hand = Hand::new_from_str("AdKx");
     /// assert!(hand.is_err());
     /// ```
@@ -0,0 +1,76 @@
+//! Cards, decks and hand rankings, without anything of the canisters.
+//!
+//! The table deals and ranks with these, and the fairness verifier rebuilds
+//! decks with them, so it does not have to depend on the table.
+
+mod error;
+pub use self::error::RSPokerError;
+/// card.rs has value and suit.
+mod card;
+/// Re-export Card, Value, and Suit
+pub use self::card::{Card, Suit, Value};
+
+/// Code related to cards in hands.
+mod hand;
+/// Everything in there should be public.
+pub use self::hand::*;
+
+/// We want to be able to iterate over five card hands.
+mod card_iter;
+/// Make that functionality public.
+pub use self::card_iter::*;
+
+/// Deck is the normal 52 card deck.
+mod deck;
+/// Export `Deck`
+pub use self::deck::Deck;
+
+/// Flattened deck
+mod flat_deck;
+/// Export the trait and the result.
+pub use self::flat_deck::FlatDeck;
+
+/// 5 Card hand ranking code.
+mod rank;
+/// Export the trait and the results.
+pub use self::rank::{Rank, Rankable};
+
+/// Lookup table hand ranking on card bit sets.
+mod lookup_rank;
+
+/// Win and tie percentages of known hands.
+mod equity;
+/// Export the calculator and its results.
+pub use self::equity::{
+    calculate_equity, Equity, EquityGame, EquityResult, EQUITY_SCALE, MAX_EQUITY_HANDS,
+    MAX_EXHAUSTIVE_BOARDS, MONTE_CARLO_BOARDS,
+};
+
+/// Eight-or-better low hand ranking code for hi-lo games.
+mod low_rank;
+/// Export the trait and the result.
+pub use self::low_rank::{LowRank, LowRankable};
+
+/// Short deck (6+) hand ranking code.
+mod short_deck_rank;
+/// Export the trait and the comparable rank.
+pub use self::short_deck_rank::{ShortDeckRank, ShortDeckRankable};
+
+// u16 backed player set.
+mod player_bit_set;
+// u64 backed card set.
+mod card_bit_set;
+// Export the bit set and the iterator
+pub use self::player_bit_set::{ActivePlayerBitSetIter, PlayerBitSet};
+// Export the bit set and the iterator used for cards (52 cards so u64 backed)
+pub use self::card_bit_set::{CardBitSet, CardBitSetIter};
+
+/// The games a table can deal and the stages of a deal.
+mod game_type;
+/// Export the game types and deal stages.
+pub use self::game_type::{DealStage, GameType};
+
+/// What the hand evaluators cost, natively or in a canister.
+mod measure;
+/// Export the measurement.
+pub use self::measure::{measure_hand_evaluators, HandEvaluatorMeasurement};
@@ -0,0 +1,208 @@
+use crate::{CardBitSet, Rank};
+
+/// Number of different sets of the 13 card values.
+const VALUE_SETS: usize = 1 << 13;
+/// Marks a set of values without a straight in [`STRAIGHTS`].
+const NO_STRAIGHT: u8 = u8::MAX;
+/// Bit mask for the wheel (Ace, two, three, four, five)
+const WHEEL: usize = 0b1_0000_0000_1111;
+
+/// The straight rank of every set of values, wheel being 0 and broadway 9.
+static STRAIGHTS: [u8; VALUE_SETS] = build_straights();
+/// The 1 to 5 highest values of every set of values, indexed by how many to keep minus one.
+static HIGHEST_VALUES: [[u16; VALUE_SETS]; 5] = build_highest_values();
+
+const fn build_straights() -> [u8; VALUE_SETS] {
+    let mut straights = [NO_STRAIGHT; VALUE_SETS];
+    let mut value_set = 0;
+    while value_set < VALUE_SETS {
+        // Look for five values in a row starting from the ace high straight
+        let mut high = 12;
+        while high >= 4 {
+            let straight = 0b1_1111 << (high - 4);
+            if value_set & straight == straight {
+                straights[value_set] = high as u8 - 3;
+                break;
+            }
+            high -= 1;
+        }
+        if straights[value_set] == NO_STRAIGHT && value_set & WHEEL == WHEEL {
+            straights[value_set] = 0;
+        }
+        value_set += 1;
+    }
+    straights
+}
+
+const fn build_highest_values() -> [[u16; VALUE_SETS]; 5] {
+    let mut highest_values = [[0; VALUE_SETS]; 5];
+    let mut value_set = 0;
+    while value_set < VALUE_SETS {
+        let mut to_keep = 1;
+        while to_keep <= 5 {
+            // Remove the lowest values until only `to_keep` are left
+            let mut kept = value_set as u16;
+            while kept.count_ones() > to_keep {
+                kept &= kept - 1;
+            }
+            highest_values[to_keep as usize - 1][value_set] = kept;
+            to_keep += 1;
+        }
+        value_set += 1;
+    }
+    highest_values
+}
+
+fn straight(value_set: u32) -> Option<u32> {
+    match STRAIGHTS[value_set as usize] {
+        NO_STRAIGHT => None,
+        rank => Some(rank as u32),
+    }
+}
+
+fn keep_n(value_set: u32, to_keep: usize) -> u32 {
+    HIGHEST_VALUES[to_keep - 1][value_set as usize] as u32
+}
+
+impl CardBitSet {
+    /// Rank the cards to find the best 5 card hand using lookup tables
+    /// instead of counting every card.
+    ///
+    /// This gives the same [`Rank`] as [`Rankable::rank`](crate::Rankable::rank)
+    /// for five to seven cards. Hands with fewer cards are ranked on the cards they have.
+    ///
+    /// # Examples
+    /// ```
+    /// use poker_core::{CardBitSet, Hand, Rank, Rankable};
+    ///
+    /// let hand = Hand::new_from_str("2h2d8d8sKd6sTh").unwrap();
+    /// let cards: CardBitSet = hand.iter().copied().collect();
+    /// assert_eq!(cards.rank(), hand.rank());
+    /// ```
+    pub fn rank(&self) -> Rank {
+        let suit_value_sets = self.suit_value_sets();
+        let [spades, clubs, hearts, diamonds] = suit_value_sets;
+
+        // Values held in at least two, three and all four suits
+        let value_set = spades | clubs | hearts | diamonds;
+        let two_or_more =
+            (spades & clubs) | (hearts & diamonds) | ((spades | clubs) & (hearts | diamonds));
+        let three_or_more =
+            (spades & clubs & (hearts | diamonds)) | (hearts & diamonds & (spades | clubs));
+        let fours = spades & clubs & hearts & diamonds;
+        let threes = three_or_more ^ fours;
+        let pairs = two_or_more ^ three_or_more;
+
+        if let Some(flush) = suit_value_sets.iter().find(|sv| sv.count_ones() >= 5) {
+            match straight(*flush) {
+                Some(rank) => Rank::StraightFlush(rank),
+                None => Rank::Flush(keep_n(*flush, 5)),
+            }
+        } else if fours != 0 {
+            Rank::FourOfAKind((fours << 13) | keep_n(value_set ^ fours, 1))
+        } else if threes.count_ones() == 2 {
+            // There are two sets. So the best we can make is a full house.
+            let set = keep_n(threes, 1);
+            Rank::FullHouse((set << 13) | (threes ^ set))
+        } else if threes != 0 && pairs != 0 {
+            Rank::FullHouse((threes << 13) | keep_n(pairs, 1))
+        } else if let Some(rank) = straight(value_set) {
+            Rank::Straight(rank)
+        } else if threes != 0 {
+            Rank::ThreeOfAKind((threes << 13) | keep_n(value_set ^ threes, 2))
+        } else if pairs.count_ones() >= 2 {
+            let pairs = keep_n(pairs, 2);
+            Rank::TwoPair((pairs << 13) | keep_n(value_set ^ pairs, 1))
+        } else if pairs == 0 {
+            Rank::HighCard(keep_n(value_set, 5))
+        } else {
+            Rank::OnePair((pairs << 13) | keep_n(value_set ^ pairs, 3))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{seq::SliceRandom, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{Card, CardIter, Deck, Hand, Rankable, Value};
+
+    fn all_cards() -> Vec<Card> {
+        Deck::default().into_iter().collect()
+    }
+
+    #[test]
+    fn test_straights_table() {
+        assert_eq!(straight(0b1_1111_0000_0000), Some(9));
+        assert_eq!(straight(0b0_0000_0001_1111), Some(1));
+        assert_eq!(straight(0b1_0000_0000_1111), Some(0));
+        assert_eq!(straight(0b1_1110_1111_0000), None);
+    }
+
+    #[test]
+    fn test_highest_values_table() {
+        assert_eq!(keep_n(0b111, 1), 0b100);
+        assert_eq!(keep_n(0b1011_0110, 3), 0b1011_0000);
+        assert_eq!(keep_n(0b11, 5), 0b11);
+    }
+
+    #[test]
+    fn test_same_rank_for_every_five_card_hand() {
+        let cards = all_cards();
+        for hand in CardIter::new(&cards, 5) {
+            let card_bit_set: CardBitSet = hand.iter().copied().collect();
+            let hand = Hand::new_with_cards(hand);
+            assert_eq!(card_bit_set.rank(), hand.rank(), "{:?}", hand);
+            assert_eq!(card_bit_set.rank(), hand.rank_five(), "{:?}", hand);
+        }
+    }
+
+    #[test]
+    fn test_same_rank_for_seven_card_sample() {
+        let mut rng = ChaCha20Rng::seed_from_u64(7);
+        let mut cards = all_cards();
+        for _ in 0..100_000 {
+            cards.shuffle(&mut rng);
+            let hand = Hand::new_with_cards(cards[..7].to_vec());
+            let card_bit_set: CardBitSet = hand.iter().copied().collect();
+            assert_eq!(card_bit_set.rank(), hand.rank(), "{:?}", hand);
+        }
+    }
+
+    #[test]
+    fn test_same_rank_for_six_card_sample() {
+        let mut rng = ChaCha20Rng::seed_from_u64(6);
+        let mut cards = all_cards();
+        for _ in 0..50_000 {
+            cards.shuffle(&mut rng);
+            let hand = Hand::new_with_cards(cards[..6].to_vec());
+            let card_bit_set: CardBitSet = hand.iter().copied().collect();
+            assert_eq!(card_bit_set.rank(), hand.rank(), "{:?}", hand);
+        }
+    }
+
+    #[test]
+    fn test_rank_fewer_than_five_cards() {
+        let two_pair: CardBitSet = Hand::new_from_str("2h2d8d8s")
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(
+            two_pair.rank(),
+            Rank::TwoPair(((1 << Value::Two as u32) | (1 << Value::Eight as u32)) << 13)
+        );
+
+        let quads: CardBitSet = Hand::new_from_str("AdAcAsAh")
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(
+            quads.rank(),
+            Rank::FourOfAKind((1 << Value::Ace as u32) << 13)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Card, Value};
+use crate::rank::Rankable;
+
+/// Highest ace-low value that can still be part of a qualifying low (the eight).
+const LOW_QUALIFIER: u8 = 7;
+
+/// A qualifying eight-or-better low hand.
+///
+/// The inner u32 is a bit set of the five distinct ace-low values
+/// (ace = bit 0, eight = bit 7). Straights and flushes don't count
+/// against a low, so comparing the bit sets as integers compares the
+/// hands from the highest card down.
+///
+/// The ordering is reversed so that, like `Rank`, the better hand
+/// compares greater: A2345 > A2346 > ... > 45678.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Copy, CandidType, Deserialize, Serialize)]
+pub struct LowRank(pub u32);
+
+impl Ord for LowRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for LowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Value of a card when aces play low (ace = 0, two = 1, ..., king = 12).
+fn ace_low_value(card: &Card) -> u8 {
+    if card.value == Value::Ace {
+        0
+    } else {
+        card.value as u8 + 1
+    }
+}
+
+/// Anything that can be ranked high can also be ranked low.
+pub trait LowRankable: Rankable {
+    /// Find the best eight-or-better low that can be made from these cards.
+    /// This works on five or more cards and returns `None` when there are
+    /// not five distinct cards of eight or lower.
+    ///
+    /// # Examples
+    /// ```
+    /// use poker_core::{Hand, LowRankable};
+    ///
+    /// let wheel = Hand::new_from_str("As2d3c4h5s").unwrap();
+    /// let eight = Hand::new_from_str("As2d3c4h8s").unwrap();
+    /// assert!(wheel.low_rank() > eight.low_rank());
+    /// assert!(Hand::new_from_str("As2d3c4h9s").unwrap().low_rank().is_none());
+    /// ```
+    fn low_rank(&self) -> Option<LowRank> {
+        let mut value_set: u32 = 0;
+        for c in self.cards() {
+            let v = ace_low_value(c);
+            if v <= LOW_QUALIFIER {
+                value_set |= 1 << v;
+            }
+        }
+
+        if value_set.count_ones() < 5 {
+            return None;
+        }
+
+        // Keep only the five lowest values.
+        while value_set.count_ones() > 5 {
+            value_set &= !(1 << (31 - value_set.leading_zeros()));
+        }
+        Some(LowRank(value_set))
+    }
+}
+
+impl<T: Rankable> LowRankable for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::*;
+
+    #[test]
+    fn test_wheel_is_best_low() {
+        let hand = Hand::new_from_str("As2d3c4h5s").unwrap();
+        assert_eq!(Some(LowRank(0b1_1111)), hand.low_rank());
+    }
+
+    #[test]
+    fn test_low_needs_eight_or_better() {
+        assert!(Hand::new_from_str("As2d3c4h8s")
+            .unwrap()
+            .low_rank()
+            .is_some());
+        assert!(Hand::new_from_str("As2d3c4h9s")
+            .unwrap()
+            .low_rank()
+            .is_none());
+    }
+
+    #[test]
+    fn test_pairs_do_not_qualify() {
+        assert!(Hand::new_from_str("As2d3c4h4s")
+            .unwrap()
+            .low_rank()
+            .is_none());
+    }
+
+    #[test]
+    fn test_straights_and_flushes_are_ignored() {
+        let flush = Hand::new_from_str("As2s3s4s6s").unwrap();
+        let rainbow = Hand::new_from_str("Ad2s3c4h6s").unwrap();
+        assert_eq!(flush.low_rank(), rainbow.low_rank());
+    }
+
+    #[test]
+    fn test_compare_from_highest_card() {
+        let seven_six = Hand::new_from_str("As2d3c6h7s").unwrap().low_rank();
+        let seven_five = Hand::new_from_str("As2d4c5h7s").unwrap().low_rank();
+        let eight = Hand::new_from_str("As2d3c4h8s").unwrap().low_rank();
+        assert!(seven_five > seven_six);
+        assert!(seven_six > eight);
+    }
+
+    #[test]
+    fn test_best_low_from_seven_cards() {
+        let hand = Hand::new_from_str("As2d3c4h8sKd6s").unwrap();
+        assert_eq!(
+            Hand::new_from_str("As2d3c4h6s").unwrap().low_rank(),
+            hand.low_rank()
+        );
+    }
+}
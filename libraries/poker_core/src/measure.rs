@@ -0,0 +1,99 @@
+use std::hint::black_box;
+
+use candid::CandidType;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{Card, CardBitSet, CardIter, Deck, Hand, Rank, Rankable};
+
+/// What ranking the same hands costs with each evaluator, in whatever unit the counter counts.
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
+pub struct HandEvaluatorMeasurement {
+    pub hands: u64,
+    pub seven_card_rankable_cost: u64,
+    pub seven_card_lookup_cost: u64,
+    pub omaha_rankable_cost: u64,
+    pub omaha_lookup_cost: u64,
+}
+
+/// Ranks `hands` random 7 card hands and `hands` random Omaha hands (4 hole and
+/// 5 community cards) with [`Rankable`] and with the lookup table evaluator on
+/// [`CardBitSet`], drawing the cards from `seed` and reading `counter` around each run.
+///
+/// Natively the counter can be a clock. In a canister it is the instruction counter,
+/// which is what the evaluators actually cost there.
+pub fn measure_hand_evaluators(
+    hands: usize,
+    seed: [u8; 32],
+    counter: impl Fn() -> u64,
+) -> HandEvaluatorMeasurement {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+
+    let seven_card_hands: Vec<Hand> = random_hands(&mut rng, hands, 7)
+        .into_iter()
+        .map(Hand::new_with_cards)
+        .collect();
+    let seven_card_bit_sets: Vec<CardBitSet> = seven_card_hands
+        .iter()
+        .map(|hand| hand.iter().copied().collect())
+        .collect();
+    let omaha_hands = random_hands(&mut rng, hands, 9);
+
+    HandEvaluatorMeasurement {
+        hands: hands as u64,
+        seven_card_rankable_cost: cost(&counter, &seven_card_hands, |hand| hand.rank()),
+        seven_card_lookup_cost: cost(&counter, &seven_card_bit_sets, |cards| cards.rank()),
+        omaha_rankable_cost: cost(&counter, &omaha_hands, |cards| {
+            best_omaha_rank(&cards[..4], &cards[4..])
+        }),
+        omaha_lookup_cost: cost(&counter, &omaha_hands, |cards| {
+            best_omaha_lookup_rank(&cards[..4], &cards[4..])
+        }),
+    }
+}
+
+/// Runs `rank` over every hand and returns what the counter counted.
+fn cost<T>(counter: &impl Fn() -> u64, hands: &[T], rank: impl Fn(&T) -> Rank) -> u64 {
+    let start = counter();
+    for hand in hands {
+        black_box(rank(black_box(hand)));
+    }
+    counter() - start
+}
+
+fn random_hands(rng: &mut ChaCha20Rng, hands: usize, cards_per_hand: usize) -> Vec<Vec<Card>> {
+    let mut deck: Vec<Card> = Deck::default().into_iter().collect();
+    (0..hands)
+        .map(|_| {
+            deck.shuffle(rng);
+            deck[..cards_per_hand].to_vec()
+        })
+        .collect()
+}
+
+fn best_omaha_rank(hole_cards: &[Card], community_cards: &[Card]) -> Rank {
+    let mut best_rank = Rank::HighCard(0);
+    for hole_combo in CardIter::new(hole_cards, 2) {
+        for community_combo in CardIter::new(community_cards, 3) {
+            let mut five_card_hand = hole_combo.clone();
+            five_card_hand.extend(community_combo);
+            best_rank = best_rank.max(Hand::new_with_cards(five_card_hand).rank_five());
+        }
+    }
+    best_rank
+}
+
+fn best_omaha_lookup_rank(hole_cards: &[Card], community_cards: &[Card]) -> Rank {
+    let community_bit_sets: Vec<CardBitSet> = CardIter::new(community_cards, 3)
+        .map(|combo| combo.into_iter().collect())
+        .collect();
+    let mut best_rank = Rank::HighCard(0);
+    for hole_combo in CardIter::new(hole_cards, 2) {
+        let hole_bit_set: CardBitSet = hole_combo.into_iter().collect();
+        for community_bit_set in &community_bit_sets {
+            best_rank = best_rank.max((hole_bit_set | *community_bit_set).rank());
+        }
+    }
+    best_rank
+}
@@ -8,7 +8,7 @@ use std::{
 /// # Examples
 ///
 /// ```
-/// use table::poker::core::PlayerBitSet;
+/// use poker_core::PlayerBitSet;
 /// let mut active_players = PlayerBitSet::new(9);
 ///
 /// // Player 4 folds
@@ -103,7 +103,7 @@ impl PlayerBitSet {
 /// # Examples
 ///
 /// ```
-/// use table::poker::core::PlayerBitSet;
+/// use poker_core::PlayerBitSet;
 ///
 /// let mut a = PlayerBitSet::default();
 /// a.enable(0);
@@ -140,7 +140,7 @@ pub struct ActivePlayerBitSetIter {
 /// # Examples
 ///
 /// ```
-/// use table::poker::core::PlayerBitSet;
+/// use poker_core::PlayerBitSet;
 ///
 /// let mut set = PlayerBitSet::default();
 ///
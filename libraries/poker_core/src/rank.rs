@@ -1,8 +1,8 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 
-use crate::poker::core::card::Card;
-use crate::poker::core::hand::Hand;
+use crate::card::Card;
+use crate::hand::Hand;
 
 /// All the different possible hand ranks.
 /// For each hand rank the u32 corresponds to
@@ -111,7 +111,7 @@ pub trait Rankable {
     ///
     /// # Examples
     /// ```
-    /// use table::poker::core::{Hand, Rank, Rankable};
+    /// use poker_core::{Hand, Rank, Rankable};
     ///
     /// let hand = Hand::new_from_str("2h2d8d8sKd6sTh").unwrap();
     /// let rank = hand.rank();
@@ -303,8 +303,8 @@ impl Rankable for Vec<Card> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::poker::core::card::*;
-    use crate::poker::core::hand::*;
+    use crate::card::*;
+    use crate::hand::*;
 
     #[test]
     fn test_keep_highest() {
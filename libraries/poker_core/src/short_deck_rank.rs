@@ -0,0 +1,136 @@
+use std::cmp::Ordering;
+
+use crate::rank::{Rank, Rankable};
+
+/// Bit mask for the short deck wheel (Ace, six, seven, eight, nine)
+const SHORT_DECK_WHEEL: u32 = 0b1_0000_1111_0000;
+
+/// A `Rank` compared with short deck (6+ Hold'em) rules.
+///
+/// A flush is harder to make than a full house with only 36 cards,
+/// so it ranks above it. Everything else keeps the standard order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ShortDeckRank(pub Rank);
+
+impl ShortDeckRank {
+    /// Position of the hand category in the short deck ordering.
+    fn category(&self) -> (u8, u32) {
+        match self.0 {
+            Rank::HighCard(v) => (0, v),
+            Rank::OnePair(v) => (1, v),
+            Rank::TwoPair(v) => (2, v),
+            Rank::ThreeOfAKind(v) => (3, v),
+            Rank::Straight(v) => (4, v),
+            Rank::FullHouse(v) => (5, v),
+            Rank::Flush(v) => (6, v),
+            Rank::FourOfAKind(v) => (7, v),
+            Rank::StraightFlush(v) => (8, v),
+        }
+    }
+}
+
+impl Ord for ShortDeckRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.category().cmp(&other.category())
+    }
+}
+
+impl PartialOrd for ShortDeckRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Anything that can be ranked with a full deck can be ranked with a short deck.
+pub trait ShortDeckRankable: Rankable {
+    /// Rank the cards to find the best 5 card hand dealt from a 36 card deck.
+    ///
+    /// The only difference to `rank` is that A-6-7-8-9 makes the lowest
+    /// straight (and straight flush). The returned `Rank` should be compared
+    /// through `ShortDeckRank` so that a flush beats a full house.
+    ///
+    /// # Examples
+    /// ```
+    /// use poker_core::{Hand, Rank, ShortDeckRankable};
+    ///
+    /// let hand = Hand::new_from_str("As6d7c8h9sKd").unwrap();
+    /// assert_eq!(Rank::Straight(0), hand.short_deck_rank());
+    /// ```
+    fn short_deck_rank(&self) -> Rank {
+        let rank = self.rank();
+        if let Rank::StraightFlush(_) = rank {
+            return rank;
+        }
+
+        let mut suit_value_sets: [u32; 4] = [0, 0, 0, 0];
+        let mut value_set: u32 = 0;
+        for c in self.cards() {
+            value_set |= 1 << c.value as u32;
+            suit_value_sets[c.suit as usize] |= 1 << c.value as u32;
+        }
+
+        if suit_value_sets
+            .iter()
+            .any(|sv| sv & SHORT_DECK_WHEEL == SHORT_DECK_WHEEL)
+        {
+            return Rank::StraightFlush(0);
+        }
+
+        match rank {
+            Rank::HighCard(_) | Rank::OnePair(_) | Rank::TwoPair(_) | Rank::ThreeOfAKind(_)
+                if value_set & SHORT_DECK_WHEEL == SHORT_DECK_WHEEL =>
+            {
+                Rank::Straight(0)
+            }
+            _ => rank,
+        }
+    }
+}
+
+impl<T: Rankable> ShortDeckRankable for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::*;
+
+    #[test]
+    fn test_short_deck_wheel() {
+        let hand = Hand::new_from_str("As6d7c8h9s").unwrap();
+        assert_eq!(Rank::Straight(0), hand.short_deck_rank());
+    }
+
+    #[test]
+    fn test_short_deck_wheel_is_lowest_straight() {
+        let wheel = Hand::new_from_str("As6d7c8h9s").unwrap().short_deck_rank();
+        let six_high = Hand::new_from_str("6s7d8c9hTs").unwrap().short_deck_rank();
+        assert!(ShortDeckRank(wheel) < ShortDeckRank(six_high));
+    }
+
+    #[test]
+    fn test_short_deck_wheel_straight_flush() {
+        let hand = Hand::new_from_str("As6s7s8s9sKd").unwrap();
+        assert_eq!(Rank::StraightFlush(0), hand.short_deck_rank());
+    }
+
+    #[test]
+    fn test_short_deck_wheel_beats_trips() {
+        let hand = Hand::new_from_str("AsAdAc6h7s8d9c").unwrap();
+        assert_eq!(Rank::Straight(0), hand.short_deck_rank());
+    }
+
+    #[test]
+    fn test_flush_beats_full_house() {
+        let flush = Hand::new_from_str("6s8sTsQsKs").unwrap().short_deck_rank();
+        let full_house = Hand::new_from_str("AsAdAcKhKd").unwrap().short_deck_rank();
+        assert!(ShortDeckRank(flush) > ShortDeckRank(full_house));
+        assert!(ShortDeckRank(full_house) > ShortDeckRank(Rank::Straight(u32::MAX)));
+        assert!(ShortDeckRank(flush) < ShortDeckRank(Rank::FourOfAKind(0)));
+    }
+
+    #[test]
+    fn test_standard_hands_unchanged() {
+        let hand = Hand::new_from_str("TsJdQcKhAs").unwrap();
+        assert_eq!(hand.rank(), hand.short_deck_rank());
+    }
+}
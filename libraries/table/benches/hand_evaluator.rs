@@ -0,0 +1,43 @@
+//! Compares the lookup table evaluator on `CardBitSet` with the `Rankable` one.
+//!
+//! Run with `cargo bench -p table --bench hand_evaluator`. For the instructions a
+//! canister spends, build the table canister with the `hand_evaluator_bench`
+//! feature and call `measure_hand_evaluator` instead.
+
+use std::time::Instant;
+
+use table::poker::core::measure_hand_evaluators;
+
+const HANDS: usize = 200_000;
+
+fn print_costs(name: &str, rankable_nanos: u64, lookup_nanos: u64) {
+    let per_hand = |nanos: u64| nanos as f64 / HANDS as f64;
+    println!("{}", name);
+    let speedup = rankable_nanos as f64 / lookup_nanos as f64;
+    println!(
+        "  Rankable::rank    {:>8.1} ns/hand",
+        per_hand(rankable_nanos)
+    );
+    println!(
+        "  CardBitSet::rank  {:>8.1} ns/hand",
+        per_hand(lookup_nanos)
+    );
+    println!("  {:.1}x faster\n", speedup);
+}
+
+fn main() {
+    let start = Instant::now();
+    let elapsed_nanos = || start.elapsed().as_nanos() as u64;
+
+    let measurement = measure_hand_evaluators(HANDS, [0; 32], elapsed_nanos);
+    print_costs(
+        "7 card hands",
+        measurement.seven_card_rankable_cost,
+        measurement.seven_card_lookup_cost,
+    );
+    print_costs(
+        "Omaha hands (4 hole and 5 community cards)",
+        measurement.omaha_rankable_cost,
+        measurement.omaha_lookup_cost,
+    );
+}
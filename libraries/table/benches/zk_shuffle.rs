@@ -0,0 +1,28 @@
+//! Times proving and verifying a zero-knowledge shuffle of a deck.
+//!
+//! Run with `cargo bench -p table --features zk_shuffle --bench zk_shuffle`. For
+//! the instructions a canister spends, build the table canister with the
+//! `zk_shuffle` feature and call `measure_zk_shuffle` instead.
+
+use std::time::Instant;
+
+use table::poker::zk_shuffle::{measure_shuffle, DEFAULT_ROUNDS};
+
+fn main() {
+    let start = Instant::now();
+    let elapsed_micros = || start.elapsed().as_micros() as u64;
+
+    for deck_size in [36, 52] {
+        for rounds in [64, DEFAULT_ROUNDS, 256] {
+            let measurement = measure_shuffle(deck_size, rounds, [0; 32], elapsed_micros)
+                .expect("the shuffle proof should verify");
+            println!(
+                "{:>2} cards, {:>3} rounds    prove {:>8.1} ms    verify {:>8.1} ms",
+                deck_size,
+                rounds,
+                measurement.prove_cost as f64 / 1000.0,
+                measurement.verify_cost as f64 / 1000.0
+            );
+        }
+    }
+}
@@ -2,7 +2,7 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use user::user::WalletPrincipalId;
 
-use crate::poker::core::Card;
+use crate::poker::core::{Card, Equity};
 
 use super::{table::Table, types::DealStage};
 
@@ -28,12 +28,26 @@ pub enum ActionType {
     },
     PlayersHandsRankedMainPot {
         hands: Vec<(String, Vec<Card>, u64)>,
+        /// The low half of the pot in hi-lo games, `None` otherwise.
+        low_hands: Option<Vec<(String, Vec<Card>, u64)>>,
     },
     PlayersHandsRankedSidePot {
         hands: Vec<(String, Vec<Card>, u64)>,
+        /// The low half of the pot in hi-lo games, `None` otherwise.
+        low_hands: Option<Vec<(String, Vec<Card>, u64)>>,
     },
     BigBlind,
     SmallBlind,
+    Straddle,
+    /// Blinds posted by a player who missed them, the big blind live and the small blind dead.
+    MissedBlinds {
+        big_blind: u64,
+        small_blind: u64,
+    },
+    /// The ante a player posted for a bomb pot.
+    BombPot {
+        ante: u64,
+    },
     Kicked {
         reason: String,
     },
@@ -41,6 +55,35 @@ pub enum ActionType {
         stage: DealStage,
     },
     SidePotCreated,
+    /// One of the boards dealt when the hand is run more than once.
+    BoardRun {
+        run: u8,
+        community_cards: Vec<Card>,
+    },
+    /// The equity of every player still in the hand when they went all in,
+    /// as the user name, their hole cards and their equity.
+    AllInEquity {
+        community_cards: Vec<Card>,
+        equities: Vec<(String, Vec<Card>, Equity)>,
+        is_exhaustive: bool,
+    },
+    /// Hole cards shown after the hand, the rest of them are mucked.
+    ShowCards {
+        cards: Vec<Card>,
+        mucked: u8,
+    },
+    /// The board cards that would have come had the hand gone on.
+    RabbitHunt {
+        community_cards: Vec<Card>,
+    },
+    /// A player moved to another seat between hands.
+    SeatChange {
+        seat_index: u8,
+    },
+    /// A player timed out and was kept in the hand for the chips they already put in.
+    DisconnectProtection {
+        protections_left: u8,
+    },
 }
 
 /// A log of an action that a user has taken.
@@ -9,6 +9,7 @@ pub enum AnteType {
     Fixed(u64),               // Fixed amount
     BigBlindAnte,             // One player pays the BB as ante
     PercentageOfBigBlind(u8), // Ante as percentage of BB
+    ButtonAnte(u64),          // The button pays one ante for the whole table
 }
 
 impl Table {
@@ -21,6 +22,7 @@ impl Table {
                 AnteType::PercentageOfBigBlind(percentage) => {
                     (self.big_blind.0 * *percentage as u64) / 100
                 }
+                AnteType::ButtonAnte(amount) => *amount,
             }
         } else {
             0
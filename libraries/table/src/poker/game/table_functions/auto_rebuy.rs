@@ -0,0 +1,133 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::{UsersCanisterId, WalletPrincipalId};
+
+use super::{
+    table::Table,
+    types::{NotificationMessage, PlayerAction, SeatStatus},
+};
+
+/// Tops a player's stack back up from their wallet between hands once it runs low.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct AutoRebuy {
+    /// The stack to top up to, in big blinds.
+    pub top_up_to_big_blinds: u16,
+    /// The stack the player is topped up below, in big blinds.
+    pub below_big_blinds: u16,
+}
+
+impl Table {
+    /// Sets the auto-rebuy of the user, or turns it off with `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `auto_rebuy` - When and how far to top the user up
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if this is not a cash table, the user is never
+    ///   topped up or the stack to top up to is over the maximum buy-in
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn set_auto_rebuy(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        auto_rebuy: Option<AutoRebuy>,
+    ) -> Result<(), TracedError<GameError>> {
+        if let Some(auto_rebuy) = auto_rebuy {
+            if !self.is_seat_choice_allowed() {
+                return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                    reason: "Auto-rebuy is only available at cash tables".to_string(),
+                })));
+            }
+            if auto_rebuy.below_big_blinds == 0
+                || auto_rebuy.below_big_blinds > auto_rebuy.top_up_to_big_blinds
+            {
+                return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                    reason: "The stack to top up to has to be at least the stack to top up below"
+                        .to_string(),
+                })));
+            }
+            if let Some(buy_in) = &self.config.buy_in {
+                if auto_rebuy.top_up_to_big_blinds > buy_in.max_big_blinds {
+                    return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                        reason: format!(
+                            "The maximum buy-in is {} big blinds",
+                            buy_in.max_big_blinds
+                        ),
+                    })));
+                }
+            }
+        }
+
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to set auto-rebuy."))?
+            .auto_rebuy = auto_rebuy;
+        Ok(())
+    }
+
+    /// The top-ups due before the next hand, as the users canister, the user and the amount.
+    pub fn get_due_auto_rebuys(&self) -> Vec<(UsersCanisterId, WalletPrincipalId, u64)> {
+        self.seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(user_principal) => Some(*user_principal),
+                _ => None,
+            })
+            .filter_map(|user_principal| {
+                let auto_rebuy = self.user_table_data.get(&user_principal)?.auto_rebuy?;
+                let user = self.users.get(&user_principal)?;
+                if user.balance.0 >= u64::from(auto_rebuy.below_big_blinds) * self.big_blind.0 {
+                    return None;
+                }
+                let amount = (u64::from(auto_rebuy.top_up_to_big_blinds) * self.big_blind.0)
+                    .saturating_sub(user.balance.0);
+                let amount = self.get_top_up_amount(user_principal, amount);
+                (amount > 0).then_some((user.users_canister_id, user_principal, amount))
+            })
+            .collect()
+    }
+
+    /// Tells the user their auto-rebuy could not be taken from their wallet.
+    ///
+    /// A user who cannot cover the big blind is sat out instead of being kicked,
+    /// and the top-up is tried again before the next hand.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `reason` - Why the transfer failed
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if the user is not found
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn handle_failed_auto_rebuy(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        reason: String,
+    ) -> Result<(), TracedError<GameError>> {
+        self.notifications.add_notification(
+            user_principal,
+            NotificationMessage::AutoRebuyFailed { reason },
+        );
+        if self
+            .check_user_balance(self.big_blind.0, user_principal)
+            .is_err()
+        {
+            self.user_sitting_out(user_principal, false)
+                .map_err(|e| trace_err!(e, "Failed to sit out user after a failed auto-rebuy."))?;
+        }
+        Ok(())
+    }
+
+    /// Is the user sitting out until their auto-rebuy goes through?
+    pub fn is_waiting_for_auto_rebuy(&self, user_principal: WalletPrincipalId) -> bool {
+        self.user_table_data
+            .get(&user_principal)
+            .is_some_and(|data| {
+                data.auto_rebuy.is_some() && data.player_action == PlayerAction::SittingOut
+            })
+    }
+}
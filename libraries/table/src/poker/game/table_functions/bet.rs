@@ -23,6 +23,15 @@ impl Table {
         &mut self,
         user_principal: WalletPrincipalId,
         bet_type: BetType,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| table.handle_bet(user_principal, bet_type))
+    }
+
+    /// Places the bet of [`Table::bet`].
+    fn handle_bet(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        bet_type: BetType,
     ) -> Result<(), TracedError<GameError>> {
         #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
         {
@@ -34,7 +43,7 @@ impl Table {
             self.clear_turn_timer();
         }
 
-        if bet_type != BetType::SmallBlind && bet_type != BetType::BigBlind {
+        if !bet_type.is_forced() {
             self.get_user_table_data_mut(user_principal)
                 .map_err(|e| {
                     trace_err!(
@@ -46,7 +55,9 @@ impl Table {
         }
 
         match bet_type {
-            BetType::BigBlind | BetType::SmallBlind => self.raise(user_principal, bet_type, 0, 0),
+            BetType::BigBlind | BetType::SmallBlind | BetType::Straddle => {
+                self.raise(user_principal, bet_type, 0, 0)
+            }
             BetType::Ante(amount) => {
                 self.check_user_balance(amount, user_principal)?;
                 self.users
@@ -137,8 +148,13 @@ impl Table {
                 //   - Your call: $10
                 //   - Pot after call: $20 + $10 + $10 = $40
                 //   - Max raise-to: $10 (call) + $40 (pot) = $50
-                if let GameType::PotLimit(_) | GameType::PotLimitOmaha4(_) | GameType::PotLimitOmaha5(_) = self.config.game_type {
-                    if bet_type != BetType::BigBlind && bet_type != BetType::SmallBlind {
+                if let GameType::PotLimit(_)
+                | GameType::PotLimitOmaha4(_)
+                | GameType::PotLimitOmaha5(_)
+                | GameType::PotLimitOmahaHiLo4(_)
+                | GameType::PotLimitOmahaHiLo5(_) = self.config.game_type
+                {
+                    if !bet_type.is_forced() {
                         // Step 1: Calculate live pot (pot from previous streets + current street bets)
                         let mut live_pot = self.pot.0;
                         for user_data in &self.user_table_data {
@@ -172,14 +188,16 @@ impl Table {
                 }
 
                 match self.config.game_type {
-                    GameType::FixedLimit(small, big) => self.handle_fixed_limit(
-                        user_principal,
-                        normal_amount,
-                        small,
-                        big,
-                        amount,
-                        bet_type,
-                    ),
+                    GameType::FixedLimit(small, big) | GameType::SevenCardStud(small, big) => {
+                        self.handle_fixed_limit(
+                            user_principal,
+                            normal_amount,
+                            small,
+                            big,
+                            amount,
+                            bet_type,
+                        )
+                    }
                     GameType::SpreadLimit(min, max) => self.handle_spread_limit(
                         user_principal,
                         normal_amount,
@@ -238,7 +256,7 @@ impl Table {
                         && normal_amount == (small + (small / 2)))
                     || (amount.saturating_sub(self.highest_bet) == small)
             }
-            DealStage::River | DealStage::Showdown => {
+            DealStage::River | DealStage::SeventhStreet | DealStage::Showdown => {
                 (normal_amount == big) || (normal_amount.saturating_sub(self.last_raise) == big)
             }
             _ => false,
@@ -0,0 +1,150 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::types::QueueItem;
+
+use super::{
+    action_log::ActionType,
+    table::{Table, TableType},
+    types::{BetType, DealStage, PlayerAction, SeatStatus},
+};
+
+/// How a table deals bomb pots.
+///
+/// In a bomb pot everyone antes the same amount and the hand starts on the flop,
+/// without blinds or a preflop betting round.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct BombPotConfig {
+    /// The ante every player posts.
+    pub ante: u64,
+    /// Deal a bomb pot every this many hands, or only when the host asks for one if `None`.
+    pub every_hands: Option<u16>,
+    /// Deal a second board, with half of every pot played for on each of them.
+    pub is_double_board: bool,
+}
+
+impl Table {
+    /// Whether bomb pots can be dealt at this table.
+    ///
+    /// Bomb pots are only dealt on cash tables with a bomb pot config and a board.
+    pub fn is_bomb_pot_allowed(&self) -> bool {
+        self.config.bomb_pot.is_some()
+            && matches!(self.config.table_type, None | Some(TableType::Cash))
+            && !self.is_stud()
+    }
+
+    /// Makes the next hand a bomb pot.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table does not deal bomb pots
+    pub fn queue_bomb_pot(&mut self) -> Result<(), TracedError<GameError>> {
+        if !self.is_bomb_pot_allowed() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Bomb pots are not allowed at this table".to_string(),
+            })));
+        }
+
+        if !self
+            .queue
+            .iter()
+            .any(|item| matches!(item, QueueItem::BombPot))
+        {
+            self.append_to_queue(QueueItem::BombPot);
+        }
+        Ok(())
+    }
+
+    /// Whether the hand about to start is a bomb pot on the table's schedule.
+    pub(crate) fn is_bomb_pot_scheduled(&self) -> bool {
+        self.is_bomb_pot_allowed()
+            && self
+                .config
+                .bomb_pot
+                .as_ref()
+                .and_then(|config| config.every_hands)
+                .is_some_and(|every_hands| {
+                    every_hands > 0 && self.round_ticker.is_multiple_of(every_hands.into())
+                })
+    }
+
+    /// Starts a bomb pot instead of posting the blinds.
+    ///
+    /// Everyone dealt in antes and the flop is dealt straight away. The ante is
+    /// capped at the shortest stack, so every player puts in the same amount and
+    /// a player who antes their last chip is all in. The blinds do not move.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the table has no bomb pot config
+    /// - [`GameError::NoCardsLeft`] if there are not enough cards left in the deck
+    /// - [`GameError::PlayerNotFound`] if a player cannot be found
+    pub(crate) fn start_bomb_pot(&mut self) -> Result<(), TracedError<GameError>> {
+        let config = self.config.bomb_pot.clone().ok_or_else(|| {
+            trace_err!(TracedError::new(GameError::Other(
+                "No bomb pot config".to_string()
+            )))
+        })?;
+
+        let players: Vec<WalletPrincipalId> = self
+            .seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(principal) => Some(*principal),
+                _ => None,
+            })
+            .filter(|principal| {
+                self.user_table_data.get(principal).is_some_and(|data| {
+                    !matches!(
+                        data.player_action,
+                        PlayerAction::SittingOut | PlayerAction::Joining
+                    )
+                })
+            })
+            .collect();
+        let shortest_stack = players
+            .iter()
+            .filter_map(|principal| self.users.get(principal))
+            .map(|user| user.balance.0)
+            .min()
+            .unwrap_or(0);
+        let ante = config.ante.min(shortest_stack);
+
+        for user_principal in players {
+            self.bet(user_principal, BetType::Ante(ante))
+                .map_err(|e| trace_err!(e, "Failed to post bomb pot ante."))?;
+            self.log_action(Some(user_principal), ActionType::BombPot { ante });
+            let is_all_in = self
+                .users
+                .get(&user_principal)
+                .is_some_and(|user| user.balance.0 == 0);
+            if is_all_in {
+                self.set_player_action(user_principal, PlayerAction::AllIn)
+                    .map_err(|e| trace_err!(e, "Failed to set bomb pot ante all in."))?;
+            }
+        }
+        self.bomb_pot_ante = Some(ante);
+        self.second_board = config.is_double_board.then(Vec::new);
+
+        self.deal_stage = DealStage::Opening;
+        self.deal_cards(false)
+            .map_err(|e| trace_err!(e, "Failed to deal opening cards in bomb pot."))?;
+        self.deal_cards(false)
+            .map_err(|e| trace_err!(e, "Failed to deal flop in bomb pot."))?;
+        self.set_current_player_to_left_of_dealer()
+            .map_err(|e| trace_err!(e, "Failed to set first player in bomb pot."))?;
+
+        if self
+            .all_in_cycle_to_showdown()
+            .map_err(|e| trace_err!(e, "Failed to check for all ins in bomb pot."))?
+        {
+            self.calculate_pots()
+                .map_err(|e| trace_err!(e, "Failed to calculate pots in bomb pot."))?;
+            self.cycle_to_showdown()
+                .map_err(|e| trace_err!(e, "Failed to cycle to showdown in bomb pot."))?;
+        }
+        Ok(())
+    }
+}
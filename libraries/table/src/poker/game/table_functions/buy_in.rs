@@ -0,0 +1,182 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::table::Table;
+
+/// The smallest and largest stack a player can sit down with at a cash table.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct BuyInConfig {
+    /// The minimum buy-in, in big blinds.
+    pub min_big_blinds: u16,
+    /// The maximum buy-in, in big blinds. Top-ups are capped here too.
+    pub max_big_blinds: u16,
+    /// How long after leaving a player has to come back with at least the
+    /// stack they left with, in seconds.
+    pub rathole_window_seconds: u64,
+}
+
+/// A player who left the table with chips, remembered for the rathole window.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct DepartedPlayer {
+    /// The stack the player left with.
+    pub stack: u64,
+    /// When the player left, in nanoseconds.
+    pub left_at: u64,
+}
+
+impl Table {
+    /// The stack the user last left the table with, if they left within the rathole window.
+    pub fn get_rathole_stack(&self, user_principal: WalletPrincipalId, now: u64) -> Option<u64> {
+        let window = self.config.buy_in.as_ref()?.rathole_window_seconds * 1_000_000_000;
+        self.departed_players
+            .get(&user_principal)
+            .filter(|departed| now.saturating_sub(departed.left_at) < window)
+            .map(|departed| departed.stack)
+    }
+
+    /// The smallest stack the user can sit down with.
+    ///
+    /// This is never less than a big blind, and never less than the stack
+    /// the user left with if they left within the rathole window.
+    pub fn get_min_buy_in(&self, user_principal: WalletPrincipalId, now: u64) -> u64 {
+        let min_buy_in = self.config.buy_in.as_ref().map_or(0, |buy_in| {
+            u64::from(buy_in.min_big_blinds) * self.big_blind.0
+        });
+        min_buy_in
+            .max(self.big_blind.0)
+            .max(self.get_rathole_stack(user_principal, now).unwrap_or(0))
+    }
+
+    /// The largest stack the user can sit down with, `None` if there is no limit.
+    ///
+    /// A user coming back within the rathole window can always bring the stack they left with.
+    pub fn get_max_buy_in(&self, user_principal: WalletPrincipalId, now: u64) -> Option<u64> {
+        let max_buy_in = u64::from(self.config.buy_in.as_ref()?.max_big_blinds) * self.big_blind.0;
+        Some(max_buy_in.max(self.get_rathole_stack(user_principal, now).unwrap_or(0)))
+    }
+
+    /// Checks that the user can sit down with `amount`.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user joining
+    /// - `amount` - The stack the user wants to sit down with
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::InsufficientFunds`] if the amount is less than a big blind
+    /// - [`GameError::ActionNotAllowed`] if the amount is outside the buy-in limits
+    pub fn validate_buy_in(
+        &self,
+        user_principal: WalletPrincipalId,
+        amount: u64,
+        now: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        if amount < self.big_blind.0 {
+            return Err(trace_err!(TracedError::new(GameError::InsufficientFunds)));
+        }
+        let min_buy_in = self.get_min_buy_in(user_principal, now);
+        if amount < min_buy_in {
+            let reason = if self.get_rathole_stack(user_principal, now) == Some(min_buy_in) {
+                format!(
+                    "You left this table recently and have to come back with at least {}",
+                    min_buy_in
+                )
+            } else {
+                format!("The minimum buy-in is {}", min_buy_in)
+            };
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason
+            })));
+        }
+        if let Some(max_buy_in) = self.get_max_buy_in(user_principal, now) {
+            if amount > max_buy_in {
+                return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                    reason: format!("The maximum buy-in is {}", max_buy_in),
+                })));
+            }
+        }
+        Ok(())
+    }
+
+    /// The part of a top-up of `amount` that keeps the user's stack within the maximum buy-in.
+    pub fn get_top_up_amount(&self, user_principal: WalletPrincipalId, amount: u64) -> u64 {
+        let Some(buy_in) = &self.config.buy_in else {
+            return amount;
+        };
+        let balance = self
+            .users
+            .get(&user_principal)
+            .map_or(0, |user| user.balance.0);
+        let max_buy_in = u64::from(buy_in.max_big_blinds) * self.big_blind.0;
+        amount.min(max_buy_in.saturating_sub(balance))
+    }
+
+    /// Checks that a top-up of `amount` keeps the user's stack within the maximum buy-in.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the stack would go over the maximum buy-in
+    pub fn validate_top_up(
+        &self,
+        user_principal: WalletPrincipalId,
+        amount: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        if self.get_top_up_amount(user_principal, amount) < amount {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Your stack would go over the maximum buy-in".to_string(),
+            })));
+        }
+        Ok(())
+    }
+
+    /// Checks that the user can take chips off the table without leaving.
+    ///
+    /// With buy-in limits a player has to leave to cash out, otherwise
+    /// they could take a big stack off the table and play on short.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table has buy-in limits
+    pub fn validate_withdrawal(&self) -> Result<(), TracedError<GameError>> {
+        if self.config.buy_in.is_some() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Leave the table to cash out".to_string(),
+            })));
+        }
+        Ok(())
+    }
+
+    /// Remembers the stack of a user leaving the table for the rathole window.
+    ///
+    /// Players whose window has passed are forgotten.
+    pub(crate) fn record_departure(&mut self, user_principal: WalletPrincipalId, now: u64) {
+        let Some(window) = self
+            .config
+            .buy_in
+            .as_ref()
+            .map(|buy_in| buy_in.rathole_window_seconds * 1_000_000_000)
+        else {
+            return;
+        };
+        self.departed_players
+            .retain(|_, departed| now.saturating_sub(departed.left_at) < window);
+
+        let stack = self
+            .users
+            .get(&user_principal)
+            .map_or(0, |user| user.balance.0);
+        if stack > 0 && window > 0 {
+            self.departed_players.insert(
+                user_principal,
+                DepartedPlayer {
+                    stack,
+                    left_at: now,
+                },
+            );
+        }
+    }
+}
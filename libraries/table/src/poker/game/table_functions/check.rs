@@ -19,6 +19,17 @@ impl Table {
         &mut self,
         user_principal: WalletPrincipalId,
         is_auto_checked: bool,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| {
+            table.handle_user_check(user_principal, is_auto_checked)
+        })
+    }
+
+    /// Checks for the user in [`Table::user_check`].
+    fn handle_user_check(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        is_auto_checked: bool,
     ) -> Result<(), TracedError<GameError>> {
         if is_auto_checked {
             if self.handle_inactive_user(user_principal).map_err(|e| {
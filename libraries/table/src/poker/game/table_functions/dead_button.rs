@@ -0,0 +1,349 @@
+use candid::{CandidType, Principal};
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::{
+    action_log::ActionType,
+    table::{Table, TableType},
+    types::{BetType, PlayerAction, SeatStatus},
+};
+
+/// The blinds a player owes for missing them while away from a cash table.
+#[derive(Debug, Clone, Copy, Default, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct MissedBlinds {
+    /// The small blind is posted dead, straight into the pot.
+    pub small_blind: bool,
+    /// The big blind is posted live and counts towards the player's bet.
+    pub big_blind: bool,
+}
+
+impl MissedBlinds {
+    /// Adds the blinds of `other` to the ones already owed.
+    pub fn merge(self, other: MissedBlinds) -> MissedBlinds {
+        MissedBlinds {
+            small_blind: self.small_blind || other.small_blind,
+            big_blind: self.big_blind || other.big_blind,
+        }
+    }
+}
+
+/// How a player who owes missed blinds comes back into the game.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub enum MissedBlindsChoice {
+    /// Post the owed blinds on the next hand, the small blind dead and the big blind live.
+    PostMissedBlinds,
+    /// Keep sitting out until the big blind reaches the player.
+    WaitForBigBlind,
+}
+
+impl Table {
+    /// Whether the table uses a dead button and keeps track of missed blinds.
+    ///
+    /// Only cash tables do, tournaments blind out players who are sitting out instead.
+    pub fn uses_dead_button(&self) -> bool {
+        self.config.table_type == Some(TableType::Cash) && !self.is_stud()
+    }
+
+    /// Sets how the user comes back into the game when they owe missed blinds.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `choice` - Whether the user posts the missed blinds or waits for the big blind
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn set_user_missed_blinds_choice(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        choice: MissedBlindsChoice,
+    ) -> Result<(), TracedError<GameError>> {
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| {
+                trace_err!(
+                    e,
+                    "Failed to get user table data to set missed blinds choice."
+                )
+            })?
+            .missed_blinds_choice = Some(choice);
+        Ok(())
+    }
+
+    /// Brings a user who is sitting out back into the game.
+    ///
+    /// A user who owes missed blinds and chose to wait for the big blind
+    /// keeps sitting out until the big blind reaches them.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn user_sitting_in(
+        &mut self,
+        user_principal: WalletPrincipalId,
+    ) -> Result<(), TracedError<GameError>> {
+        let uses_dead_button = self.uses_dead_button();
+        let user_table_data = self
+            .get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to sit in."))?;
+
+        if uses_dead_button
+            && user_table_data.missed_blinds.is_some()
+            && user_table_data.missed_blinds_choice == Some(MissedBlindsChoice::WaitForBigBlind)
+        {
+            user_table_data.is_waiting_for_big_blind = Some(true);
+        } else {
+            user_table_data.is_waiting_for_big_blind = None;
+            user_table_data.player_action = PlayerAction::None;
+        }
+        Ok(())
+    }
+
+    /// A player joining a cash table that is already running owes the big blind,
+    /// so moving seats cannot be used to skip it.
+    pub(crate) fn set_new_player_missed_blinds(&mut self, user_principal: WalletPrincipalId) {
+        if !self.uses_dead_button() || self.round_ticker == 0 {
+            return;
+        }
+        if let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) {
+            user_table_data.missed_blinds = Some(MissedBlinds {
+                small_blind: false,
+                big_blind: true,
+            });
+        }
+    }
+
+    /// Records the seats of the blinds posted without a dead button,
+    /// so the next hand can move the big blind forward from there.
+    ///
+    /// Posting the blinds settles any missed blinds of the two players.
+    pub(crate) fn set_blind_seats(
+        &mut self,
+        small_blind_user_principal: WalletPrincipalId,
+        big_blind_user_principal: WalletPrincipalId,
+    ) {
+        if let (Some(small_blind_seat), Some(big_blind_seat)) = (
+            self.get_seat_index(small_blind_user_principal),
+            self.get_seat_index(big_blind_user_principal),
+        ) {
+            self.blind_seats = Some((small_blind_seat as usize, big_blind_seat as usize));
+        }
+        for user_principal in [small_blind_user_principal, big_blind_user_principal] {
+            if let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) {
+                user_table_data.missed_blinds = None;
+            }
+        }
+    }
+
+    /// Posts the blinds with the dead button rule.
+    ///
+    /// The big blind moves forward to the next player who is due it every hand,
+    /// and everyone sitting out on the way owes it. The last big blind posts the
+    /// small blind, which is dead if they left or are sitting out. The button goes
+    /// to where the last small blind was, and is dead if that seat is empty.
+    ///
+    /// Returns `false` without posting anything when the table does not use a dead button,
+    /// fewer than three players are due the big blind or no blinds were posted yet.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the big blind cannot be found
+    /// - [`GameError::InsufficientFunds`] if a blind cannot be posted
+    pub(crate) fn post_dead_button_blinds(&mut self) -> Result<bool, TracedError<GameError>> {
+        let Some((small_blind_seat, big_blind_seat)) = self.blind_seats else {
+            return Ok(false);
+        };
+        if !self.uses_dead_button()
+            || small_blind_seat >= self.seats.len()
+            || big_blind_seat >= self.seats.len()
+        {
+            return Ok(false);
+        }
+        let players_due = self
+            .seats
+            .iter()
+            .filter(|seat| matches!(seat, SeatStatus::Occupied(p) if self.is_due_big_blind(*p)))
+            .count();
+        if players_due < 3 {
+            return Ok(false);
+        }
+
+        let mut skipped_players = Vec::new();
+        let mut next_big_blind = None;
+        let mut index = big_blind_seat;
+        for _ in 0..self.seats.len() {
+            index = (index + 1) % self.seats.len();
+            if let SeatStatus::Occupied(user_principal) = self.seats[index] {
+                if self.is_due_big_blind(user_principal) {
+                    next_big_blind = Some((index, user_principal));
+                    break;
+                }
+                skipped_players.push(user_principal);
+            }
+        }
+        let (next_big_blind_seat, big_blind_user_principal) = next_big_blind.ok_or_else(|| {
+            trace_err!(TracedError::new(GameError::Other(
+                "Big blind not found".to_string()
+            )))
+        })?;
+
+        let mut small_blind_user_principal = None;
+        if let SeatStatus::Occupied(user_principal) = self.seats[big_blind_seat] {
+            if user_principal != big_blind_user_principal {
+                if self.is_dealt_in(user_principal) {
+                    small_blind_user_principal = Some(user_principal);
+                } else {
+                    self.add_missed_blinds(
+                        user_principal,
+                        MissedBlinds {
+                            small_blind: true,
+                            big_blind: false,
+                        },
+                    );
+                }
+            }
+        }
+        for user_principal in skipped_players {
+            self.add_missed_blinds(
+                user_principal,
+                MissedBlinds {
+                    small_blind: true,
+                    big_blind: true,
+                },
+            );
+        }
+
+        // Only a player who joined in between can make the big blind catch up with the button
+        self.dealer_position = if small_blind_seat == next_big_blind_seat {
+            big_blind_seat
+        } else {
+            small_blind_seat
+        };
+        self.blind_seats = Some((big_blind_seat, next_big_blind_seat));
+
+        if let Some(user_table_data) = self.user_table_data.get_mut(&big_blind_user_principal) {
+            if user_table_data.is_waiting_for_big_blind == Some(true) {
+                user_table_data.is_waiting_for_big_blind = None;
+                user_table_data.player_action = PlayerAction::None;
+            }
+            user_table_data.missed_blinds = None;
+        }
+
+        if let Some(small_blind_user_principal) = small_blind_user_principal {
+            self.bet(small_blind_user_principal, BetType::SmallBlind)
+                .map_err(|e| trace_err!(e, "Failed to post small blind."))?;
+            if let Some(user_table_data) = self.user_table_data.get_mut(&small_blind_user_principal)
+            {
+                user_table_data.missed_blinds = None;
+            }
+        }
+        self.bet(big_blind_user_principal, BetType::BigBlind)
+            .map_err(|e| trace_err!(e, "Failed to post big blind."))?;
+        self.small_blind_user_principal =
+            small_blind_user_principal.unwrap_or(WalletPrincipalId(Principal::anonymous()));
+        self.big_blind_user_principal = big_blind_user_principal;
+
+        self.post_missed_blinds()
+            .map_err(|e| trace_err!(e, "Failed to post missed blinds."))?;
+        Ok(true)
+    }
+
+    /// Posts the missed blinds of every player dealt in who owes them.
+    ///
+    /// A player who cannot cover them and still play sits out instead.
+    fn post_missed_blinds(&mut self) -> Result<(), TracedError<GameError>> {
+        let players_owing: Vec<(WalletPrincipalId, MissedBlinds)> = self
+            .seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(user_principal) if self.is_dealt_in(*user_principal) => self
+                    .user_table_data
+                    .get(user_principal)
+                    .and_then(|data| data.missed_blinds)
+                    .map(|missed_blinds| (*user_principal, missed_blinds)),
+                _ => None,
+            })
+            .collect();
+
+        for (user_principal, missed_blinds) in players_owing {
+            let big_blind = if missed_blinds.big_blind {
+                self.big_blind.0
+            } else {
+                0
+            };
+            let small_blind = if missed_blinds.small_blind {
+                self.small_blind.0
+            } else {
+                0
+            };
+            let balance = self
+                .get_user_balance(user_principal)
+                .map_err(|e| trace_err!(e, "Failed to get balance to post missed blinds."))?;
+            if balance.0 <= big_blind + small_blind {
+                self.set_player_action(user_principal, PlayerAction::SittingOut)
+                    .map_err(|e| trace_err!(e, "Failed to sit out player owing blinds."))?;
+                continue;
+            }
+
+            self.conserving_chips(0, |table| {
+                // The player still has to act on the live big blind
+                table.update_user_balances(big_blind, user_principal, PlayerAction::None)?;
+                table.update_highest_bet(user_principal)?;
+                table
+                    .users
+                    .get_mut(&user_principal)
+                    .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?
+                    .balance
+                    .0 -= small_blind;
+                table.add_to_pot(small_blind);
+                Ok(())
+            })?;
+            self.get_user_table_data_mut(user_principal)
+                .map_err(|e| trace_err!(e, "Failed to clear missed blinds."))?
+                .missed_blinds = None;
+            self.log_action(
+                Some(user_principal),
+                ActionType::MissedBlinds {
+                    big_blind,
+                    small_blind,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Adds to the blinds the user owes.
+    fn add_missed_blinds(&mut self, user_principal: WalletPrincipalId, missed: MissedBlinds) {
+        if let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) {
+            user_table_data.missed_blinds = Some(
+                user_table_data
+                    .missed_blinds
+                    .unwrap_or_default()
+                    .merge(missed),
+            );
+        }
+    }
+
+    /// Whether the user is dealt into the next hand.
+    fn is_dealt_in(&self, user_principal: WalletPrincipalId) -> bool {
+        self.user_table_data
+            .get(&user_principal)
+            .is_some_and(|data| {
+                !matches!(
+                    data.player_action,
+                    PlayerAction::SittingOut | PlayerAction::Joining
+                )
+            })
+    }
+
+    /// Whether the big blind can be posted by the user, either because they
+    /// are dealt in or because they are waiting for it.
+    fn is_due_big_blind(&self, user_principal: WalletPrincipalId) -> bool {
+        self.is_dealt_in(user_principal)
+            || self
+                .user_table_data
+                .get(&user_principal)
+                .is_some_and(|data| data.is_waiting_for_big_blind == Some(true))
+    }
+}
@@ -99,6 +99,17 @@ impl Table {
             },
         );
         match self.deal_stage {
+            DealStage::Opening if self.is_stud() => {
+                self.deal_stud_street()
+                    .map_err(|e| trace_err!(e, "Failed to deal third street."))?;
+                return Ok(());
+            }
+            DealStage::Flop | DealStage::Turn | DealStage::River | DealStage::SeventhStreet
+                if self.is_stud() =>
+            {
+                self.deal_stud_street()
+                    .map_err(|e| trace_err!(e, "Failed to deal stud street."))?;
+            }
             DealStage::Opening => {
                 self.deal_opening_cards()
                     .map_err(|e| trace_err!(e, "Failed to deal opening cards."))?;
@@ -142,8 +153,8 @@ impl Table {
     /// - [`GameError::Other`] if the user table data cannot be retrieved
     fn deal_opening_cards(&mut self) -> Result<(), TracedError<GameError>> {
         let num_hole_cards = match self.config.game_type {
-            GameType::PotLimitOmaha4(_) => 4,
-            GameType::PotLimitOmaha5(_) => 5,
+            GameType::PotLimitOmaha4(_) | GameType::PotLimitOmahaHiLo4(_) => 4,
+            GameType::PotLimitOmaha5(_) | GameType::PotLimitOmahaHiLo5(_) => 5,
             _ => 2,
         };
 
@@ -187,6 +198,78 @@ impl Table {
         Ok(())
     }
 
+    /// Deals the cards of the next Seven Card Stud street to every player still in the hand.
+    ///
+    /// Third street (`Opening`) is two down cards and one up card, fourth to sixth
+    /// street (`Flop`, `Turn`, `River`) one up card each and seventh street one down card.
+    /// If the deck runs out on seventh street a single community card is dealt instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::NoCardsLeft`] if there are no cards left in the deck
+    fn deal_stud_street(&mut self) -> Result<(), TracedError<GameError>> {
+        let (down_cards, up_cards, next_stage) = match self.deal_stage {
+            DealStage::Opening => (2, 1, DealStage::Flop),
+            DealStage::Flop => (0, 1, DealStage::Turn),
+            DealStage::Turn => (0, 1, DealStage::River),
+            DealStage::River => (0, 1, DealStage::SeventhStreet),
+            DealStage::SeventhStreet => (1, 0, DealStage::Showdown),
+            _ => return Ok(()),
+        };
+
+        let players: Vec<WalletPrincipalId> = self
+            .seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(principal) => Some(*principal),
+                _ => None,
+            })
+            .filter(|principal| {
+                self.user_table_data
+                    .get(principal)
+                    .map(|data| {
+                        !matches!(
+                            data.player_action,
+                            PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+                        )
+                    })
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        if self.deal_stage == DealStage::SeventhStreet && self.deck.len() < players.len() {
+            self.deal_card()
+                .map_err(|e| trace_err!(e, "Failed to deal shared card on seventh street."))?;
+            self.deal_stage = next_stage;
+            return Ok(());
+        }
+
+        for i in 0..down_cards + up_cards {
+            let is_up_card = i >= down_cards;
+            for user_principal in &players {
+                let card = self
+                    .deck
+                    .deal()
+                    .ok_or_else(|| trace_err!(TracedError::new(GameError::NoCardsLeft)))?;
+                self.update_card_provenance(card, Some(*user_principal), self.deal_stage);
+                if let Some(user_table_data) = self.user_table_data.get_mut(user_principal) {
+                    user_table_data.cards.push(card);
+                    if is_up_card {
+                        user_table_data
+                            .up_cards
+                            .get_or_insert_with(Vec::new)
+                            .push(card);
+                    }
+                }
+                if is_up_card {
+                    self.reveal_card_provenance(&[card]);
+                }
+            }
+        }
+        self.deal_stage = next_stage;
+        Ok(())
+    }
+
     /// Deals the flop cards (the first three community cards)
     ///
     /// # Errors
@@ -199,6 +282,8 @@ impl Table {
             self.deal_card()
                 .map_err(|e| trace_err!(e, "Failed to deal card in deal_flop_cards."))?;
         }
+        self.deal_second_board_cards(3)
+            .map_err(|e| trace_err!(e, "Failed to deal second board in deal_flop_cards."))?;
         self.deal_stage = DealStage::Turn;
         Ok(())
     }
@@ -211,6 +296,8 @@ impl Table {
     fn deal_turn_card(&mut self) -> Result<(), TracedError<GameError>> {
         self.burn_and_deal()
             .map_err(|e| trace_err!(e, "Failed to burn and deal in deal_turn_card."))?;
+        self.deal_second_board_cards(1)
+            .map_err(|e| trace_err!(e, "Failed to deal second board in deal_turn_card."))?;
         self.deal_stage = DealStage::River;
         Ok(())
     }
@@ -223,10 +310,38 @@ impl Table {
     fn deal_river_card(&mut self) -> Result<(), TracedError<GameError>> {
         self.burn_and_deal()
             .map_err(|e| trace_err!(e, "Failed to burn and deal in deal_river_card."))?;
+        self.deal_second_board_cards(1)
+            .map_err(|e| trace_err!(e, "Failed to deal second board in deal_river_card."))?;
         self.deal_stage = DealStage::Showdown;
         Ok(())
     }
 
+    /// Burns a card and deals `count` cards to the second board of a double board bomb pot.
+    ///
+    /// Does nothing if the hand has a single board.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::NoCardsLeft`] if there are no cards left in the deck
+    fn deal_second_board_cards(&mut self, count: usize) -> Result<(), TracedError<GameError>> {
+        if self.second_board.is_none() {
+            return Ok(());
+        }
+        self.burn_card()
+            .map_err(|e| trace_err!(e, "Failed to burn card for the second board."))?;
+        for _ in 0..count {
+            let card = self
+                .deck
+                .deal()
+                .ok_or_else(|| trace_err!(TracedError::new(GameError::NoCardsLeft)))?;
+            self.update_card_provenance(card, None, self.deal_stage);
+            if let Some(second_board) = self.second_board.as_mut() {
+                second_board.push(card);
+            }
+        }
+        Ok(())
+    }
+
     /// Discards a card from the deck and deals a card to the community cards
     ///
     /// # Errors
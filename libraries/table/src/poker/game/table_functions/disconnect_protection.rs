@@ -0,0 +1,136 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::{action_log::ActionType, table::Table, types::PlayerAction};
+
+/// The nanoseconds in a day, the period the protections are counted over.
+pub const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// Keeps players whose turn times out in the hand instead of folding them.
+///
+/// A protected player is treated as all in for the chips they already put in:
+/// they stay eligible for that part of the pot and everything bet on top of it
+/// goes into a side pot.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct DisconnectProtectionConfig {
+    /// How many times a day each player can be protected.
+    pub protections_per_day: u8,
+}
+
+/// How many protections a player used on a given day.
+#[derive(Debug, Clone, Copy, Default, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct DisconnectProtectionUsage {
+    /// The day the protections were used on, counted from the Unix epoch.
+    pub day: u64,
+    /// The protections used that day.
+    pub used: u8,
+}
+
+impl Table {
+    /// The protections the user has left today.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `now` - The current time in nanoseconds
+    pub fn get_disconnect_protections_left(
+        &self,
+        user_principal: WalletPrincipalId,
+        now: u64,
+    ) -> u8 {
+        let Some(protection) = &self.config.disconnect_protection else {
+            return 0;
+        };
+        let used = self
+            .disconnect_protection_usage
+            .get(&user_principal)
+            .filter(|usage| usage.day == now / NANOS_PER_DAY)
+            .map_or(0, |usage| usage.used);
+        protection.protections_per_day.saturating_sub(used)
+    }
+
+    /// Protects the user when their turn timer runs out while they face a bet.
+    ///
+    /// Returns `false` when the user cannot be protected, in which case
+    /// they are folded as before. Users who have not put any chips into
+    /// the pot yet are not protected, as folding costs them nothing.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user whose timer ran out
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    /// - [`GameError::PlayerNotFound`] if retrieving a player fails
+    pub fn protect_disconnected_user(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        now: u64,
+    ) -> Result<bool, TracedError<GameError>> {
+        let protections_left = self.get_disconnect_protections_left(user_principal, now);
+        if protections_left == 0
+            || !self.is_game_ongoing()
+            || !self.is_players_turn(user_principal)
+            || self.sorted_users.is_some()
+        {
+            return Ok(false);
+        }
+        let user_table_data = self
+            .get_user_table_data(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to protect user."))?;
+        if user_table_data.total_bet + user_table_data.current_total_bet == 0 {
+            return Ok(false);
+        }
+
+        self.conserving_chips(0, |table| {
+            table.handle_protect_disconnected_user(user_principal, protections_left - 1, now)
+        })?;
+        Ok(true)
+    }
+
+    /// Freezes the stake of the user in [`Table::protect_disconnected_user`].
+    fn handle_protect_disconnected_user(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        protections_left: u8,
+        now: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        let user_table_data = self.get_user_table_data_mut(user_principal).map_err(|e| {
+            trace_err!(
+                e,
+                "Failed to get user table data to freeze the user's stake."
+            )
+        })?;
+        user_table_data.inactive_turns += 1;
+        user_table_data.player_action = PlayerAction::AllIn;
+
+        let day = now / NANOS_PER_DAY;
+        let usage = self
+            .disconnect_protection_usage
+            .entry(user_principal)
+            .or_default();
+        if usage.day != day {
+            *usage = DisconnectProtectionUsage { day, used: 0 };
+        }
+        usage.used += 1;
+        self.log_action(
+            Some(user_principal),
+            ActionType::DisconnectProtection { protections_left },
+        );
+
+        self.next_player()
+            .map_err(|e| trace_err!(e, "Failed to get next player in disconnect protection."))?;
+        // The side pot for the chips bet on top of the frozen stake is formed with the pots
+        self.check_next_turn_or_showdown().map_err(|e| {
+            trace_err!(
+                e,
+                "Failed to check_next_turn_or_showdown in disconnect protection."
+            )
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+
+use crate::poker::{
+    core::{calculate_equity, Card, Equity, EquityResult},
+    game::types::GameType,
+};
+
+use super::{
+    action_log::ActionType,
+    table::Table,
+    types::{PlayerAction, SeatStatus},
+};
+
+impl Table {
+    /// Calculates the equities of known hands in this table's game for post-hand review.
+    ///
+    /// # Parameters
+    ///
+    /// - `hands` - The hole cards of every player to compare, at most one per seat
+    /// - `board` - The community cards dealt so far
+    /// - `dead_cards` - Cards that are known to be out of the deck
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if equities cannot be calculated for the game type
+    ///   or there are more hands than seats
+    /// - [`GameError::Other`] if a hand does not have the game's number of hole cards or
+    ///   the cards are not a valid spot
+    pub fn calculate_equity(
+        &self,
+        hands: &[Vec<Card>],
+        board: &[Card],
+        dead_cards: &[Card],
+    ) -> Result<EquityResult, TracedError<GameError>> {
+        let game = self.config.game_type.equity_game().ok_or_else(|| {
+            trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Equities cannot be calculated for this game type".to_string(),
+            }))
+        })?;
+        if hands.len() > self.config.seats as usize {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "There are more hands than seats at the table".to_string(),
+            })));
+        }
+        let hole_cards = match self.config.game_type {
+            GameType::PotLimitOmaha4(_) => 4,
+            GameType::PotLimitOmaha5(_) => 5,
+            _ => 2,
+        };
+        if let Some(hand) = hands.iter().find(|hand| hand.len() != hole_cards) {
+            return Err(trace_err!(TracedError::new(GameError::Other(format!(
+                "A hand of {} cards cannot be played at this table",
+                hand.len()
+            )))));
+        }
+        calculate_equity(game, hands, board, dead_cards, self.round_ticker)
+            .map_err(|e| trace_err!(TracedError::new(GameError::Other(e.to_string()))))
+    }
+
+    /// The equities logged when the players went all in this hand, if they did.
+    ///
+    /// Each entry is the user name, their hole cards and their equity.
+    pub fn get_all_in_equities(&self) -> Option<Vec<(String, Vec<Card>, Equity)>> {
+        self.action_logs
+            .iter()
+            .rev()
+            .find_map(|action_log| match &action_log.action_type {
+                ActionType::AllInEquity { equities, .. } => Some(equities.clone()),
+                _ => None,
+            })
+    }
+
+    /// Logs the equity of every player still in the hand before the rest of the
+    /// board is dealt out, so the hand history can replay it.
+    ///
+    /// Nothing is logged when the board is complete or the game has no equities.
+    pub(crate) fn log_all_in_equities(&mut self) {
+        let Some(game) = self.config.game_type.equity_game() else {
+            return;
+        };
+        if self.community_cards.len() >= 5 {
+            return;
+        }
+
+        let mut user_names = Vec::new();
+        let mut hands = Vec::new();
+        for seat in self.seats.iter() {
+            let SeatStatus::Occupied(user_principal) = seat else {
+                continue;
+            };
+            let (Some(user), Some(user_table_data)) = (
+                self.users.get(user_principal),
+                self.user_table_data.get(user_principal),
+            ) else {
+                continue;
+            };
+            if matches!(
+                user_table_data.player_action,
+                PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+            ) {
+                continue;
+            }
+            user_names.push(user.user_name.clone());
+            hands.push(user_table_data.cards.clone());
+        }
+
+        // The round ticker seeds the sample so the logged equities can be reproduced.
+        let result =
+            match calculate_equity(game, &hands, &self.community_cards, &[], self.round_ticker) {
+                Ok(result) => result,
+                Err(e) => {
+                    ic_cdk::println!("Could not calculate all in equities: {}", e);
+                    return;
+                }
+            };
+
+        let equities = user_names
+            .into_iter()
+            .zip(hands)
+            .zip(result.equities)
+            .map(|((user_name, cards), equity)| (user_name, cards, equity))
+            .collect();
+        self.log_action(
+            None,
+            ActionType::AllInEquity {
+                community_cards: self.community_cards.clone(),
+                equities,
+                is_exhaustive: result.is_exhaustive,
+            },
+        );
+    }
+}
@@ -22,6 +22,17 @@ impl Table {
         &mut self,
         user_principal: WalletPrincipalId,
         is_inactive_user: bool,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| {
+            table.handle_user_fold(user_principal, is_inactive_user)
+        })
+    }
+
+    /// Folds the hand of the user in [`Table::user_fold`].
+    fn handle_user_fold(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        is_inactive_user: bool,
     ) -> Result<(), TracedError<GameError>> {
         if !is_inactive_user {
             #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
@@ -79,6 +90,14 @@ impl Table {
     pub fn user_pre_fold(
         &mut self,
         user_principal: WalletPrincipalId,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| table.handle_user_pre_fold(user_principal))
+    }
+
+    /// Folds the hand of the user in [`Table::user_pre_fold`].
+    fn handle_user_pre_fold(
+        &mut self,
+        user_principal: WalletPrincipalId,
     ) -> Result<(), TracedError<GameError>> {
         let user_table_data = self.get_user_table_data_mut(user_principal).map_err(|e| {
             trace_err!(
@@ -126,6 +145,14 @@ impl Table {
     pub fn force_fold(
         &mut self,
         user_principal: WalletPrincipalId,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| table.handle_force_fold(user_principal))
+    }
+
+    /// Folds the hand of the user in [`Table::force_fold`].
+    fn handle_force_fold(
+        &mut self,
+        user_principal: WalletPrincipalId,
     ) -> Result<(), TracedError<GameError>> {
         if self.sorted_users.is_none() {
             if self
@@ -0,0 +1,34 @@
+use user::user::WalletPrincipalId;
+
+use super::{table::Table, types::SeatStatus};
+
+impl Table {
+    /// The players who cannot cover the big blind and are knocked out before the next hand,
+    /// with the stack each of them started the last hand with.
+    ///
+    /// The tournament ranks players knocked out in the same hand-for-hand hand by these stacks.
+    pub fn get_busted_players(&self) -> Vec<(WalletPrincipalId, u64)> {
+        self.seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(user_principal) => Some(*user_principal),
+                _ => None,
+            })
+            .filter(|user_principal| {
+                self.check_user_balance(self.big_blind.0, *user_principal)
+                    .is_err()
+            })
+            .map(|user_principal| {
+                let balance = self
+                    .users
+                    .get(&user_principal)
+                    .map_or(0, |user| user.balance.0);
+                let total_bet = self
+                    .user_table_data
+                    .get(&user_principal)
+                    .map_or(0, |data| data.total_bet);
+                (user_principal, balance + total_bet)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+
+use crate::poker::game::types::QueueItem;
+
+use super::table::Table;
+
+/// Where the chips at the table are held at a point in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChipCount {
+    /// The chips behind of every user at the table.
+    pub balances: u64,
+    /// The chips bet in the current stage that have not been moved into a pot yet.
+    pub bets: u64,
+    /// The main pot.
+    pub pot: u64,
+    /// All side pots.
+    pub side_pots: u64,
+    /// The rake that has not been withdrawn yet.
+    pub rake: u64,
+}
+
+impl ChipCount {
+    /// All chips the table is holding.
+    pub fn total(&self) -> u64 {
+        self.balances
+            .saturating_add(self.bets)
+            .saturating_add(self.pot)
+            .saturating_add(self.side_pots)
+            .saturating_add(self.rake)
+    }
+}
+
+impl Table {
+    /// Counts the chips the table is holding.
+    pub fn count_chips(&self) -> ChipCount {
+        ChipCount {
+            balances: self.users.users.values().map(|user| user.balance.0).sum(),
+            bets: self
+                .user_table_data
+                .values()
+                .map(|data| data.current_total_bet)
+                .sum(),
+            pot: self.pot.0,
+            // Confirming a side pot moves its open amount over to the confirmed one
+            side_pots: self
+                .side_pots
+                .iter()
+                .map(|side_pot| side_pot.pot.max(side_pot.confirmed_pot))
+                .sum(),
+            rake: self.rake_total.unwrap_or(0),
+        }
+    }
+
+    /// Checks that no chips were created or lost since `before` was counted.
+    ///
+    /// # Parameters
+    ///
+    /// - `before` - The chips counted before the transition
+    /// - `withdrawn` - The chips taken off the table during the transition
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ChipsNotConserved`] if the chips at the table do not add up
+    pub fn check_chip_conservation(
+        &self,
+        before: &ChipCount,
+        withdrawn: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        let after = self.count_chips();
+        let expected = before.total().saturating_sub(withdrawn);
+        let actual = after.total();
+        if expected != actual {
+            return Err(trace_err!(
+                TracedError::new(GameError::ChipsNotConserved { expected, actual }),
+                format!("Chips before: {:?}, chips after: {:?}", before, after)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs a `transition` of the table and checks that it conserved chips.
+    ///
+    /// The check only runs in debug and test builds, or with the `chip_invariants`
+    /// feature, so release canisters do not pay for the extra counting.
+    ///
+    /// # Parameters
+    ///
+    /// - `withdrawn` - The chips the transition takes off the table
+    /// - `transition` - The transition to run
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by `transition`
+    /// - [`GameError::ChipsNotConserved`] if the transition created or lost chips
+    pub(crate) fn conserving_chips<T>(
+        &mut self,
+        withdrawn: u64,
+        transition: impl FnOnce(&mut Self) -> Result<T, TracedError<GameError>>,
+    ) -> Result<T, TracedError<GameError>> {
+        #[cfg(any(debug_assertions, feature = "chip_invariants"))]
+        {
+            let before = self.count_chips();
+            let result = transition(self)?;
+            self.check_chip_conservation(&before, withdrawn)
+                .map_err(|e| trace_err!(e, "Transition did not conserve chips."))?;
+            Ok(result)
+        }
+        #[cfg(not(any(debug_assertions, feature = "chip_invariants")))]
+        {
+            let _ = withdrawn;
+            transition(self)
+        }
+    }
+
+    /// The chips of the users that are queued to leave the table.
+    pub(crate) fn get_queued_withdrawals(&self) -> u64 {
+        let mut leaving = HashSet::new();
+        self.queue
+            .iter()
+            .filter_map(|item| match item {
+                QueueItem::RemoveUser(user_principal, _)
+                | QueueItem::LeaveTableToMove(_, user_principal, _) => Some(*user_principal),
+                _ => None,
+            })
+            .filter(|user_principal| leaving.insert(*user_principal))
+            .filter_map(|user_principal| self.users.get(&user_principal))
+            .map(|user| user.balance.0)
+            .sum()
+    }
+}
@@ -10,26 +10,58 @@ pub mod all_in_util_functions;
 
 pub mod ante;
 
+pub mod auto_rebuy;
+
 pub mod bet;
 
+pub mod bomb_pot;
+
+pub mod buy_in;
+
 pub mod check;
 
+pub mod dead_button;
+
 pub mod deal_functions;
 
+pub mod disconnect_protection;
+
+pub mod equity;
+
 pub mod fold;
 
+pub mod hand_for_hand;
+
+pub mod invariants;
+
+pub mod post_hand;
+
 pub mod pot;
 
+pub mod pre_action;
+
 pub mod rake;
 
+pub mod rng_archive;
+
+pub mod run_it;
+
 pub mod showdown;
 
+pub mod shuffle_seed;
+
 pub mod side_pot;
 
 pub mod sitting_out;
 
+pub mod straddle;
+
+pub mod stud;
+
 pub mod table;
 
+pub mod time_bank;
+
 pub mod timer;
 
 pub mod turn;
@@ -39,3 +71,5 @@ pub mod types;
 pub mod user;
 
 pub mod utils;
+
+pub mod waitlist;
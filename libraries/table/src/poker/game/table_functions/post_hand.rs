@@ -0,0 +1,248 @@
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use user::user::WalletPrincipalId;
+
+use crate::poker::core::Card;
+use crate::poker::game::types::CardProvenance;
+
+use super::{action_log::ActionType, table::Table, types::PlayerAction};
+
+impl Table {
+    /// Whether the hand is over and the next one has not started yet.
+    ///
+    /// Players can show or muck their cards and rabbit hunt the board in this window.
+    pub fn is_post_hand_window_open(&self) -> bool {
+        self.sorted_users.is_some()
+    }
+
+    /// Whether the cards no one saw stay private once the hand is over.
+    pub fn keeps_mucked_cards_private(&self) -> bool {
+        self.config.keep_mucked_cards_private.unwrap_or(false)
+    }
+
+    /// Whether the card can be revealed to everyone once its hand is over.
+    ///
+    /// Every card can, unless the table keeps mucked cards private,
+    /// in which case only the cards the whole table saw can.
+    pub fn is_card_public_after_hand(&self, provenance: &CardProvenance) -> bool {
+        !self.keeps_mucked_cards_private() || provenance.is_seen_by_table()
+    }
+
+    /// Shows or mucks each of the user's hole cards after the hand.
+    ///
+    /// Hands that went to a contested showdown are tabled, so their cards can be shown
+    /// again but not mucked.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `shown_cards` - Whether to show each hole card, in the order they were dealt
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the hand is not over, the user was not dealt in,
+    ///   there is not one choice per hole card or a tabled card would be mucked
+    /// - [`GameError::Other`] if a card does not match its provenance
+    pub fn show_or_muck_cards(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        shown_cards: Vec<bool>,
+    ) -> Result<(), TracedError<GameError>> {
+        if !self.is_post_hand_window_open() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "The hand is not over yet".to_string(),
+            })));
+        }
+        let user_table_data = self
+            .get_user_table_data(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to show cards."))?;
+        if user_table_data.cards.is_empty() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "You were not dealt in this hand".to_string(),
+            })));
+        }
+        if shown_cards.len() != user_table_data.cards.len() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Choose to show or muck each of your cards".to_string(),
+            })));
+        }
+        let was_shown = user_table_data.shown_cards.clone().unwrap_or_default();
+        if self.is_shown_down(user_principal)
+            && shown_cards
+                .iter()
+                .zip(&was_shown)
+                .any(|(shown, was_shown)| !shown && *was_shown)
+        {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Cards shown down cannot be mucked".to_string(),
+            })));
+        }
+
+        let cards: Vec<Card> = user_table_data
+            .cards
+            .iter()
+            .zip(&shown_cards)
+            .filter(|(_, shown)| **shown)
+            .map(|(card, _)| *card)
+            .collect();
+        for card in &cards {
+            self.check_card_provenance(*card, Some(user_principal))
+                .map_err(|e| trace_err!(e, "Failed to check provenance of shown card."))?;
+        }
+
+        self.reveal_card_provenance(&cards);
+
+        let mucked = (shown_cards.len() - cards.len()) as u8;
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to show cards."))?
+            .shown_cards = Some(shown_cards);
+        self.log_action(
+            Some(user_principal),
+            ActionType::ShowCards { cards, mucked },
+        );
+        Ok(())
+    }
+
+    /// Reveals the board cards that would have come had the hand gone on.
+    ///
+    /// The cards are dealt from the rest of the deck with a card burnt before every
+    /// street, without changing the outcome of the hand.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user asking for the rabbit hunt
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the hand is not over, the user is not at the table,
+    ///   the board is complete, the hand had two boards or it was already rabbit hunted
+    /// - [`GameError::NoCardsLeft`] if there are not enough cards left in the deck
+    /// - [`GameError::Other`] if a card does not match its provenance
+    pub fn rabbit_hunt(
+        &mut self,
+        user_principal: WalletPrincipalId,
+    ) -> Result<(), TracedError<GameError>> {
+        let reason = if !self.is_post_hand_window_open() {
+            Some("The hand is not over yet")
+        } else if !self.user_table_data.contains_key(&user_principal) {
+            Some("You are not at the table")
+        } else if self.is_stud() || self.community_cards.len() >= 5 {
+            Some("There is no board left to rabbit hunt")
+        } else if self.second_board.is_some() {
+            Some("Double board hands cannot be rabbit hunted")
+        } else if self.rabbit_hunt_cards.is_some() {
+            Some("The board was already rabbit hunted")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: reason.to_string(),
+            })));
+        }
+
+        let mut cards = Vec::new();
+        let mut board_len = self.community_cards.len();
+        while board_len < 5 {
+            let street_len = if board_len == 0 { 3 } else { 1 };
+            // Burn a card before every street
+            self.deck
+                .deal()
+                .ok_or_else(|| trace_err!(TracedError::new(GameError::NoCardsLeft)))?;
+            for _ in 0..street_len {
+                let card = self
+                    .deck
+                    .deal()
+                    .ok_or_else(|| trace_err!(TracedError::new(GameError::NoCardsLeft)))?;
+                self.check_card_provenance(card, None)
+                    .map_err(|e| trace_err!(e, "Failed to check provenance of rabbit card."))?;
+                cards.push(card);
+            }
+            board_len += street_len;
+        }
+
+        self.reveal_card_provenance(&cards);
+        self.rabbit_hunt_cards = Some(cards.clone());
+        self.log_action(
+            Some(user_principal),
+            ActionType::RabbitHunt {
+                community_cards: cards,
+            },
+        );
+        Ok(())
+    }
+
+    /// Tables the hands that went to a contested showdown and mucks every other hand.
+    pub(crate) fn set_showdown_shown_cards(&mut self) {
+        let is_contested = !self.is_won_uncontested();
+        let mut shown_down_cards = Vec::new();
+        for table_data in self.user_table_data.values_mut() {
+            let is_shown_down = is_contested
+                && !matches!(
+                    table_data.player_action,
+                    PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+                );
+            table_data.shown_cards = Some(vec![is_shown_down; table_data.cards.len()]);
+            if is_shown_down {
+                shown_down_cards.extend(table_data.cards.iter().copied());
+            }
+        }
+        self.reveal_card_provenance(&shown_down_cards);
+    }
+
+    /// Records on their provenance that the whole table saw the `cards` this round.
+    pub(crate) fn reveal_card_provenance(&mut self, cards: &[Card]) {
+        let round_id = self.round_ticker;
+        for provenance in self.card_provenance.values_mut() {
+            if provenance.round_id == round_id && cards.contains(&provenance.card) {
+                provenance.is_revealed = Some(true);
+            }
+        }
+    }
+
+    /// Whether the user's hand went to a contested showdown.
+    fn is_shown_down(&self, user_principal: WalletPrincipalId) -> bool {
+        !self.is_won_uncontested()
+            && self
+                .user_table_data
+                .get(&user_principal)
+                .is_some_and(|data| {
+                    !matches!(
+                        data.player_action,
+                        PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+                    )
+                })
+    }
+
+    /// Checks that the card was dealt to `dealt_to` this round, or never dealt
+    /// when `dealt_to` is `None`, according to its provenance.
+    ///
+    /// Rounds without any provenance are not checked.
+    fn check_card_provenance(
+        &self,
+        card: Card,
+        dealt_to: Option<WalletPrincipalId>,
+    ) -> Result<(), TracedError<GameError>> {
+        let mut round_provenance = self
+            .card_provenance
+            .values()
+            .filter(|provenance| provenance.round_id == self.round_ticker)
+            .peekable();
+        if round_provenance.peek().is_none() {
+            return Ok(());
+        }
+
+        let is_matching = round_provenance.any(|provenance| {
+            provenance.card == card
+                && provenance.dealt_to == dealt_to
+                && (dealt_to.is_some() || provenance.dealt_at_stage.is_none())
+        });
+        if is_matching {
+            Ok(())
+        } else {
+            Err(trace_err!(TracedError::new(GameError::Other(format!(
+                "Card {:?} does not match its provenance",
+                card
+            )))))
+        }
+    }
+}
@@ -91,6 +91,17 @@ impl Table {
     ///
     /// This function should be called after each betting round to adjust the pots accordingly.
     pub fn calculate_pots(&mut self) -> Result<(), TracedError<GameError>> {
+        // Blinds posted by players sitting out are dead money in the main pot.
+        let mut dead_money = 0;
+        for data in self.user_table_data.values_mut() {
+            if data.player_action == PlayerAction::SittingOut {
+                dead_money += data.current_total_bet;
+                data.total_bet += data.current_total_bet;
+                data.current_total_bet = 0;
+            }
+        }
+        self.add_to_pot(dead_money);
+
         // Collect all active players and their current_total_bet.
         // Active players are those who have not folded.
         let mut players_bets: Vec<(WalletPrincipalId, u64)> = self
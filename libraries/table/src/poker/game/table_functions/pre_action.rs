@@ -0,0 +1,175 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::{
+    table::Table,
+    types::{BetType, PlayerAction},
+};
+
+/// An action a player submits before their turn, taken for them once the turn reaches them.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub enum PreAction {
+    /// Check. Cancelled if someone bets first.
+    Check,
+    /// Check, or fold if someone bets first.
+    CheckFold,
+    /// Call the given amount, the highest bet when it was submitted.
+    /// Cancelled if someone raises first.
+    Call(u64),
+    /// Call whatever the highest bet is once the turn comes.
+    CallAny,
+    /// Raise the total bet of the stage to the given amount.
+    /// Cancelled if someone raises first.
+    RaiseTo(u64),
+}
+
+impl PreAction {
+    /// Whether the pre-action no longer applies once the highest bet goes up.
+    pub fn is_cancelled_by_raise(&self) -> bool {
+        matches!(
+            self,
+            PreAction::Check | PreAction::Call(_) | PreAction::RaiseTo(_)
+        )
+    }
+}
+
+impl Table {
+    /// Sets the action the user takes once the turn reaches them, or clears it with `None`.
+    ///
+    /// Pre-actions only last for the current stage of the hand.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `pre_action` - The action to take at the user's turn
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if there is no hand in progress, the user is not in it,
+    ///   it is already their turn or the pre-action does not match the current bet
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn set_user_pre_action(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        pre_action: Option<PreAction>,
+    ) -> Result<(), TracedError<GameError>> {
+        let Some(pre_action) = pre_action else {
+            self.get_user_table_data_mut(user_principal)
+                .map_err(|e| trace_err!(e, "Failed to get user table data to clear pre-action."))?
+                .pre_action = None;
+            return Ok(());
+        };
+
+        let user_table_data = self
+            .get_user_table_data(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to set pre-action."))?;
+        let reason = if !self.is_game_ongoing()
+            || matches!(
+                user_table_data.player_action,
+                PlayerAction::Folded
+                    | PlayerAction::AllIn
+                    | PlayerAction::SittingOut
+                    | PlayerAction::Joining
+            ) {
+            Some("You are not in a hand")
+        } else if self.is_players_turn(user_principal) {
+            Some("It is already your turn")
+        } else {
+            match pre_action {
+                PreAction::Check if user_table_data.current_total_bet < self.highest_bet => {
+                    Some("You cannot check with a bet in front of you")
+                }
+                PreAction::Call(amount)
+                    if amount != self.highest_bet
+                        || amount <= user_table_data.current_total_bet =>
+                {
+                    Some("The amount to call has changed")
+                }
+                PreAction::RaiseTo(amount) if amount <= self.highest_bet => {
+                    Some("The raise has to be above the current bet")
+                }
+                _ => None,
+            }
+        };
+        if let Some(reason) = reason {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: reason.to_string(),
+            })));
+        }
+
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to set pre-action."))?
+            .pre_action = Some(pre_action);
+        Ok(())
+    }
+
+    /// Hides the pre-actions of every user but the one by `user_id`.
+    pub fn hide_pre_actions(&mut self, user_id: WalletPrincipalId) {
+        for (user_principal, table_data) in self.user_table_data.iter_mut() {
+            if *user_principal != user_id {
+                table_data.pre_action = None;
+            }
+        }
+    }
+
+    /// Cancels the pre-actions that depended on the highest bet, after it went up.
+    pub(crate) fn cancel_pre_actions_on_raise(&mut self) {
+        for table_data in self.user_table_data.values_mut() {
+            if table_data
+                .pre_action
+                .is_some_and(|pre_action| pre_action.is_cancelled_by_raise())
+            {
+                table_data.pre_action = None;
+            }
+        }
+    }
+
+    /// Takes the pre-action of the player whose turn it is, if they submitted one.
+    ///
+    /// A pre-action that can no longer be taken is dropped and the player acts themselves.
+    pub(crate) fn run_pre_action(&mut self) {
+        if !self.is_game_ongoing() {
+            return;
+        }
+        let Ok(user_principal) = self.get_player_at_seat(self.current_player_index) else {
+            return;
+        };
+        let highest_bet = self.highest_bet;
+        let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) else {
+            return;
+        };
+        if matches!(
+            user_table_data.player_action,
+            PlayerAction::Folded | PlayerAction::AllIn | PlayerAction::SittingOut
+        ) {
+            return;
+        }
+        let Some(pre_action) = user_table_data.pre_action.take() else {
+            return;
+        };
+
+        let can_check = user_table_data.current_total_bet >= highest_bet;
+        let res = match pre_action {
+            PreAction::Check | PreAction::CheckFold | PreAction::Call(_) | PreAction::CallAny
+                if can_check =>
+            {
+                self.user_check(user_principal, false)
+            }
+            PreAction::Check => Ok(()),
+            PreAction::CheckFold => self.user_fold(user_principal, false),
+            PreAction::Call(amount) if amount != highest_bet => Ok(()),
+            PreAction::Call(_) | PreAction::CallAny => self.bet(user_principal, BetType::Called),
+            PreAction::RaiseTo(amount) => self.bet(user_principal, BetType::Raised(amount)),
+        };
+        if let Err(e) = res {
+            ic_cdk::println!(
+                "Could not take pre-action {:?} for user {}: {:?}",
+                pre_action,
+                user_principal.0.to_text(),
+                e
+            );
+        }
+    }
+}
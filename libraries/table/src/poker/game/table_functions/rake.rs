@@ -86,10 +86,16 @@ impl Rake {
         }
 
         let rake = match game_type {
-            GameType::NoLimit(_) | GameType::PotLimit(_) | GameType::PotLimitOmaha4(_) | GameType::PotLimitOmaha5(_) => get_no_limit_config(scaled_small_blind),
-            GameType::FixedLimit(_, _) | GameType::SpreadLimit(_, _) => {
-                get_fixed_limit_configs(scaled_small_blind)
-            }
+            GameType::NoLimit(_)
+            | GameType::PotLimit(_)
+            | GameType::PotLimitOmaha4(_)
+            | GameType::PotLimitOmaha5(_)
+            | GameType::PotLimitOmahaHiLo4(_)
+            | GameType::PotLimitOmahaHiLo5(_)
+            | GameType::ShortDeck(_) => get_no_limit_config(scaled_small_blind),
+            GameType::FixedLimit(_, _)
+            | GameType::SpreadLimit(_, _)
+            | GameType::SevenCardStud(_, _) => get_fixed_limit_configs(scaled_small_blind),
         };
 
         match rake {
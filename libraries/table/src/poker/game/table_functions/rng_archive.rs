@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::poker::game::types::{ArchivedRound, CardProvenance};
+
+use super::table::Table;
+
+/// How many rounds of RNG data a table keeps before older ones are archived.
+///
+/// Every round adds a whole deck and the provenance of each of its cards, all of
+/// which is serialized with the table on upgrade.
+pub const MAX_RNG_HISTORY_ROUNDS: usize = 50;
+
+/// How many rounds are archived at most when a hand starts.
+///
+/// Archiving a round takes a stable memory insert per card, so a table that
+/// built up a long history before archiving existed works it off a few rounds
+/// per hand instead of in one message.
+pub const MAX_ROUNDS_ARCHIVED_PER_HAND: usize = 5;
+
+/// How many rounds a page of RNG history holds by default, and at most.
+pub const DEFAULT_RNG_HISTORY_PAGE_SIZE: usize = 10;
+pub const MAX_RNG_HISTORY_PAGE_SIZE: usize = 50;
+
+/// How many cards a page of card provenance holds by default, and at most: a full deck.
+pub const DEFAULT_CARD_PROVENANCE_PAGE_SIZE: usize = 52;
+pub const MAX_CARD_PROVENANCE_PAGE_SIZE: usize = 52;
+
+impl Table {
+    /// Takes the RNG history and card provenance of up to `max_rounds` of the
+    /// rounds before the last `rounds_to_keep` out of the table, oldest round first.
+    pub fn take_archivable_rounds(
+        &mut self,
+        rounds_to_keep: usize,
+        max_rounds: usize,
+    ) -> Vec<ArchivedRound> {
+        if self.rng_history.len() <= rounds_to_keep {
+            return Vec::new();
+        }
+
+        let archived_len = (self.rng_history.len() - rounds_to_keep).min(max_rounds);
+        if archived_len == 0 {
+            return Vec::new();
+        }
+        let archived_history: Vec<_> = self.rng_history.drain(..archived_len).collect();
+        let archived_round_ids: HashSet<u64> = archived_history
+            .iter()
+            .map(|rng_metadata| rng_metadata.round_id)
+            .collect();
+
+        let mut archived_provenance: HashMap<u64, Vec<CardProvenance>> = HashMap::new();
+        self.card_provenance.retain(|_, provenance| {
+            if !archived_round_ids.contains(&provenance.round_id) {
+                return true;
+            }
+            archived_provenance
+                .entry(provenance.round_id)
+                .or_default()
+                .push(provenance.clone());
+            false
+        });
+
+        archived_history
+            .into_iter()
+            .map(|rng_metadata| {
+                let mut card_provenance = archived_provenance
+                    .remove(&rng_metadata.round_id)
+                    .unwrap_or_default();
+                card_provenance.sort_by_key(|provenance| provenance.shuffled_position);
+                ArchivedRound {
+                    rng_metadata,
+                    card_provenance,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Takes the first `limit` items from `cursor` on, out of items sorted by `cursor_of`.
+///
+/// Returns the page and the cursor of the item that starts the next page, if any.
+pub fn paginate<T, C: PartialOrd + Copy>(
+    items: impl IntoIterator<Item = T>,
+    cursor: Option<C>,
+    limit: usize,
+    cursor_of: impl Fn(&T) -> C,
+) -> (Vec<T>, Option<C>) {
+    let mut page: Vec<T> = items
+        .into_iter()
+        .skip_while(|item| cursor.is_some_and(|cursor| cursor_of(item) < cursor))
+        .take(limit.saturating_add(1))
+        .collect();
+    let next_cursor = if page.len() > limit {
+        page.pop().map(|item| cursor_of(&item))
+    } else {
+        None
+    };
+    (page, next_cursor)
+}
@@ -0,0 +1,237 @@
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use user::user::WalletPrincipalId;
+
+use crate::poker::core::Card;
+
+use super::{
+    table::{Table, TableType},
+    types::{DealStage, NotificationMessage, PlayerAction, SeatStatus, UserTableData},
+};
+
+/// The most boards a hand can be run on.
+pub const MAX_RUNS: u8 = 3;
+/// How long the players still in the hand have to agree on the runs once everyone is all in.
+pub const RUN_IT_WINDOW_NS: u64 = 15 * 1_000_000_000;
+
+impl Table {
+    /// Sets how many times the user agrees to run the board once everyone is all in.
+    ///
+    /// The board is only run more than once if every player still in the hand
+    /// agreed to it, using the lowest number any of them picked. The agreement
+    /// only holds for the current hand. Once every player still in the hand
+    /// answered in the run it window, the board is run out straight away.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user agreeing
+    /// - `runs` - The number of boards to deal, between 1 and [`MAX_RUNS`]
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table is not a cash table, no hand is being
+    ///   played or `runs` is out of range
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn set_user_run_it_times(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        runs: u8,
+    ) -> Result<(), TracedError<GameError>> {
+        if !matches!(self.config.table_type, None | Some(TableType::Cash)) {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Running it more than once is only allowed on cash tables".to_string(),
+            })));
+        }
+        if !self.is_game_ongoing() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Running it more than once can only be agreed during a hand".to_string(),
+            })));
+        }
+        if runs == 0 || runs > MAX_RUNS {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: format!("The board can be run between 1 and {} times", MAX_RUNS),
+            })));
+        }
+
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to set run it times."))?
+            .run_it_times = Some(runs);
+
+        if self.run_it_window_closes_at.is_some() && self.has_everyone_answered_run_it() {
+            self.run_it_window_closes_at = None;
+            self.conserving_chips(0, |table| table.run_out_board())
+                .map_err(|e| trace_err!(e, "Failed to run out the board after the agreement."))?;
+        }
+        Ok(())
+    }
+
+    /// Gives the players still in the hand [`RUN_IT_WINDOW_NS`] to agree on running
+    /// the board more than once, now that everyone is all in.
+    ///
+    /// No window opens on tables that are not cash tables, when the board can only
+    /// be run once or when every player still in the hand already answered.
+    ///
+    /// Returns whether the window opened, in which case the board is run out by
+    /// [`Table::close_run_it_window`] or once every player answered.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn open_run_it_window(&mut self, now: u64) -> Result<bool, TracedError<GameError>> {
+        if self.config.table_type != Some(TableType::Cash)
+            || self.get_max_runs() < 2
+            || self.has_everyone_answered_run_it()
+        {
+            return Ok(false);
+        }
+
+        // The bets are in the pots by now, so the table holds them only once while it waits
+        self.prepare_user_actions(true)
+            .map_err(|e| trace_err!(e, "Failed to prepare user actions for the run it window."))?;
+        let expires_at = now.saturating_add(RUN_IT_WINDOW_NS);
+        self.run_it_window_closes_at = Some(expires_at);
+        let user_principals: Vec<WalletPrincipalId> = self
+            .seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(principal)
+                    if self
+                        .user_table_data
+                        .get(principal)
+                        .is_some_and(Self::is_in_hand) =>
+                {
+                    Some(*principal)
+                }
+                _ => None,
+            })
+            .collect();
+        for user_principal in user_principals {
+            self.notifications.add_notification(
+                user_principal,
+                NotificationMessage::RunItWindowOpened { expires_at },
+            );
+        }
+
+        #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+        {
+            // Nobody is left to act, the window timer runs out the board instead
+            self.clear_turn_timer();
+            self.start_run_it_window_timer(RUN_IT_WINDOW_NS);
+        }
+        Ok(true)
+    }
+
+    /// Runs out the board with the runs agreed so far once the run it window closed.
+    ///
+    /// Does nothing if no window is open or it is still open at `now`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::NoCardsLeft`] if the deck runs out of cards
+    /// - [`GameError::PlayerNotFound`] if a player cannot be found during the showdown
+    pub fn close_run_it_window(&mut self, now: u64) -> Result<(), TracedError<GameError>> {
+        match self.run_it_window_closes_at {
+            Some(closes_at) if now >= closes_at => {}
+            _ => return Ok(()),
+        }
+
+        self.run_it_window_closes_at = None;
+        self.conserving_chips(0, |table| table.run_out_board())
+            .map_err(|e| trace_err!(e, "Failed to run out the board after the run it window."))
+    }
+
+    /// The number of boards the players still in the hand agreed to run.
+    ///
+    /// Falls back to fewer runs if the deck cannot deal that many boards.
+    /// Double board bomb pots are only run once.
+    pub fn get_agreed_runs(&self) -> u8 {
+        let runs = self
+            .get_players_in_hand()
+            .map(|data| data.run_it_times.unwrap_or(1).clamp(1, MAX_RUNS))
+            .min()
+            .unwrap_or(1);
+        runs.min(self.get_max_runs())
+    }
+
+    /// The most boards the rest of the deck can be run on from the current stage.
+    ///
+    /// Stud hands and double board bomb pots are only run once.
+    fn get_max_runs(&self) -> u8 {
+        let cards_per_run = match self.deal_stage {
+            // Three burns plus the flop, turn and river
+            DealStage::Flop => 8,
+            DealStage::Turn => 4,
+            DealStage::River => 2,
+            _ => return 1,
+        };
+        if self.is_stud() || self.second_board.is_some() {
+            return 1;
+        }
+
+        let mut runs = MAX_RUNS;
+        while runs > 1 && cards_per_run * runs as usize > self.deck.len() {
+            runs -= 1;
+        }
+        runs
+    }
+
+    /// Whether every player still in the hand said how many times to run the board.
+    fn has_everyone_answered_run_it(&self) -> bool {
+        self.get_players_in_hand()
+            .all(|data| data.run_it_times.is_some())
+    }
+
+    fn get_players_in_hand(&self) -> impl Iterator<Item = &UserTableData> {
+        self.seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(principal) => self.user_table_data.get(principal),
+                _ => None,
+            })
+            .filter(|data| Self::is_in_hand(data))
+    }
+
+    fn is_in_hand(data: &UserTableData) -> bool {
+        !matches!(
+            data.player_action,
+            PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+        )
+    }
+
+    /// Deals the rest of the board `runs` times from the remaining deck and
+    /// splits every pot evenly across the boards.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::NoCardsLeft`] if the deck runs out of cards
+    /// - [`GameError::PlayerNotFound`] if a player cannot be found during the showdown
+    pub fn run_it_multiple_times(&mut self, runs: u8) -> Result<(), TracedError<GameError>> {
+        let deal_stage = self.deal_stage;
+        let community_cards = self.community_cards.clone();
+
+        let mut boards = Vec::new();
+        for run in 1..=runs {
+            self.deal_stage = deal_stage;
+            self.community_cards = community_cards.clone();
+            while self.deal_stage != DealStage::Showdown {
+                self.deal_cards(true)
+                    .map_err(|e| trace_err!(e, "Error dealing cards when running it."))?;
+            }
+            let run_cards = self.community_cards[community_cards.len()..].to_vec();
+            self.set_run_provenance(&run_cards, run);
+            boards.push(self.community_cards.clone());
+        }
+
+        self.showdown_boards(boards)
+            .map_err(|e| trace_err!(e, "Failed to show down after running it."))
+    }
+
+    /// Records the run the `cards` were dealt in on their provenance.
+    fn set_run_provenance(&mut self, cards: &[Card], run: u8) {
+        let round_id = self.round_ticker;
+        for provenance in self.card_provenance.values_mut() {
+            if provenance.round_id == round_id && cards.contains(&provenance.card) {
+                provenance.dealt_in_run = Some(run);
+            }
+        }
+    }
+}
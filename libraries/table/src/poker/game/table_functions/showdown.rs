@@ -4,7 +4,10 @@ use errors::{game_error::GameError, trace_err, traced_error::TracedError};
 use user::user::WalletPrincipalId;
 
 use crate::poker::{
-    core::{Card, Hand, Rank, Rankable, CardIter},
+    core::{
+        Card, CardBitSet, CardIter, Hand, LowRank, LowRankable, Rank, ShortDeckRank,
+        ShortDeckRankable,
+    },
     game::{table_functions::table::Pot, types::{GameType, UserCards}},
 };
 
@@ -16,6 +19,7 @@ use super::{
 };
 
 type RankedHand = (WalletPrincipalId, Hand, Rank, Vec<Card>);
+type LogHands = Vec<(String, Vec<Card>, u64)>;
 
 impl Table {
     /// Compares the hands of the players to determine the winner
@@ -25,9 +29,49 @@ impl Table {
     /// - [`GameError::Other`] if the user table data cannot be retrieved
     /// - [`GameError::PlayerNotFound`] if retrieving a player fails
     pub fn showdown(&mut self) -> Result<(), TracedError<GameError>> {
-        let ranked_hands = self
-            .get_ranked_hands()
-            .map_err(|e| trace_err!(e, "Failed to get ranked hands in showdown."))?;
+        let mut boards = vec![self.community_cards.clone()];
+        // Double board bomb pots are played for on both boards
+        if let Some(second_board) = self.second_board.clone() {
+            boards.push(second_board);
+        }
+        self.showdown_boards(boards)
+    }
+
+    /// Compares the hands of the players on every board to determine the winners.
+    ///
+    /// Each main and side pot is split evenly across the boards, with the odd
+    /// chips going to the first board. The first board is kept as the table's
+    /// community cards afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// - `boards`: The community cards of each run, at least one
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    /// - [`GameError::PlayerNotFound`] if retrieving a player fails
+    pub fn showdown_boards(
+        &mut self,
+        boards: Vec<Vec<Card>>,
+    ) -> Result<(), TracedError<GameError>> {
+        self.conserving_chips(0, |table| table.handle_showdown_boards(boards))
+    }
+
+    /// Shows down every board in [`Table::showdown_boards`].
+    fn handle_showdown_boards(
+        &mut self,
+        boards: Vec<Vec<Card>>,
+    ) -> Result<(), TracedError<GameError>> {
+        let mut runs = Vec::new();
+        for board in &boards {
+            self.community_cards = board.clone();
+            let ranked_hands = self
+                .get_ranked_hands()
+                .map_err(|e| trace_err!(e, "Failed to get ranked hands in showdown."))?;
+            let low_ranks = self.get_low_ranks(&ranked_hands);
+            runs.push((board.clone(), ranked_hands, low_ranks));
+        }
 
         let mut winners_total_amount: HashMap<WalletPrincipalId, u64> = HashMap::new();
         self.log_action(
@@ -37,6 +81,8 @@ impl Table {
             },
         );
 
+        // Odd chips are handed out from the button of the hand being paid
+        let button = self.dealer_position;
         self.rotate_dealer()
             .map_err(|e| trace_err!(e, "Failed to rotate dealer in showdown."))?;
 
@@ -48,16 +94,8 @@ impl Table {
 
         self.confirm_side_pots();
 
-        // Distribute the side pots
-        for pot in self.side_pots.clone().iter_mut() {
-            let inner_ranked_hands = ranked_hands.clone();
-            let inner_ranked_hands = inner_ranked_hands
-                .iter()
-                .filter(|(user_principal, _, _, _)| pot.user_principals.contains(user_principal))
-                .collect::<Vec<_>>();
-
-            let mut individual_shares: HashMap<WalletPrincipalId, u64> = HashMap::new();
-
+        let mut side_pots = self.side_pots.clone();
+        for pot in side_pots.iter_mut() {
             if let Some(enable_rake) = self.config.enable_rake {
                 if enable_rake {
                     if let CurrencyType::Real(currency) = &self.config.currency_type {
@@ -80,57 +118,8 @@ impl Table {
                     }
                 }
             }
-
-            let inner_rank_hands_clone = inner_ranked_hands.clone();
-
-            if let Some((_, _, first_rank, _)) = inner_ranked_hands.first() {
-                let tied_users: Vec<WalletPrincipalId> = inner_ranked_hands
-                    .iter()
-                    .filter(|(_, _, rank, _)| rank == first_rank)
-                    .map(|(user_principal, _, _, _)| *user_principal)
-                    .collect();
-
-                if !tied_users.is_empty() {
-                    let individual_share = pot.confirmed_pot / tied_users.len() as u64;
-
-                    for user in &tied_users {
-                        self.users
-                            .get_mut(user)
-                            .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?
-                            .deposit(individual_share);
-                        *winners_total_amount.entry(*user).or_insert(0) += individual_share;
-                        individual_shares.insert(*user, individual_share);
-                    }
-                }
-            }
-
-            let mut log_hands = Vec::new();
-            for (user_principal, _, _, cards) in inner_rank_hands_clone.iter() {
-                let user = self
-                    .users
-                    .get(user_principal)
-                    .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
-                let amount_won = *individual_shares.get(user_principal).unwrap_or(&0);
-                log_hands.push((user.user_name.clone(), cards.clone(), amount_won));
-            }
-
-            if self.all_players_folded() {
-                // If all players except one folded remove all cards from log hands.
-                // This is for ensuring correct mucking of cards in the frontend.
-                log_hands = log_hands
-                    .iter()
-                    .map(|(user_name, _, amount_won)| (user_name.clone(), vec![], *amount_won))
-                    .collect();
-            }
-
-            self.log_action(
-                None,
-                ActionType::PlayersHandsRankedSidePot { hands: log_hands },
-            );
         }
 
-        let mut individual_shares: HashMap<WalletPrincipalId, u64> = HashMap::new();
-
         if let Some(enable_rake) = self.config.enable_rake {
             if enable_rake {
                 if let CurrencyType::Real(currency) = &self.config.currency_type {
@@ -154,74 +143,111 @@ impl Table {
             }
         }
 
-        // Distribute the main pot
-        if let Some((_, _, first_rank, _)) = ranked_hands.first() {
-            let tied_users: Vec<WalletPrincipalId> = ranked_hands
-                .iter()
-                .filter(|(_, _, rank, _)| rank == first_rank)
-                .map(|(user_principal, _, _, _)| *user_principal)
-                .collect();
+        let number_of_runs = runs.len() as u64;
+        let mut winners: Vec<WalletPrincipalId> = Vec::new();
+        for (run, (board, ranked_hands, low_ranks)) in runs.iter().enumerate() {
+            self.community_cards = board.clone();
+            if number_of_runs > 1 {
+                self.log_action(
+                    None,
+                    ActionType::BoardRun {
+                        run: run as u8 + 1,
+                        community_cards: board.clone(),
+                    },
+                );
+            }
 
-            if !tied_users.is_empty() {
-                let mut winners = Vec::new();
-                let individual_share = self.pot.0 / tied_users.len() as u64;
-
-                for user in &tied_users {
-                    let user = {
-                        let user = self.users.get_mut(user).ok_or_else(|| {
-                            trace_err!(TracedError::new(GameError::PlayerNotFound))
-                        })?;
-                        user.deposit(individual_share);
-                        user.clone()
-                    };
+            // Distribute the side pots
+            for pot in side_pots.iter() {
+                let inner_ranked_hands = ranked_hands
+                    .iter()
+                    .filter(|(user_principal, _, _, _)| {
+                        pot.user_principals.contains(user_principal)
+                    })
+                    .collect::<Vec<_>>();
+
+                let amount = Self::get_run_share(pot.confirmed_pot, run as u64, number_of_runs);
+                let (high_shares, low_shares) =
+                    self.split_pot(amount, &inner_ranked_hands, low_ranks, button);
+                self.award_shares(&high_shares, &mut winners_total_amount)
+                    .map_err(|e| trace_err!(e, "Failed to award side pot high shares."))?;
+                self.award_shares(&low_shares, &mut winners_total_amount)
+                    .map_err(|e| trace_err!(e, "Failed to award side pot low shares."))?;
+
+                let (log_hands, low_log_hands) = self
+                    .get_log_hands(&inner_ranked_hands, &high_shares, &low_shares, low_ranks)
+                    .map_err(|e| trace_err!(e, "Failed to build side pot log hands."))?;
+
+                self.log_action(
+                    None,
+                    ActionType::PlayersHandsRankedSidePot {
+                        hands: log_hands,
+                        low_hands: low_log_hands,
+                    },
+                );
+            }
 
-                    *winners_total_amount.entry(user.principal_id).or_insert(0) += individual_share;
-                    individual_shares.insert(user.principal_id, individual_share);
+            // Distribute the main pot
+            let main_pot_hands = ranked_hands.iter().collect::<Vec<_>>();
+            let amount = Self::get_run_share(self.pot.0, run as u64, number_of_runs);
+            let (high_shares, low_shares) =
+                self.split_pot(amount, &main_pot_hands, low_ranks, button);
+            self.award_shares(&high_shares, &mut winners_total_amount)
+                .map_err(|e| trace_err!(e, "Failed to award main pot high shares."))?;
+            self.award_shares(&low_shares, &mut winners_total_amount)
+                .map_err(|e| trace_err!(e, "Failed to award main pot low shares."))?;
+
+            let (log_hands, low_log_hands) = self
+                .get_log_hands(&main_pot_hands, &high_shares, &low_shares, low_ranks)
+                .map_err(|e| trace_err!(e, "Failed to build main pot log hands."))?;
+
+            if low_log_hands.is_some() || high_shares.is_empty() {
+                self.log_action(
+                    None,
+                    ActionType::PlayersHandsRankedMainPot {
+                        hands: log_hands,
+                        low_hands: low_log_hands,
+                    },
+                );
+            }
 
-                    winners.push(user.clone());
+            if !high_shares.is_empty() {
+                // Winners are listed in rank order, high hands first and then any low-only
+                // winners, followed by the new winners of any later run.
+                for (user_principal, _, _, _) in main_pot_hands.iter() {
+                    if high_shares.contains_key(user_principal) && !winners.contains(user_principal)
+                    {
+                        winners.push(*user_principal);
+                    }
+                }
+                for (user_principal, _, _, _) in main_pot_hands.iter() {
+                    if low_shares.contains_key(user_principal) && !winners.contains(user_principal)
+                    {
+                        winners.push(*user_principal);
+                    }
                 }
-                // Handle ties according to your game's rules
-                self.winners = Some(winners.clone());
-                self.set_sorted_users(winners_total_amount)
-                    .map_err(|e| trace_err!(e, "Failed to set sorted users."))?;
-                self.pot = Pot(0);
-                self.side_pots.clear();
-
-                // 🔓 REVEAL DECK: Game has ended, reveal the full deck for transparency
-                self.reveal_deck_for_current_round();
-
-                #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
-                self.start_next_turn_timer(self.config.auto_start_timer.into());
-                return Ok(());
             }
         }
 
-        let mut log_hands = Vec::new();
-        for (user_principal, _, _, cards) in ranked_hands.iter() {
-            let user = self
-                .users
-                .get(user_principal)
-                .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
-            let amount_won = *individual_shares.get(user_principal).unwrap_or(&0);
-            log_hands.push((user.user_name.clone(), cards.clone(), amount_won));
-        }
-
-        if self.all_players_folded() {
-            // If all players except one folded remove all cards from log hands.
-            // This is for ensuring correct mucking of cards in the frontend.
-            log_hands = log_hands
+        if !winners.is_empty() {
+            let winners = winners
                 .iter()
-                .map(|(user_name, _, amount_won)| (user_name.clone(), vec![], *amount_won))
-                .collect();
+                .map(|user_principal| {
+                    self.users
+                        .get(user_principal)
+                        .cloned()
+                        .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            self.winners = Some(winners);
         }
 
-        self.log_action(
-            None,
-            ActionType::PlayersHandsRankedMainPot { hands: log_hands },
-        );
-
+        if let Some(board) = boards.into_iter().next() {
+            self.community_cards = board;
+        }
         self.set_sorted_users(winners_total_amount)
             .map_err(|e| trace_err!(e, "Failed to set sorted users."))?;
+        self.set_showdown_shown_cards();
         self.pot = Pot(0);
         self.side_pots.clear();
 
@@ -233,6 +259,203 @@ impl Table {
         Ok(())
     }
 
+    /// The part of a pot that is played for on one run of the board.
+    /// Odd chips that cannot be split evenly go to the first run.
+    fn get_run_share(amount: u64, run: u64, number_of_runs: u64) -> u64 {
+        let share = amount / number_of_runs;
+        if run == 0 {
+            share + amount % number_of_runs
+        } else {
+            share
+        }
+    }
+
+    /// Whether the table's game type splits each pot between a high and a low hand
+    pub fn is_hi_lo(&self) -> bool {
+        matches!(
+            self.config.game_type,
+            GameType::PotLimitOmahaHiLo4(_) | GameType::PotLimitOmahaHiLo5(_)
+        )
+    }
+
+    /// Splits a pot between the best high hand(s) and, in hi-lo games, the
+    /// best qualifying low hand(s).
+    ///
+    /// If nobody qualifies for the low the high hand scoops the whole pot.
+    /// The odd chip of an uneven high/low split goes to the high half, and the
+    /// odd chips of a half split between tied hands go to the first of those
+    /// winners left of the button.
+    ///
+    /// # Parameters
+    ///
+    /// - `amount`: The amount in the pot
+    /// - `contenders`: The ranked hands eligible for this pot, best first
+    /// - `low_ranks`: The qualifying low of each player that has one
+    /// - `button`: The seat of the button for this hand
+    ///
+    /// # Returns
+    ///
+    /// The (high, low) share of each winning player
+    fn split_pot(
+        &self,
+        amount: u64,
+        contenders: &[&RankedHand],
+        low_ranks: &HashMap<WalletPrincipalId, LowRank>,
+        button: usize,
+    ) -> (
+        HashMap<WalletPrincipalId, u64>,
+        HashMap<WalletPrincipalId, u64>,
+    ) {
+        let mut high_shares = HashMap::new();
+        let mut low_shares = HashMap::new();
+
+        let best_low = contenders
+            .iter()
+            .filter_map(|(user_principal, _, _, _)| low_ranks.get(user_principal))
+            .max()
+            .copied();
+
+        let (high_amount, low_amount) = match best_low {
+            Some(_) => (amount - amount / 2, amount / 2),
+            None => (amount, 0),
+        };
+
+        if let Some((_, _, first_rank, _)) = contenders.first() {
+            let tied_users: Vec<WalletPrincipalId> = contenders
+                .iter()
+                .filter(|(_, _, rank, _)| rank == first_rank)
+                .map(|(user_principal, _, _, _)| *user_principal)
+                .collect();
+
+            self.split_between(high_amount, &tied_users, button, &mut high_shares);
+        }
+
+        if let Some(best_low) = best_low {
+            let tied_users: Vec<WalletPrincipalId> = contenders
+                .iter()
+                .filter(|(user_principal, _, _, _)| {
+                    low_ranks.get(user_principal) == Some(&best_low)
+                })
+                .map(|(user_principal, _, _, _)| *user_principal)
+                .collect();
+
+            self.split_between(low_amount, &tied_users, button, &mut low_shares);
+        }
+
+        (high_shares, low_shares)
+    }
+
+    /// Splits `amount` evenly between the tied winners. The chips that cannot
+    /// be split evenly are credited to the first winner left of the button.
+    ///
+    /// # Parameters
+    ///
+    /// - `amount`: The amount to split
+    /// - `tied_users`: The winners sharing the amount
+    /// - `button`: The seat of the button for this hand
+    /// - `shares`: The shares to add each winner's part to
+    fn split_between(
+        &self,
+        amount: u64,
+        tied_users: &[WalletPrincipalId],
+        button: usize,
+        shares: &mut HashMap<WalletPrincipalId, u64>,
+    ) {
+        if tied_users.is_empty() {
+            return;
+        }
+
+        let individual_share = amount / tied_users.len() as u64;
+        for user in tied_users {
+            shares.insert(*user, individual_share);
+        }
+
+        let odd_chips = amount % tied_users.len() as u64;
+        if odd_chips > 0 {
+            let first_left_of_button = tied_users.iter().min_by_key(|user_principal| {
+                self.seats
+                    .iter()
+                    .position(
+                        |seat| matches!(seat, SeatStatus::Occupied(p) if p == *user_principal),
+                    )
+                    .map_or(usize::MAX, |seat| {
+                        (seat + self.seats.len() - button - 1) % self.seats.len()
+                    })
+            });
+            if let Some(user) = first_left_of_button {
+                *shares.entry(*user).or_insert(0) += odd_chips;
+            }
+        }
+    }
+
+    /// Deposits each share into the winner's balance
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if a winner is not at the table
+    fn award_shares(
+        &mut self,
+        shares: &HashMap<WalletPrincipalId, u64>,
+        winners_total_amount: &mut HashMap<WalletPrincipalId, u64>,
+    ) -> Result<(), TracedError<GameError>> {
+        for (user_principal, share) in shares {
+            self.users
+                .get_mut(user_principal)
+                .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?
+                .deposit(*share);
+            *winners_total_amount.entry(*user_principal).or_insert(0) += share;
+        }
+        Ok(())
+    }
+
+    /// Builds the hands shown in the pot's action log. The low half is only
+    /// returned for hi-lo games.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if a player is not at the table
+    fn get_log_hands(
+        &mut self,
+        contenders: &[&RankedHand],
+        high_shares: &HashMap<WalletPrincipalId, u64>,
+        low_shares: &HashMap<WalletPrincipalId, u64>,
+        low_ranks: &HashMap<WalletPrincipalId, LowRank>,
+    ) -> Result<(LogHands, Option<LogHands>), TracedError<GameError>> {
+        let all_players_folded = self.all_players_folded();
+        let mut log_hands = Vec::new();
+        let mut low_log_hands = Vec::new();
+
+        for (user_principal, _, _, cards) in contenders.iter() {
+            let user = self
+                .users
+                .get(user_principal)
+                .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
+            // If all players except one folded remove all cards from log hands.
+            // This is for ensuring correct mucking of cards in the frontend.
+            let cards = if all_players_folded {
+                vec![]
+            } else {
+                cards.clone()
+            };
+            let amount_won = *high_shares.get(user_principal).unwrap_or(&0);
+            log_hands.push((user.user_name.clone(), cards.clone(), amount_won));
+
+            if let Some(low_rank) = low_ranks.get(user_principal) {
+                let amount_won = *low_shares.get(user_principal).unwrap_or(&0);
+                low_log_hands.push((*low_rank, (user.user_name.clone(), cards, amount_won)));
+            }
+        }
+
+        if self.is_hi_lo() {
+            // Best low first, the same way the high hands are ordered.
+            low_log_hands.sort_by_key(|(low_rank, _)| std::cmp::Reverse(*low_rank));
+            let low_log_hands = low_log_hands.into_iter().map(|(_, hand)| hand).collect();
+            Ok((log_hands, Some(low_log_hands)))
+        } else {
+            Ok((log_hands, None))
+        }
+    }
+
     /// Checks if the side pots have been confirmed and if not, confirms them
     fn confirm_side_pots(&mut self) {
         for pot in self.side_pots.iter_mut() {
@@ -281,8 +504,12 @@ impl Table {
         let mut ranked_hands: Vec<RankedHand> = Vec::new();
         let is_omaha = matches!(
             self.config.game_type,
-            GameType::PotLimitOmaha4(_) | GameType::PotLimitOmaha5(_)
+            GameType::PotLimitOmaha4(_)
+                | GameType::PotLimitOmaha5(_)
+                | GameType::PotLimitOmahaHiLo4(_)
+                | GameType::PotLimitOmahaHiLo5(_)
         );
+        let is_short_deck = matches!(self.config.game_type, GameType::ShortDeck(_));
 
         for user_principal in self.seats.iter() {
             if let SeatStatus::Occupied(user_principal) = user_principal {
@@ -329,7 +556,11 @@ impl Table {
                     let mut all_cards = hole_cards.clone();
                     all_cards.extend(self.community_cards.clone());
                     let hand = Hand::new_with_cards(all_cards.clone());
-                    let rank = hand.rank();
+                    let rank = if is_short_deck {
+                        hand.short_deck_rank()
+                    } else {
+                        hand.iter().copied().collect::<CardBitSet>().rank()
+                    };
                     (hand, rank, all_cards)
                 };
 
@@ -338,10 +569,67 @@ impl Table {
         }
 
         // Sort ranked hands by their rank
-        ranked_hands.sort_by(|a, b| b.2.cmp(&a.2)); // Descending order
+        if is_short_deck {
+            // Flush beats full house with a short deck
+            ranked_hands.sort_by_key(|hand| std::cmp::Reverse(ShortDeckRank(hand.2)));
+        } else {
+            ranked_hands.sort_by(|a, b| b.2.cmp(&a.2)); // Descending order
+        }
         Ok(ranked_hands)
     }
 
+    /// Gets the best qualifying low of every ranked player in hi-lo games.
+    /// Players without an eight-or-better low are left out, and the map is
+    /// empty for every other game type.
+    ///
+    /// # Parameters
+    ///
+    /// - `ranked_hands`: The ranked hands of the players still in the hand
+    fn get_low_ranks(&self, ranked_hands: &[RankedHand]) -> HashMap<WalletPrincipalId, LowRank> {
+        let mut low_ranks = HashMap::new();
+        if !self.is_hi_lo() || self.community_cards.len() < 3 {
+            return low_ranks;
+        }
+
+        for (user_principal, _, _, _) in ranked_hands {
+            let Some(user_table_data) = self.user_table_data.get(user_principal) else {
+                continue;
+            };
+            if let Some(low_rank) =
+                Self::get_best_omaha_low(&user_table_data.cards, &self.community_cards)
+            {
+                low_ranks.insert(*user_principal, low_rank);
+            }
+        }
+        low_ranks
+    }
+
+    /// Finds the best eight-or-better low for Omaha (exactly 2 hole + exactly 3 community)
+    ///
+    /// # Parameters
+    ///
+    /// - `hole_cards`: The player's hole cards (4 or 5 cards)
+    /// - `community_cards`: The 5 community cards
+    ///
+    /// # Returns
+    ///
+    /// The best low, or `None` if the player has no qualifying low
+    fn get_best_omaha_low(hole_cards: &[Card], community_cards: &[Card]) -> Option<LowRank> {
+        let mut best_low = None;
+        for hole_combo in CardIter::new(hole_cards, 2) {
+            for community_combo in CardIter::new(community_cards, 3) {
+                let mut five_card_hand: Vec<Card> = hole_combo.clone();
+                five_card_hand.extend(community_combo);
+
+                let low_rank = five_card_hand.low_rank();
+                if low_rank > best_low {
+                    best_low = low_rank;
+                }
+            }
+        }
+        best_low
+    }
+
     /// Finds the best 5-card hand for Omaha (exactly 2 hole + exactly 3 community)
     ///
     /// # Parameters
@@ -368,30 +656,38 @@ impl Table {
             ))));
         }
 
-        let mut best_rank = Rank::HighCard(0);
-        let mut best_hand = Hand::default();
-        let mut _best_cards = Vec::new();
+        // Every 2 hole card and 3 community card combination, with their bit sets
+        let hole_combos: Vec<(Vec<Card>, CardBitSet)> = CardIter::new(hole_cards, 2)
+            .map(|combo| {
+                let card_bit_set = combo.iter().copied().collect();
+                (combo, card_bit_set)
+            })
+            .collect();
+        let community_combos: Vec<(Vec<Card>, CardBitSet)> = CardIter::new(community_cards, 3)
+            .map(|combo| {
+                let card_bit_set = combo.iter().copied().collect();
+                (combo, card_bit_set)
+            })
+            .collect();
 
-        // Generate all combinations of 2 hole cards
-        for hole_combo in CardIter::new(hole_cards, 2) {
-            // Generate all combinations of 3 community cards
-            for community_combo in CardIter::new(community_cards, 3) {
-                // Combine to make a 5-card hand
-                let mut five_card_hand: Vec<Card> = hole_combo.clone();
-                five_card_hand.extend(community_combo.clone());
-                
-                let hand = Hand::new_with_cards(five_card_hand.clone());
-                let rank = hand.rank_five(); // Use rank_five for exactly 5 cards (faster)
+        let mut best_rank = Rank::HighCard(0);
+        let mut best_combo = (0, 0);
+        for (hole_index, (_, hole_bit_set)) in hole_combos.iter().enumerate() {
+            for (community_index, (_, community_bit_set)) in community_combos.iter().enumerate() {
+                let rank = (*hole_bit_set | *community_bit_set).rank();
 
                 // Keep track of the best hand
                 if rank > best_rank {
                     best_rank = rank;
-                    best_hand = hand;
-                    _best_cards = five_card_hand;
+                    best_combo = (hole_index, community_index);
                 }
             }
         }
 
+        let mut five_card_hand = hole_combos[best_combo.0].0.clone();
+        five_card_hand.extend(community_combos[best_combo.1].0.clone());
+        let best_hand = Hand::new_with_cards(five_card_hand);
+
         // Create all_cards for logging (all hole + all community)
         let mut all_cards = hole_cards.to_vec();
         all_cards.extend(community_cards.to_vec());
@@ -0,0 +1,159 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+pub use fairness::{
+    shuffle::{combine_shuffle_seeds, shuffle_commitment},
+    types::PlayerSeed,
+};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::{table::Table, types::SeatStatus};
+
+/// The longest seed a player can reveal, in bytes.
+pub const MAX_SHUFFLE_SEED_LEN: usize = 64;
+
+/// The canister's randomness for the next shuffle, drawn a hand ahead.
+///
+/// Only the commitment is published until the deck is revealed. Seeds can only be
+/// revealed once it is, so the randomness is fixed before anyone's seed is known.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct CommittedShuffle {
+    pub random_bytes: Vec<u8>,
+    pub time_seed: u64,
+    /// The hash published for the random bytes and time seed.
+    pub commitment: Vec<u8>,
+}
+
+impl CommittedShuffle {
+    pub fn new(random_bytes: Vec<u8>, time_seed: u64) -> Self {
+        CommittedShuffle {
+            commitment: shuffle_commitment(&random_bytes, time_seed),
+            random_bytes,
+            time_seed,
+        }
+    }
+}
+
+impl Table {
+    /// Whether players add their own seeds to the shuffle.
+    pub fn is_commit_reveal_shuffle(&self) -> bool {
+        self.config.commit_reveal_shuffle.unwrap_or(false)
+    }
+
+    /// Commits the user to a seed for the next shuffle.
+    ///
+    /// A new commitment replaces the previous one, which has to be revealed again.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `commitment` - The SHA-256 hash of the user's seed
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table does not use commit-reveal shuffling
+    ///   or the commitment is not a SHA-256 hash
+    /// - [`GameError::PlayerNotFound`] if the user is not seated at the table
+    pub fn commit_shuffle_seed(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        commitment: Vec<u8>,
+    ) -> Result<(), TracedError<GameError>> {
+        if !self.is_commit_reveal_shuffle() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Commit-reveal shuffling is not enabled".to_string(),
+            })));
+        }
+        if self.get_seat_index(user_principal).is_none() {
+            return Err(trace_err!(TracedError::new(GameError::PlayerNotFound)));
+        }
+        if commitment.len() != 32 {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "The commitment must be the SHA-256 hash of the seed".to_string(),
+            })));
+        }
+
+        self.player_seeds.insert(
+            user_principal,
+            PlayerSeed {
+                user_principal,
+                commitment,
+                seed: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Reveals the seed the user committed to.
+    ///
+    /// Seeds are only taken once the canister committed to the randomness of the
+    /// next shuffle, so it cannot draw its randomness to suit them.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `seed` - The seed whose hash the user committed to
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the next shuffle is not committed yet, the
+    ///   user has no commitment, the seed is empty or too long, or it does not match
+    ///   the commitment
+    pub fn reveal_shuffle_seed(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        seed: Vec<u8>,
+    ) -> Result<(), TracedError<GameError>> {
+        if self.committed_shuffle.is_none() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "The next shuffle is not committed yet".to_string(),
+            })));
+        }
+        let player_seed = self.player_seeds.get_mut(&user_principal).ok_or_else(|| {
+            trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "No seed commitment to reveal".to_string(),
+            }))
+        })?;
+        if seed.is_empty() || seed.len() > MAX_SHUFFLE_SEED_LEN {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: format!("The seed must be 1 to {} bytes", MAX_SHUFFLE_SEED_LEN),
+            })));
+        }
+
+        let revealed = PlayerSeed {
+            seed: Some(seed),
+            ..player_seed.clone()
+        };
+        if !revealed.matches_commitment() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "The seed does not match the commitment".to_string(),
+            })));
+        }
+        *player_seed = revealed;
+        Ok(())
+    }
+
+    /// The commitments of the seated players for the next shuffle, in seat order.
+    pub fn get_shuffle_player_seeds(&self) -> Vec<PlayerSeed> {
+        self.seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(user_principal) => self.player_seeds.get(user_principal),
+                _ => None,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Clears the commitments once the deck they went into is dealt.
+    pub fn clear_shuffle_seeds(&mut self) {
+        self.player_seeds.clear();
+    }
+
+    /// The commitment to the canister's randomness for the next shuffle, if it was drawn.
+    pub fn get_shuffle_commitment(&self) -> Option<Vec<u8>> {
+        self.committed_shuffle
+            .as_ref()
+            .map(|committed_shuffle| committed_shuffle.commitment.clone())
+    }
+}
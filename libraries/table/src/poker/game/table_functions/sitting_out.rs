@@ -24,6 +24,9 @@ impl Table {
         self.users
             .get(&user_principal)
             .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data in user sitting out."))?
+            .is_waiting_for_big_blind = None;
         if self.is_game_ongoing() {
             if self.is_players_turn(user_principal) {
                 self.user_fold(user_principal, is_inactive_user)
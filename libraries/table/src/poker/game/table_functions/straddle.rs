@@ -0,0 +1,185 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::types::GameType;
+
+use super::{
+    table::{Table, TableType},
+    types::{BetType, PlayerAction, SeatStatus},
+};
+
+/// Who can post a straddle before the cards are dealt.
+///
+/// The straddle is twice the big blind, counts as the new big blind for the
+/// minimum raise and gives the straddler the last option before the flop.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub enum StraddleType {
+    /// The player to the left of the big blind.
+    UnderTheGun,
+    /// The player on the button.
+    Button,
+    /// Any player but the blinds. The one closest to the button straddles
+    /// when more than one wants to.
+    Mississippi,
+}
+
+impl Table {
+    /// Sets whether the user posts a straddle when they are in a position to.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `wants_to_straddle` - Whether the user wants to straddle
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table does not allow straddles
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn set_user_straddle(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        wants_to_straddle: bool,
+    ) -> Result<(), TracedError<GameError>> {
+        if wants_to_straddle && !self.is_straddle_allowed() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Straddles are not allowed at this table".to_string(),
+            })));
+        }
+
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to set straddle."))?
+            .wants_to_straddle = Some(wants_to_straddle);
+        Ok(())
+    }
+
+    /// Whether straddles can be posted at this table.
+    ///
+    /// Straddles are only allowed on no limit and pot limit cash tables with a straddle type.
+    pub fn is_straddle_allowed(&self) -> bool {
+        self.config.straddle_type.is_some()
+            && matches!(self.config.table_type, None | Some(TableType::Cash))
+            && !matches!(
+                self.config.game_type,
+                GameType::FixedLimit(_, _)
+                    | GameType::SpreadLimit(_, _)
+                    | GameType::SevenCardStud(_, _)
+            )
+    }
+
+    /// The amount of a straddle, twice the big blind.
+    pub fn get_straddle_amount(&self) -> u64 {
+        self.big_blind.0.saturating_mul(2)
+    }
+
+    /// The player who straddles this hand, if anyone does.
+    ///
+    /// Needs at least three players dealt in, so the straddler is never one of the blinds.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if the big blind is not seated
+    pub fn get_straddle_user_principal(
+        &self,
+    ) -> Result<Option<WalletPrincipalId>, TracedError<GameError>> {
+        let Some(straddle_type) = self.config.straddle_type else {
+            return Ok(None);
+        };
+        if !self.is_straddle_allowed() {
+            return Ok(None);
+        }
+
+        // The dealer position alone does not tell who it is when the button is dead
+        let big_blind_user_principal = self.big_blind_user_principal;
+        let big_blind_index = self
+            .seats
+            .iter()
+            .position(
+                |seat| matches!(seat, SeatStatus::Occupied(p) if *p == big_blind_user_principal),
+            )
+            .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
+
+        // The players from the one left of the big blind round to the button
+        let mut positions = Vec::new();
+        let mut index = big_blind_index;
+        for _ in 0..self.seats.len() {
+            index = (index + 1) % self.seats.len();
+            if let SeatStatus::Occupied(user_principal) = self.seats[index] {
+                if user_principal == big_blind_user_principal {
+                    break;
+                }
+                if self
+                    .user_table_data
+                    .get(&user_principal)
+                    .is_some_and(|data| {
+                        !matches!(
+                            data.player_action,
+                            PlayerAction::SittingOut | PlayerAction::Joining
+                        )
+                    })
+                {
+                    positions.push(user_principal);
+                }
+            }
+            if index == self.dealer_position {
+                break;
+            }
+        }
+        // The small blind and big blind are not part of them
+        if positions.is_empty() || self.get_playing_users()? < 3 {
+            return Ok(None);
+        }
+
+        let straddle_amount = self.get_straddle_amount();
+        let can_straddle = |user_principal: &WalletPrincipalId| {
+            self.user_table_data
+                .get(user_principal)
+                .is_some_and(|data| {
+                    data.wants_to_straddle == Some(true) && data.current_total_bet == 0
+                })
+                && self
+                    .users
+                    .get(user_principal)
+                    .is_some_and(|user| user.balance.0 > straddle_amount)
+        };
+
+        let straddler = match straddle_type {
+            StraddleType::UnderTheGun => positions.first().filter(|uid| can_straddle(uid)),
+            StraddleType::Button => positions
+                .last()
+                .filter(|uid| self.get_player_at_seat(self.dealer_position).ok() == Some(**uid))
+                .filter(|uid| can_straddle(uid)),
+            StraddleType::Mississippi => positions.iter().rev().find(|uid| can_straddle(uid)),
+        };
+        Ok(straddler.copied())
+    }
+
+    /// Posts the straddle for this hand if anyone straddles.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if the big blind is not seated
+    /// - [`GameError::InsufficientFunds`] if the straddler cannot afford the straddle
+    pub(crate) fn post_straddle(&mut self) -> Result<(), TracedError<GameError>> {
+        let Some(straddle_user_principal) = self
+            .get_straddle_user_principal()
+            .map_err(|e| trace_err!(e, "Failed to get straddler."))?
+        else {
+            return Ok(());
+        };
+
+        self.bet(straddle_user_principal, BetType::Straddle)
+            .map_err(|e| trace_err!(e, "Failed to post straddle."))?;
+        self.straddle_user_principal = Some(straddle_user_principal);
+        Ok(())
+    }
+
+    /// The player who acts last before the flop when everyone calls.
+    ///
+    /// This is the straddler if there is one and the big blind otherwise.
+    pub fn get_pre_flop_option_user_principal(&self) -> WalletPrincipalId {
+        self.straddle_user_principal
+            .unwrap_or(self.big_blind_user_principal)
+    }
+}
@@ -0,0 +1,142 @@
+use candid::Principal;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, CardBitSet, Rank, Suit},
+    game::types::GameType,
+};
+
+use super::{
+    table::Table,
+    types::{BetType, PlayerAction, SeatStatus},
+};
+
+/// Suit order used to break bring-in ties: clubs are the lowest, spades the highest.
+fn bring_in_suit_rank(suit: Suit) -> u8 {
+    match suit {
+        Suit::Club => 0,
+        Suit::Diamond => 1,
+        Suit::Heart => 2,
+        Suit::Spade => 3,
+    }
+}
+
+impl Table {
+    /// Is the table playing a stud game, where each player gets their own
+    /// up and down cards instead of sharing community cards?
+    pub fn is_stud(&self) -> bool {
+        matches!(self.config.game_type, GameType::SevenCardStud(_, _))
+    }
+
+    /// Seats of the players that are still in the hand, starting left of the dealer.
+    fn get_stud_seats_in_hand(&self) -> Vec<(usize, WalletPrincipalId)> {
+        (1..=self.seats.len())
+            .map(|offset| (self.dealer_position + offset) % self.seats.len())
+            .filter_map(|seat| match self.seats[seat] {
+                SeatStatus::Occupied(principal) => Some((seat, principal)),
+                _ => None,
+            })
+            .filter(|(_, principal)| {
+                self.user_table_data
+                    .get(principal)
+                    .map(|data| {
+                        !matches!(
+                            data.player_action,
+                            PlayerAction::Folded
+                                | PlayerAction::SittingOut
+                                | PlayerAction::Joining
+                                | PlayerAction::AllIn
+                        )
+                    })
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Makes the player with the lowest door card (the first up card) post the bring-in.
+    ///
+    /// Ties on value are broken by suit, clubs being the lowest. The bring-in uses the
+    /// small blind amount and the action moves to the player on their left.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if no player has a door card
+    /// - [`GameError::InsufficientFunds`] if the player cannot pay the bring-in
+    pub fn post_bring_in(&mut self) -> Result<(), TracedError<GameError>> {
+        let bring_in = self
+            .get_stud_seats_in_hand()
+            .into_iter()
+            .filter_map(|(seat, principal)| {
+                let door_card = *self
+                    .user_table_data
+                    .get(&principal)?
+                    .visible_cards()
+                    .first()?;
+                Some((seat, principal, door_card))
+            })
+            .min_by_key(|(_, _, card): &(usize, WalletPrincipalId, Card)| {
+                (card.value, bring_in_suit_rank(card.suit))
+            })
+            .ok_or_else(|| {
+                trace_err!(TracedError::new(GameError::Other(
+                    "No door card to determine the bring-in".to_string(),
+                )))
+            })?;
+
+        // The bring-in is not a blind, so nobody gets the option to raise their own bet.
+        self.small_blind_user_principal = bring_in.1;
+        self.big_blind_user_principal = WalletPrincipalId(Principal::anonymous());
+
+        self.current_player_index = bring_in.0;
+        self.bet(bring_in.1, BetType::SmallBlind)
+            .map_err(|e| trace_err!(e, "Failed to post the bring-in."))?;
+        Ok(())
+    }
+
+    /// Sets the current player to the one showing the best hand with their up cards.
+    ///
+    /// Ties go to the player closest to the left of the dealer.
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if the current player cannot be found
+    pub fn set_current_player_to_best_visible_hand(
+        &mut self,
+    ) -> Result<(), TracedError<GameError>> {
+        let mut best: Option<(usize, Rank)> = None;
+        for (seat, principal) in self.get_stud_seats_in_hand() {
+            let up_cards = match self.user_table_data.get(&principal) {
+                Some(data) => data.visible_cards(),
+                None => continue,
+            };
+            let rank = up_cards.into_iter().collect::<CardBitSet>().rank();
+            if best.is_none_or(|(_, best_rank)| rank > best_rank) {
+                best = Some((seat, rank));
+            }
+        }
+
+        if let Some((seat, _)) = best {
+            self.current_player_index = seat;
+            #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+            {
+                ic_cdk::println!("Clearing turn timer in set_current_player_to_best_visible_hand.");
+                self.clear_turn_timer();
+                let current_player = self
+                    .get_player_at_seat(self.current_player_index)
+                    .map_err(|e| trace_err!(e, "Failed to get current player."))?;
+
+                self.set_last_timer_started_timestamp(ic_cdk::api::time());
+                self.start_turn_timer(current_player, self.config.timer_duration.into());
+                self.notifications
+                    .clear_notifications_older_than(ic_cdk::api::time() - 6e11 as u64); // 10 minutes
+
+                self.notifications.add_notification(
+                    current_player,
+                    crate::poker::game::table_functions::types::NotificationMessage::UserTurnStarted,
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -21,11 +21,18 @@ use crate::poker::game::types::{GameType, QueueItem, UserCards};
 
 use super::action_log::{ActionLog, ActionType};
 use super::ante::AnteType;
+use super::bomb_pot::BombPotConfig;
+use super::buy_in::{BuyInConfig, DepartedPlayer};
+use super::disconnect_protection::{DisconnectProtectionConfig, DisconnectProtectionUsage};
 use super::rake::Rake;
+use super::shuffle_seed::{CommittedShuffle, PlayerSeed};
 use super::side_pot::SidePot;
+use super::straddle::StraddleType;
+use super::time_bank::TimeBankConfig;
 use super::types::{
     BetType, CurrencyType, DealStage, Notifications, PlayerAction, SeatStatus, UserTableData,
 };
+use super::waitlist::WaitlistEntry;
 
 #[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
 pub struct TableConfig {
@@ -47,6 +54,13 @@ pub struct TableConfig {
     pub is_shared_rake: Option<(Principal, String)>,
     pub require_proof_of_humanity: Option<bool>,
     pub is_paused: Option<bool>,
+    pub straddle_type: Option<StraddleType>,
+    pub time_bank: Option<TimeBankConfig>,
+    pub bomb_pot: Option<BombPotConfig>,
+    pub disconnect_protection: Option<DisconnectProtectionConfig>,
+    pub buy_in: Option<BuyInConfig>,
+    pub commit_reveal_shuffle: Option<bool>,
+    pub keep_mucked_cards_private: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
@@ -98,7 +112,33 @@ pub struct Table {
     pub small_blind: SmallBlind,
     pub big_blind_user_principal: WalletPrincipalId,
     pub small_blind_user_principal: WalletPrincipalId,
+    /// The player who straddled this hand, if anyone did.
+    pub straddle_user_principal: Option<WalletPrincipalId>,
     pub dealer_position: usize,
+    /// The seats the small and big blind were due from in the last hand, even if they were dead.
+    /// Cash tables move the big blind forward from here so nobody can skip it.
+    pub blind_seats: Option<(usize, usize)>,
+    /// The board cards that would have come, once someone rabbit hunted after the hand.
+    pub rabbit_hunt_cards: Option<Vec<Card>>,
+    /// The ante everyone posted if this hand is a bomb pot.
+    pub bomb_pot_ante: Option<u64>,
+    /// The second board of a double board bomb pot.
+    pub second_board: Option<Vec<Card>>,
+    /// When the players still in the hand stop being able to agree on running it
+    /// more than once, while the table waits on them with everyone all in.
+    pub run_it_window_closes_at: Option<u64>,
+    /// The players waiting for a seat, in the order the seats are offered to them.
+    pub waitlist: Vec<WaitlistEntry>,
+    /// The disconnect protections each player used today, kept after they leave the table.
+    pub disconnect_protection_usage: HashMap<WalletPrincipalId, DisconnectProtectionUsage>,
+    /// The players who left with chips recently, who have to come back with at least that stack.
+    pub departed_players: HashMap<WalletPrincipalId, DepartedPlayer>,
+    /// The seed commitments players made for the next shuffle.
+    pub player_seeds: HashMap<WalletPrincipalId, PlayerSeed>,
+    /// The canister's randomness for the next shuffle on commit-reveal tables.
+    pub committed_shuffle: Option<CommittedShuffle>,
+    /// Whether the tournament starts each hand once every table finished the last one.
+    pub is_hand_for_hand: bool,
     pub current_player_index: usize,
     pub winners: Option<Vec<User>>,
     pub sorted_users: Option<Vec<UserCards>>,
@@ -148,6 +188,13 @@ impl Default for TableConfig {
             is_shared_rake: None,
             require_proof_of_humanity: None,
             is_paused: None,
+            straddle_type: None,
+            time_bank: None,
+            bomb_pot: None,
+            disconnect_protection: None,
+            buy_in: None,
+            commit_reveal_shuffle: None,
+            keep_mucked_cards_private: None,
         }
     }
 }
@@ -176,8 +223,52 @@ impl TableConfig {
             is_shared_rake: None,              // No shared rake
             require_proof_of_humanity: Some(false), // No proof of humanity required
             is_paused: Some(false),            // Not paused initially
+            straddle_type: None,               // No straddles in tournaments
+            time_bank: None,                   // No time bank
+            bomb_pot: None,                    // No bomb pots in tournaments
+            disconnect_protection: None,       // No disconnect protection
+            buy_in: None,                      // Everyone starts with the same stack
+            commit_reveal_shuffle: None,       // Dealt from the canister's randomness alone
+            keep_mucked_cards_private: None,   // The deck is revealed after every hand
         }
     }
+
+    /// A two-seat table for heads-up cash games or sit & gos.
+    pub fn default_heads_up(game_type: GameType, table_type: TableType) -> Self {
+        let is_cash = table_type == TableType::Cash;
+        Self {
+            name: "Heads-Up Table".to_string(),
+            game_type,
+            seats: 2,                               // One on one
+            timer_duration: 15,                     // 15 seconds per action
+            color: 0,                               // Default table color
+            card_color: 0,                          // Default card color
+            environment_color: 0,                   // Default environment
+            auto_start_timer: 1,                    // Auto-start timer enabled
+            max_inactive_turns: 3,                  // Maximum inactive turns before folding
+            currency_type: CurrencyType::Fake,      // Default to fake currency
+            enable_rake: Some(is_cash),             // Rake only applies to cash play
+            max_seated_out_turns: Some(3),          // Sit out for 3 hands maximum
+            is_private: Some(false),                // Not a private table
+            ante_type: Some(AnteType::None),        // No ante
+            table_type: Some(table_type),           // Cash or sit & go
+            is_shared_rake: None,                   // No shared rake
+            require_proof_of_humanity: Some(false), // No proof of humanity required
+            is_paused: Some(false),                 // Not paused initially
+            straddle_type: None,                    // Straddles are not allowed heads-up
+            time_bank: None,                        // No time bank
+            bomb_pot: None,                         // No bomb pots heads-up
+            disconnect_protection: None,            // No disconnect protection
+            buy_in: None,                           // No buy-in limits
+            commit_reveal_shuffle: None,            // Dealt from the canister's randomness alone
+            keep_mucked_cards_private: None,        // The deck is revealed after every hand
+        }
+    }
+
+    /// Is this a dedicated heads-up table?
+    pub fn is_heads_up(&self) -> bool {
+        self.seats == 2
+    }
 }
 
 impl Default for Table {
@@ -196,7 +287,19 @@ impl Default for Table {
             small_blind: SmallBlind(0),
             big_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
             small_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
+            straddle_user_principal: None,
             dealer_position: 0,
+            blind_seats: None,
+            rabbit_hunt_cards: None,
+            bomb_pot_ante: None,
+            second_board: None,
+            run_it_window_closes_at: None,
+            waitlist: Vec::new(),
+            disconnect_protection_usage: HashMap::new(),
+            departed_players: HashMap::new(),
+            player_seeds: HashMap::new(),
+            committed_shuffle: None,
+            is_hand_for_hand: false,
             current_player_index: 0,
             winners: None,
             sorted_users: None,
@@ -242,6 +345,13 @@ impl TableConfig {
         is_shared_rake: Option<(Principal, String)>,
         require_proof_of_humanity: Option<bool>,
         is_paused: Option<bool>,
+        straddle_type: Option<StraddleType>,
+        time_bank: Option<TimeBankConfig>,
+        bomb_pot: Option<BombPotConfig>,
+        disconnect_protection: Option<DisconnectProtectionConfig>,
+        buy_in: Option<BuyInConfig>,
+        commit_reveal_shuffle: Option<bool>,
+        keep_mucked_cards_private: Option<bool>,
     ) -> TableConfig {
         TableConfig {
             name,
@@ -262,13 +372,20 @@ impl TableConfig {
             is_shared_rake,
             require_proof_of_humanity,
             is_paused,
+            straddle_type,
+            time_bank,
+            bomb_pot,
+            disconnect_protection,
+            buy_in,
+            commit_reveal_shuffle,
+            keep_mucked_cards_private,
         }
     }
 }
 
 impl Table {
     pub fn new(id: TableId, config: TableConfig, bytes: Vec<u8>) -> Table {
-        let deck = FlatDeck::new(bytes);
+        let deck = config.game_type.new_deck(bytes);
 
         let (small_blind, big_blind) = match config.game_type {
             GameType::NoLimit(small_blind) => (small_blind, small_blind * 2),
@@ -277,6 +394,10 @@ impl Table {
             GameType::PotLimit(small) => (small, small * 2),
             GameType::PotLimitOmaha4(small) => (small, small * 2),
             GameType::PotLimitOmaha5(small) => (small, small * 2),
+            GameType::PotLimitOmahaHiLo4(small) => (small, small * 2),
+            GameType::PotLimitOmahaHiLo5(small) => (small, small * 2),
+            GameType::ShortDeck(small) => (small, small * 2),
+            GameType::SevenCardStud(small, _) => (small / 2, small),
         };
         let rake = if let CurrencyType::Real(currency) = &config.currency_type {
             Rake::new(SmallBlind(small_blind), &config.game_type, currency).ok()
@@ -300,7 +421,19 @@ impl Table {
             small_blind: SmallBlind(small_blind),
             big_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
             small_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
+            straddle_user_principal: None,
             dealer_position: 0,
+            blind_seats: None,
+            rabbit_hunt_cards: None,
+            bomb_pot_ante: None,
+            second_board: None,
+            run_it_window_closes_at: None,
+            waitlist: Vec::new(),
+            disconnect_protection_usage: HashMap::new(),
+            departed_players: HashMap::new(),
+            player_seeds: HashMap::new(),
+            committed_shuffle: None,
+            is_hand_for_hand: false,
             current_player_index: 1,
             winners: None,
             sorted_users: None,
@@ -462,6 +595,7 @@ impl Table {
             BetType::Raised(_) => amount,
             BetType::BigBlind => self.big_blind.0,
             BetType::SmallBlind => self.small_blind.0,
+            BetType::Straddle => self.get_straddle_amount(),
             BetType::Ante(amount) => amount,
             _ => 0,
         };
@@ -495,6 +629,7 @@ impl Table {
             let player_action = match bet_type {
                 BetType::BigBlind => PlayerAction::Raised(amount),
                 BetType::SmallBlind => PlayerAction::Raised(amount),
+                BetType::Straddle => PlayerAction::Raised(amount),
                 BetType::Raised(_) => {
                     if is_opening_bet {
                         PlayerAction::Bet(amount)
@@ -529,6 +664,7 @@ impl Table {
         // - For opening bets: increment = amount (since highest_bet = 0)
         // - For raises: increment = amount - old_highest_bet
         // - For blinds: increment = blind amount
+        // - For straddles: increment = straddle amount, as it is the new big blind
         // Note: last_raise is reset to 0 when a new betting street starts (flop/turn/river)
         let old_highest_bet = self.highest_bet;
         self.last_raise = match bet_type {
@@ -538,6 +674,7 @@ impl Table {
             },
             BetType::BigBlind => self.big_blind.0,
             BetType::SmallBlind => self.small_blind.0,
+            BetType::Straddle => amount,
             _ => 0,
         };
         self.last_raise_principal = user_principal;
@@ -560,8 +697,13 @@ impl Table {
                 BetType::SmallBlind => {
                     self.log_action(Some(user_principal), ActionType::SmallBlind)
                 }
+                BetType::Straddle => self.log_action(Some(user_principal), ActionType::Straddle),
                 _ => {}
             }
+            // A raise never ends the stage, so the turn is already settled
+            if !bet_type.is_forced() {
+                self.run_pre_action();
+            }
         } else {
             self.log_action(
                 Some(user_principal),
@@ -571,7 +713,7 @@ impl Table {
             self.next_player()
                 .map_err(|e| trace_err!(e, "Next player failed in raise."))?;
 
-            if bet_type != BetType::BigBlind && bet_type != BetType::SmallBlind {
+            if !bet_type.is_forced() {
                 self.check_next_turn_or_showdown()
                     .map_err(|e| trace_err!(e, "check_next_turn_or_showdown failed in raise."))?;
             }
@@ -640,6 +782,7 @@ impl Table {
         }
         self.activate_queued_players()
             .map_err(|e| trace_err!(e, "Failed to activate queued players"))?;
+        self.bomb_pot_ante = None;
         self.handle_queue_items()
             .map_err(|e| trace_err!(e, "Failed to handle queue items"))?;
 
@@ -753,6 +896,10 @@ impl Table {
         }
 
         self.round_ticker += 1;
+        self.refill_time_banks_for_hand();
+        if self.is_bomb_pot_scheduled() {
+            self.bomb_pot_ante = self.config.bomb_pot.as_ref().map(|config| config.ante);
+        }
         #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
         if self.round_ticker % 10 == 0 {
             let rake_total = self.rake_total.unwrap_or(0);
@@ -784,24 +931,66 @@ impl Table {
         self.winners = None;
         self.sorted_users = None;
         self.community_cards.clear();
-        self.deck = FlatDeck::new(bytes);
+        self.rabbit_hunt_cards = None;
+        self.second_board = None;
+        self.run_it_window_closes_at = None;
+        self.deck = self.config.game_type.new_deck(bytes);
 
         self.action_logs.clear();
         self.highest_bet = 0;
         self.last_raise = 0;
-        let small_blind_user_principal = self
-            .get_small_blind_user_principal()
-            .map_err(|e| trace_err!(e, "Failed to get small blind principal"))?;
-        let big_blind_user_principal = self
-            .get_big_blind_user_principal()
-            .map_err(|e| trace_err!(e, "Failed to get big blind principal"))?;
-
-        self.handle_blind_sitting_out(small_blind_user_principal, BetType::SmallBlind)
-            .map_err(|e| trace_err!(e, "Failed to handle small blind sitting out"))?;
-        self.handle_blind_sitting_out(big_blind_user_principal, BetType::BigBlind)
-            .map_err(|e| trace_err!(e, "Failed to handle big blind sitting out"))?;
-        self.big_blind_user_principal = big_blind_user_principal;
-        self.small_blind_user_principal = small_blind_user_principal;
+        self.straddle_user_principal = None;
+
+        if self.is_stud() {
+            // Stud has no blinds: antes, third street and then the bring-in
+            if let Some(ante_type) = self.config.ante_type.clone() {
+                self.handle_ante(ante_type)?;
+            }
+            self.deal_stage = DealStage::Opening;
+            self.deal_cards(false).map_err(|e| {
+                trace_err!(e, "Failed to deal third street in start betting round.")
+            })?;
+            self.post_bring_in()
+                .map_err(|e| trace_err!(e, "Failed to post bring-in in start betting round."))?;
+            #[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+            self.start_turn_timer(
+                self.get_player_at_seat(self.current_player_index)
+                    .map_err(|e| {
+                        trace_err!(e, "Failed to get player at seat to start turn timer.")
+                    })?,
+                self.config.timer_duration.into(),
+            );
+            return Ok((kicked_players, seated_out_kicked_players));
+        }
+
+        if self.bomb_pot_ante.is_some() && self.is_bomb_pot_allowed() {
+            self.start_bomb_pot()
+                .map_err(|e| trace_err!(e, "Failed to start bomb pot"))?;
+            return Ok((kicked_players, seated_out_kicked_players));
+        }
+        self.bomb_pot_ante = None;
+
+        if !self
+            .post_dead_button_blinds()
+            .map_err(|e| trace_err!(e, "Failed to post dead button blinds"))?
+        {
+            let small_blind_user_principal = self
+                .get_small_blind_user_principal()
+                .map_err(|e| trace_err!(e, "Failed to get small blind principal"))?;
+            let big_blind_user_principal = self
+                .get_big_blind_user_principal()
+                .map_err(|e| trace_err!(e, "Failed to get big blind principal"))?;
+
+            self.handle_blind_sitting_out(small_blind_user_principal, BetType::SmallBlind)
+                .map_err(|e| trace_err!(e, "Failed to handle small blind sitting out"))?;
+            self.handle_blind_sitting_out(big_blind_user_principal, BetType::BigBlind)
+                .map_err(|e| trace_err!(e, "Failed to handle big blind sitting out"))?;
+            self.big_blind_user_principal = big_blind_user_principal;
+            self.small_blind_user_principal = small_blind_user_principal;
+            self.set_blind_seats(small_blind_user_principal, big_blind_user_principal);
+        }
+        self.post_straddle()
+            .map_err(|e| trace_err!(e, "Failed to post straddle"))?;
         if let Some(ante_type) = self.config.ante_type.clone() {
             self.handle_ante(ante_type)?;
         }
@@ -862,6 +1051,10 @@ impl Table {
                 let dealer = self.get_player_at_seat(self.dealer_position)?;
                 self.bet(dealer, BetType::Ante(ante_amount))?;
             }
+            AnteType::ButtonAnte(amount) => {
+                let dealer = self.get_player_at_seat(self.dealer_position)?;
+                self.bet(dealer, BetType::Ante(amount))?;
+            }
             AnteType::Fixed(amount) => {
                 let users = self
                     .seats
@@ -244,11 +244,15 @@ fn test_calling_all_in_action_log() {
     );
     println!("{:#?}", table.action_logs);
     assert_eq!(
-        table.action_logs[table.action_logs.len() - 5].action_type,
+        table.action_logs[table.action_logs.len() - 6].action_type,
         ActionType::AllIn {
             amount: convert_to_e8s(100.0)
         }
     );
+    assert!(matches!(
+        table.action_logs[table.action_logs.len() - 5].action_type,
+        ActionType::AllInEquity { .. }
+    ));
     assert_eq!(
         table.action_logs[table.action_logs.len() - 1].action_type,
         ActionType::Stage {
@@ -348,7 +348,7 @@ fn test_all_in_basic_current_player_index() {
         PlayerAction::AllIn
     );
 
-    assert_eq!(table.current_player_index, 0);
+    assert_eq!(table.current_player_index, 1);
 
     assert_eq!(table.deal_stage, DealStage::Flop);
 
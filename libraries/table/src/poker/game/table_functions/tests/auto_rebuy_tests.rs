@@ -0,0 +1,146 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        auto_rebuy::AutoRebuy,
+        buy_in::BuyInConfig,
+        table::{Table, TableType},
+        tests::{self, get_table_config},
+        types::{NotificationMessage, PlayerAction},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+/// Seats players with the given stacks at a $1/$2 table.
+fn setup_table(stacks: &[f64]) -> (Table, Vec<WalletPrincipalId>) {
+    tests::setup_table(
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3),
+        stacks,
+    )
+}
+
+fn auto_rebuy(top_up_to_big_blinds: u16, below_big_blinds: u16) -> Option<AutoRebuy> {
+    Some(AutoRebuy {
+        top_up_to_big_blinds,
+        below_big_blinds,
+    })
+}
+
+fn set_auto_rebuy_error(
+    table: &mut Table,
+    uid: WalletPrincipalId,
+    auto_rebuy: Option<AutoRebuy>,
+) -> GameError {
+    table
+        .set_auto_rebuy(uid, auto_rebuy)
+        .unwrap_err()
+        .into_inner()
+}
+
+#[test]
+fn test_auto_rebuy_must_top_up_a_short_stack() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0]);
+    table.config.buy_in = Some(BuyInConfig {
+        min_big_blinds: 20,
+        max_big_blinds: 100,
+        rathole_window_seconds: 0,
+    });
+
+    assert_eq!(table.set_auto_rebuy(uids[0], auto_rebuy(100, 50)), Ok(()));
+    assert_eq!(
+        table.get_user_table_data(uids[0]).unwrap().auto_rebuy,
+        auto_rebuy(100, 50)
+    );
+    assert!(matches!(
+        set_auto_rebuy_error(&mut table, uids[1], auto_rebuy(100, 0)),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert!(matches!(
+        set_auto_rebuy_error(&mut table, uids[1], auto_rebuy(40, 50)),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert!(matches!(
+        set_auto_rebuy_error(&mut table, uids[1], auto_rebuy(101, 50)),
+        GameError::ActionNotAllowed { .. }
+    ));
+
+    // Turning it off is always allowed
+    assert_eq!(table.set_auto_rebuy(uids[0], None), Ok(()));
+    assert_eq!(table.get_user_table_data(uids[0]).unwrap().auto_rebuy, None);
+
+    // Tournament stacks cannot be topped up
+    table.config.table_type = Some(TableType::Tournament {
+        tournament_id: Principal::anonymous(),
+        is_final_table: false,
+    });
+    assert!(matches!(
+        set_auto_rebuy_error(&mut table, uids[0], auto_rebuy(100, 50)),
+        GameError::ActionNotAllowed { .. }
+    ));
+}
+
+#[test]
+fn test_only_short_stacks_are_due_a_top_up() {
+    let (mut table, uids) = setup_table(&[30.0, 100.0, 150.0]);
+    for uid in &uids {
+        assert_eq!(table.set_auto_rebuy(*uid, auto_rebuy(100, 50)), Ok(()));
+    }
+
+    let due = table.get_due_auto_rebuys();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].1, uids[0]);
+    assert_eq!(due[0].2, convert_to_e8s(170.0));
+
+    // The top-up never takes the stack over the maximum buy-in
+    table.config.buy_in = Some(BuyInConfig {
+        min_big_blinds: 20,
+        max_big_blinds: 50,
+        rathole_window_seconds: 0,
+    });
+    let due = table.get_due_auto_rebuys();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].2, convert_to_e8s(70.0));
+
+    // Nothing is due once it is turned off
+    assert_eq!(table.set_auto_rebuy(uids[0], None), Ok(()));
+    assert!(table.get_due_auto_rebuys().is_empty());
+}
+
+#[test]
+fn test_failed_auto_rebuy_sits_the_player_out_instead_of_kicking_them() {
+    let (mut table, uids) = setup_table(&[1.0, 100.0, 100.0]);
+    assert_eq!(table.set_auto_rebuy(uids[0], auto_rebuy(100, 50)), Ok(()));
+
+    assert_eq!(
+        table.handle_failed_auto_rebuy(uids[0], "Insufficient allowance".to_string()),
+        Ok(())
+    );
+    let notification = table.notifications.notifications.last().unwrap();
+    assert_eq!(notification.user_principal, uids[0]);
+    assert_eq!(
+        notification.message,
+        NotificationMessage::AutoRebuyFailed {
+            reason: "Insufficient allowance".to_string()
+        }
+    );
+    assert_eq!(
+        table.get_user_table_data(uids[0]).unwrap().player_action,
+        PlayerAction::SittingOut
+    );
+    assert!(table.is_waiting_for_auto_rebuy(uids[0]));
+    assert_eq!(
+        table.check_and_kick_user_for_insufficient_funds(uids[0], table.big_blind.0),
+        Ok(None)
+    );
+    assert!(table.users.get(&uids[0]).is_some());
+
+    // Without an auto-rebuy to wait for they are kicked as before
+    assert_eq!(table.set_auto_rebuy(uids[0], None), Ok(()));
+    assert!(table
+        .check_and_kick_user_for_insufficient_funds(uids[0], table.big_blind.0)
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,189 @@
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        bomb_pot::BombPotConfig,
+        table::{Table, TableType},
+        tests::{self, balance, current_player, get_table_config},
+        types::{BetType, PlayerAction},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+/// Seats three players with the given stacks on a $1/$2 table with the given bomb pot config.
+fn setup_table(
+    bomb_pot: Option<BombPotConfig>,
+    balances: [f64; 3],
+) -> (Table, Vec<WalletPrincipalId>) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3);
+    config.bomb_pot = bomb_pot;
+    tests::setup_table(config, &balances)
+}
+
+fn bomb_pot_config(every_hands: Option<u16>, is_double_board: bool) -> BombPotConfig {
+    BombPotConfig {
+        ante: convert_to_e8s(5.0),
+        every_hands,
+        is_double_board,
+    }
+}
+
+fn count_logs(table: &Table, is_match: impl Fn(&ActionType) -> bool) -> usize {
+    table
+        .action_logs
+        .iter()
+        .filter(|log| is_match(&log.action_type))
+        .count()
+}
+
+#[test]
+fn test_queued_bomb_pot_starts_on_the_flop() {
+    let (mut table, uids) = setup_table(Some(bomb_pot_config(None, false)), [100.0; 3]);
+    assert_eq!(table.queue_bomb_pot(), Ok(()));
+    // Asking twice still makes one bomb pot
+    assert_eq!(table.queue_bomb_pot(), Ok(()));
+    assert_eq!(table.queue.len(), 1);
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(table.bomb_pot_ante, Some(convert_to_e8s(5.0)));
+    assert_eq!(table.community_cards.len(), 3);
+    assert_eq!(table.pot.0, convert_to_e8s(15.0));
+    assert_eq!(table.highest_bet, 0);
+    for uid in uids.iter() {
+        assert_eq!(balance(&table, *uid), convert_to_e8s(95.0));
+        assert_eq!(table.get_user_table_data(*uid).unwrap().cards.len(), 2);
+    }
+    assert_eq!(
+        count_logs(&table, |action| *action
+            == ActionType::BombPot {
+                ante: convert_to_e8s(5.0)
+            }),
+        3
+    );
+    assert_eq!(
+        count_logs(&table, |action| matches!(
+            action,
+            ActionType::SmallBlind | ActionType::BigBlind
+        )),
+        0
+    );
+
+    // The player left of the button acts first
+    let first_player = table
+        .get_player_at_seat((table.dealer_position + 1) % 3)
+        .unwrap();
+    assert_eq!(current_player(&table), first_player);
+
+    // The next hand is a regular one again
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_fold(uid, false), Ok(()));
+    }
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.bomb_pot_ante, None);
+    assert!(table.community_cards.is_empty());
+}
+
+#[test]
+fn test_bomb_pots_are_dealt_on_schedule() {
+    let (mut table, _) = setup_table(Some(bomb_pot_config(Some(2), false)), [100.0; 3]);
+
+    for is_bomb_pot in [false, true, false, true] {
+        assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+        assert_eq!(table.bomb_pot_ante.is_some(), is_bomb_pot);
+        assert_eq!(table.community_cards.len(), if is_bomb_pot { 3 } else { 0 });
+        while table.is_game_ongoing() {
+            let uid = current_player(&table);
+            assert_eq!(table.user_fold(uid, false), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn test_bomb_pots_are_only_allowed_on_cash_tables_with_a_config() {
+    let is_not_allowed = |table: &mut Table| {
+        matches!(
+            table.queue_bomb_pot().map_err(|e| e.into_inner()),
+            Err(GameError::ActionNotAllowed { .. })
+        )
+    };
+
+    let (mut table, _) = setup_table(None, [100.0; 3]);
+    assert!(is_not_allowed(&mut table));
+
+    let (mut table, _) = setup_table(Some(bomb_pot_config(Some(1), false)), [100.0; 3]);
+    table.config.table_type = Some(TableType::SitAndGo);
+    assert!(is_not_allowed(&mut table));
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.bomb_pot_ante, None);
+    assert!(table.community_cards.is_empty());
+}
+
+#[test]
+fn test_double_board_bomb_pot_splits_the_pot_across_both_boards() {
+    let (mut table, uids) = setup_table(Some(bomb_pot_config(None, true)), [100.0; 3]);
+    assert_eq!(table.queue_bomb_pot(), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.second_board.as_ref().map(Vec::len), Some(3));
+    // Two flops, each after a burnt card
+    assert_eq!(table.deck.len(), 52 - 6 - 8);
+
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+
+    assert_eq!(table.community_cards.len(), 5);
+    let second_board = table.second_board.clone().unwrap();
+    assert_eq!(second_board.len(), 5);
+    assert!(second_board
+        .iter()
+        .all(|card| !table.community_cards.contains(card)));
+    assert_eq!(
+        count_logs(&table, |action| matches!(
+            action,
+            ActionType::BoardRun { .. }
+        )),
+        2
+    );
+    let total: u64 = uids.iter().map(|uid| balance(&table, *uid)).sum();
+    assert_eq!(total, convert_to_e8s(300.0));
+}
+
+#[test]
+fn test_bomb_pot_ante_is_capped_at_the_shortest_stack() {
+    let (mut table, uids) = setup_table(Some(bomb_pot_config(None, false)), [100.0, 100.0, 3.0]);
+    assert_eq!(table.queue_bomb_pot(), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(table.bomb_pot_ante, Some(convert_to_e8s(3.0)));
+    assert_eq!(table.pot.0, convert_to_e8s(9.0));
+    assert_eq!(balance(&table, uids[2]), 0);
+    assert_eq!(
+        table.get_user_table_data(uids[2]).unwrap().player_action,
+        PlayerAction::AllIn
+    );
+
+    // The other two players keep betting on the flop, in a pot the all in player is not part of
+    assert!(table.is_game_ongoing());
+    let uid = current_player(&table);
+    assert_ne!(uid, uids[2]);
+    assert_eq!(
+        table.bet(uid, BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+
+    assert!(balance(&table, uids[2]) <= convert_to_e8s(9.0));
+    let total: u64 = uids.iter().map(|uid| balance(&table, *uid)).sum();
+    assert_eq!(total, convert_to_e8s(203.0));
+}
@@ -0,0 +1,173 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::{UsersCanisterId, WalletPrincipalId};
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        buy_in::BuyInConfig,
+        table::Table,
+        tests::{self, balance, fold_to_the_last_player, get_table_config},
+    },
+    types::{GameType, QueueItem},
+    utils::convert_to_e8s,
+};
+
+const HOUR: u64 = 3_600 * 1_000_000_000;
+
+/// Seats players with the given stacks at a $1/$2 table with a 20 to 100 big blind buy-in.
+fn setup_table(stacks: &[f64]) -> (Table, Vec<WalletPrincipalId>) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3);
+    config.buy_in = Some(BuyInConfig {
+        min_big_blinds: 20,
+        max_big_blinds: 100,
+        rathole_window_seconds: 3_600,
+    });
+    tests::setup_table(config, stacks)
+}
+
+fn new_player() -> WalletPrincipalId {
+    WalletPrincipalId(Principal::from_slice(&[10]))
+}
+
+fn buy_in_error(table: &Table, uid: WalletPrincipalId, amount: f64, now: u64) -> GameError {
+    table
+        .validate_buy_in(uid, convert_to_e8s(amount), now)
+        .unwrap_err()
+        .into_inner()
+}
+
+#[test]
+fn test_buy_in_must_be_within_the_limits() {
+    let (table, _) = setup_table(&[100.0, 100.0]);
+    let uid = new_player();
+    let now = user::user::time();
+
+    assert_eq!(table.get_min_buy_in(uid, now), convert_to_e8s(40.0));
+    assert_eq!(table.get_max_buy_in(uid, now), Some(convert_to_e8s(200.0)));
+    assert_eq!(
+        table.validate_buy_in(uid, convert_to_e8s(40.0), now),
+        Ok(())
+    );
+    assert_eq!(
+        table.validate_buy_in(uid, convert_to_e8s(200.0), now),
+        Ok(())
+    );
+    assert!(matches!(
+        buy_in_error(&table, uid, 39.0, now),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert!(matches!(
+        buy_in_error(&table, uid, 201.0, now),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert_eq!(
+        buy_in_error(&table, uid, 1.0, now),
+        GameError::InsufficientFunds
+    );
+}
+
+#[test]
+fn test_buy_in_without_limits_only_needs_a_big_blind() {
+    let (mut table, _) = setup_table(&[100.0, 100.0]);
+    table.config.buy_in = None;
+    let uid = new_player();
+    let now = user::user::time();
+
+    assert_eq!(table.get_max_buy_in(uid, now), None);
+    assert_eq!(table.validate_buy_in(uid, convert_to_e8s(2.0), now), Ok(()));
+    assert_eq!(
+        table.validate_buy_in(uid, convert_to_e8s(10_000.0), now),
+        Ok(())
+    );
+    assert_eq!(table.validate_withdrawal(), Ok(()));
+}
+
+#[test]
+fn test_player_rejoining_within_the_window_brings_their_stack() {
+    let (mut table, uids) = setup_table(&[300.0, 100.0, 30.0]);
+    assert!(table.remove_user(uids[0], ActionType::Leave).is_ok());
+    assert!(table.remove_user(uids[2], ActionType::Leave).is_ok());
+    let now = user::user::time();
+
+    // They won a big stack and cannot come back short
+    assert_eq!(
+        table.get_rathole_stack(uids[0], now),
+        Some(convert_to_e8s(300.0))
+    );
+    assert_eq!(table.get_min_buy_in(uids[0], now), convert_to_e8s(300.0));
+    assert_eq!(
+        table.get_max_buy_in(uids[0], now),
+        Some(convert_to_e8s(300.0))
+    );
+    assert!(matches!(
+        buy_in_error(&table, uids[0], 200.0, now),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert_eq!(
+        table.validate_buy_in(uids[0], convert_to_e8s(300.0), now),
+        Ok(())
+    );
+
+    // Leaving short never lowers the minimum buy-in
+    assert_eq!(table.get_min_buy_in(uids[2], now), convert_to_e8s(40.0));
+
+    // Once the window has passed the usual limits apply again
+    let later = now + HOUR;
+    assert_eq!(table.get_rathole_stack(uids[0], later), None);
+    assert_eq!(
+        table.validate_buy_in(uids[0], convert_to_e8s(40.0), later),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_player_leaving_during_a_hand_is_remembered_with_their_final_stack() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0, 100.0]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert!(table.remove_user(uids[0], ActionType::Leave).is_ok());
+    assert!(table.departed_players.is_empty());
+
+    fold_to_the_last_player(&mut table);
+    let stack = balance(&table, uids[0]);
+    assert!(table.handle_queue_items().is_ok());
+
+    let departed = table.departed_players.get(&uids[0]).unwrap();
+    assert_eq!(departed.stack, stack);
+}
+
+#[test]
+fn test_top_ups_are_capped_at_the_maximum_buy_in() {
+    let (mut table, uids) = setup_table(&[150.0, 200.0]);
+
+    assert_eq!(
+        table.get_top_up_amount(uids[0], convert_to_e8s(100.0)),
+        convert_to_e8s(50.0)
+    );
+    assert_eq!(table.validate_top_up(uids[0], convert_to_e8s(50.0)), Ok(()));
+    assert!(matches!(
+        table
+            .validate_top_up(uids[0], convert_to_e8s(51.0))
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+
+    // A queued top-up for a full stack is dropped
+    table.append_to_queue(QueueItem::Deposit(
+        uids[1],
+        UsersCanisterId(Principal::anonymous()),
+        convert_to_e8s(10.0),
+    ));
+    assert!(table.handle_queue_items().is_ok());
+    assert!(table.queue.is_empty());
+    assert_eq!(
+        table.users.get(&uids[1]).unwrap().balance.0,
+        convert_to_e8s(200.0)
+    );
+
+    // Cashing out means leaving the table
+    assert!(matches!(
+        table.validate_withdrawal().map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+}
@@ -0,0 +1,168 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        table::{Table, TableId},
+        tests::{create_user, current_player, get_table_config},
+        types::{BetType, DealStage, PlayerAction},
+    },
+    types::{GameType, QueueItem},
+    utils::convert_to_e8s,
+};
+
+fn setup_table(balances: &[f64]) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), balances.len() as u8),
+        vec![1, 2, 3],
+    );
+
+    let principals = [
+        "2chl6-4hpzw-vqaaa-aaaaa-c",
+        "br5f7-7uaaa-aaaaa-qaaca-cai",
+        "bw4dl-smaaa-aaaaa-qaacq-cai",
+    ];
+    for (seat, balance) in balances.iter().enumerate() {
+        let user = create_user(
+            Principal::from_text(principals[seat]).expect("Could not decode principal"),
+            convert_to_e8s(*balance),
+        );
+        assert!(table.add_user(user, seat as u8, false).is_ok());
+    }
+
+    let uids = (0..balances.len())
+        .map(|seat| table.get_player_at_seat(seat).unwrap())
+        .collect();
+    (table, uids)
+}
+
+#[test]
+fn test_chips_conserved_through_a_hand() {
+    let (mut table, _) = setup_table(&[100.0, 100.0, 100.0]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.count_chips().total(), convert_to_e8s(300.0));
+
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Raised(convert_to_e8s(6.0))), Ok(()));
+    let uid = current_player(&table);
+    assert_eq!(table.user_fold(uid, false), Ok(()));
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    assert_eq!(table.deal_stage, DealStage::Turn);
+
+    let chips = table.count_chips();
+    assert_eq!(chips.bets, 0);
+    assert_eq!(chips.pot, convert_to_e8s(13.0));
+    assert_eq!(chips.total(), convert_to_e8s(300.0));
+
+    while table.sorted_users.is_none() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+
+    let chips = table.count_chips();
+    assert_eq!(chips.pot, 0);
+    assert_eq!(chips.total(), convert_to_e8s(300.0));
+}
+
+#[test]
+fn test_chips_conserved_with_side_pots() {
+    let (mut table, uids) = setup_table(&[20.0, 50.0, 100.0]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    for _ in 0..uids.len() {
+        let uid = current_player(&table);
+        let balance = table.users.get(&uid).unwrap().balance.0;
+        let current_total_bet = table.get_user_table_data(uid).unwrap().current_total_bet;
+        assert_eq!(
+            table.bet(uid, BetType::Raised(balance + current_total_bet)),
+            Ok(())
+        );
+    }
+
+    assert!(table.sorted_users.is_some());
+    for uid in &uids {
+        assert_ne!(
+            table.get_user_table_data(*uid).unwrap().player_action,
+            PlayerAction::Folded
+        );
+    }
+    assert_eq!(table.count_chips().total(), convert_to_e8s(170.0));
+}
+
+#[test]
+fn test_chips_conserved_with_rake() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0]);
+    table.config.enable_rake = Some(true);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    let chips = table.count_chips();
+    assert!(chips.rake > 0);
+    assert_eq!(chips.total(), convert_to_e8s(200.0));
+}
+
+#[test]
+fn test_chip_conservation_detects_created_chips() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    let before = table.count_chips();
+    table.pot.0 += 1;
+
+    let error = table
+        .check_chip_conservation(&before, 0)
+        .unwrap_err()
+        .into_inner();
+    assert_eq!(
+        error,
+        GameError::ChipsNotConserved {
+            expected: convert_to_e8s(200.0),
+            actual: convert_to_e8s(200.0) + 1,
+        }
+    );
+
+    // Withdrawals are accounted for
+    let before = table.count_chips();
+    table.users.get_mut(&uids[1]).unwrap().balance.0 -= convert_to_e8s(20.0);
+    assert!(table
+        .check_chip_conservation(&before, convert_to_e8s(20.0))
+        .is_ok());
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "chip_invariants"))]
+fn test_transition_losing_chips_fails() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    let result = table.conserving_chips(0, |table| {
+        table.users.get_mut(&uids[0]).unwrap().balance.0 -= 1;
+        Ok(())
+    });
+    assert!(matches!(
+        result.unwrap_err().into_inner(),
+        GameError::ChipsNotConserved { .. }
+    ));
+}
+
+#[test]
+fn test_queued_withdrawals_leave_with_the_user() {
+    let (mut table, uids) = setup_table(&[100.0, 100.0, 100.0]);
+    table.append_to_queue(QueueItem::SittingOut(uids[1]));
+
+    assert_eq!(table.get_queued_withdrawals(), 0);
+
+    // Queuing the same user twice only withdraws their chips once
+    table.append_to_queue(QueueItem::RemoveUser(uids[2], ActionType::Leave));
+    table.append_to_queue(QueueItem::RemoveUser(uids[2], ActionType::Leave));
+    assert_eq!(table.get_queued_withdrawals(), convert_to_e8s(100.0));
+}
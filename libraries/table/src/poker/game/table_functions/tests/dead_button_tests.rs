@@ -0,0 +1,227 @@
+use candid::Principal;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        dead_button::{MissedBlinds, MissedBlindsChoice},
+        table::{Table, TableId, TableType},
+        tests::{
+            fold_to_the_last_player, get_table_config, seat_player, turn_tests::is_it_users_turn,
+        },
+        types::{BetType, PlayerAction},
+    },
+    types::{GameType, QueueItem},
+    utils::convert_to_e8s,
+};
+
+/// Seats a player in each of the given seats of a four seat cash table with $1/$2 blinds.
+fn setup_cash_table(seats: &[u8]) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 4),
+        vec![1, 2, 3, 4],
+    );
+    table.config.table_type = Some(TableType::Cash);
+
+    let uids = seats
+        .iter()
+        .map(|seat| seat_player(&mut table, *seat, 100.0))
+        .collect();
+    (table, uids)
+}
+
+fn missed_blinds(table: &Table, uid: WalletPrincipalId) -> Option<MissedBlinds> {
+    table.get_user_table_data(uid).unwrap().missed_blinds
+}
+
+fn current_total_bet(table: &Table, uid: WalletPrincipalId) -> u64 {
+    table.get_user_table_data(uid).unwrap().current_total_bet
+}
+
+#[test]
+fn test_big_blind_moves_past_players_sitting_out() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.dealer_position, 0);
+    assert_eq!(table.big_blind_user_principal, uids[2]);
+    fold_to_the_last_player(&mut table);
+
+    assert_eq!(table.user_sitting_out(uids[3], false), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    // The big blind skips the player sitting out, who now owes both blinds
+    assert_eq!(table.dealer_position, 1);
+    assert_eq!(table.small_blind_user_principal, uids[2]);
+    assert_eq!(table.big_blind_user_principal, uids[0]);
+    assert_eq!(current_total_bet(&table, uids[3]), 0);
+    assert_eq!(
+        missed_blinds(&table, uids[3]),
+        Some(MissedBlinds {
+            small_blind: true,
+            big_blind: true,
+        })
+    );
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_dead_small_blind_when_the_last_big_blind_sits_out() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+
+    assert_eq!(table.user_sitting_out(uids[2], false), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    // Nobody posts the small blind this hand
+    assert_eq!(table.dealer_position, 1);
+    assert_eq!(
+        table.small_blind_user_principal,
+        WalletPrincipalId(Principal::anonymous())
+    );
+    assert_eq!(table.big_blind_user_principal, uids[3]);
+    assert_eq!(current_total_bet(&table, uids[1]), 0);
+    assert_eq!(current_total_bet(&table, uids[3]), convert_to_e8s(2.0));
+    assert_eq!(
+        missed_blinds(&table, uids[2]),
+        Some(MissedBlinds {
+            small_blind: true,
+            big_blind: false,
+        })
+    );
+    assert!(is_it_users_turn(&table, uids[0]));
+}
+
+#[test]
+fn test_dead_button_when_the_last_small_blind_leaves() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+
+    assert!(table.remove_user(uids[1], ActionType::Leave).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    // The button stays on the empty seat and the blinds still move forward one player
+    assert_eq!(table.dealer_position, 1);
+    assert_eq!(table.small_blind_user_principal, uids[2]);
+    assert_eq!(table.big_blind_user_principal, uids[3]);
+    assert!(is_it_users_turn(&table, uids[0]));
+
+    assert_eq!(table.bet(uids[0], BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, uids[2]));
+}
+
+#[test]
+fn test_returning_player_posts_missed_blinds() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+    assert_eq!(table.user_sitting_out(uids[3], false), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+
+    assert_eq!(table.user_sitting_in(uids[3]), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    assert_eq!(table.small_blind_user_principal, uids[0]);
+    assert_eq!(table.big_blind_user_principal, uids[1]);
+    // The big blind is live and the small blind is dead
+    assert_eq!(current_total_bet(&table, uids[3]), convert_to_e8s(2.0));
+    assert_eq!(table.pot.0, convert_to_e8s(1.0));
+    assert_eq!(missed_blinds(&table, uids[3]), None);
+    assert!(table
+        .action_logs
+        .iter()
+        .any(|log| log.user_principal == Some(uids[3])
+            && log.action_type
+                == ActionType::MissedBlinds {
+                    big_blind: convert_to_e8s(2.0),
+                    small_blind: convert_to_e8s(1.0),
+                }));
+
+    // The live big blind still gets to act
+    assert!(is_it_users_turn(&table, uids[2]));
+    assert_eq!(table.bet(uids[2], BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, uids[3]));
+    assert_eq!(table.user_check(uids[3], false), Ok(()));
+    assert!(is_it_users_turn(&table, uids[0]));
+}
+
+#[test]
+fn test_returning_player_can_wait_for_the_big_blind() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+    assert_eq!(table.user_sitting_out(uids[3], false), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    // Sitting back in during the hand goes through the queue
+    assert_eq!(
+        table.set_user_missed_blinds_choice(uids[3], MissedBlindsChoice::WaitForBigBlind),
+        Ok(())
+    );
+    table.append_to_queue(QueueItem::SittingIn(uids[3], false));
+    fold_to_the_last_player(&mut table);
+
+    for big_blind in [uids[1], uids[2]] {
+        assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+        assert_eq!(table.big_blind_user_principal, big_blind);
+        let user_table_data = table.get_user_table_data(uids[3]).unwrap();
+        assert_eq!(user_table_data.player_action, PlayerAction::SittingOut);
+        assert_eq!(user_table_data.is_waiting_for_big_blind, Some(true));
+        assert_eq!(user_table_data.current_total_bet, 0);
+        fold_to_the_last_player(&mut table);
+    }
+
+    // The big blind reaches the player, who is dealt in without owing anything else
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.big_blind_user_principal, uids[3]);
+    let user_table_data = table.get_user_table_data(uids[3]).unwrap();
+    assert_eq!(user_table_data.current_total_bet, convert_to_e8s(2.0));
+    assert_eq!(user_table_data.missed_blinds, None);
+    assert_eq!(user_table_data.is_waiting_for_big_blind, None);
+    assert_eq!(table.pot.0, 0);
+}
+
+#[test]
+fn test_new_player_owes_the_big_blind() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.big_blind_user_principal, uids[2]);
+    fold_to_the_last_player(&mut table);
+
+    // Taking the seat between the blinds does not skip them
+    let new_uid = seat_player(&mut table, 2, 100.0);
+    assert_eq!(
+        missed_blinds(&table, new_uid),
+        Some(MissedBlinds {
+            small_blind: false,
+            big_blind: true,
+        })
+    );
+
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.small_blind_user_principal, uids[2]);
+    assert_eq!(table.big_blind_user_principal, uids[0]);
+    assert_eq!(current_total_bet(&table, new_uid), convert_to_e8s(2.0));
+    assert_eq!(missed_blinds(&table, new_uid), None);
+    assert_eq!(table.pot.0, 0);
+}
+
+#[test]
+fn test_no_missed_blinds_without_a_cash_table() {
+    let (mut table, uids) = setup_cash_table(&[0, 1, 2, 3]);
+    table.config.table_type = None;
+    assert!(!table.uses_dead_button());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    fold_to_the_last_player(&mut table);
+
+    assert_eq!(table.user_sitting_out(uids[3], false), Ok(()));
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    // The player sitting out is still charged the big blind
+    assert_eq!(table.big_blind_user_principal, uids[3]);
+    assert_eq!(current_total_bet(&table, uids[3]), convert_to_e8s(2.0));
+    assert_eq!(missed_blinds(&table, uids[3]), None);
+}
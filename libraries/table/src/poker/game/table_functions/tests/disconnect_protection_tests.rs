@@ -0,0 +1,133 @@
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        disconnect_protection::{DisconnectProtectionConfig, NANOS_PER_DAY},
+        table::Table,
+        tests::{self, balance, current_player, get_table_config},
+        types::{BetType, DealStage, PlayerAction},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+const NOW: u64 = 20_000 * NANOS_PER_DAY + 1;
+
+/// Starts a hand on a three seat table with the given disconnect protection.
+fn setup_table(protections_per_day: Option<u8>) -> (Table, Vec<WalletPrincipalId>) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3);
+    config.disconnect_protection =
+        protections_per_day.map(|protections_per_day| DisconnectProtectionConfig {
+            protections_per_day,
+        });
+
+    let (mut table, uids) = tests::setup_table(config, &[100.0; 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    (table, uids)
+}
+
+/// The button raises and the small blind calls, leaving the big blind to act.
+fn raise_to_the_big_blind(table: &mut Table, uids: &[WalletPrincipalId]) {
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+    assert!(table.is_players_turn(uids[2]));
+}
+
+#[test]
+fn test_protected_player_stays_in_for_their_stake() {
+    let (mut table, uids) = setup_table(Some(2));
+    raise_to_the_big_blind(&mut table, &uids);
+
+    assert_eq!(table.protect_disconnected_user(uids[2], NOW), Ok(true));
+    assert_eq!(
+        table.get_user_table_data(uids[2]).unwrap().player_action,
+        PlayerAction::AllIn
+    );
+    assert_eq!(balance(&table, uids[2]), convert_to_e8s(98.0));
+    // The round closes behind them, so the stage and side pot are logged after
+    let log = table
+        .action_logs
+        .iter()
+        .rev()
+        .find(|log| log.user_principal.is_some())
+        .unwrap();
+    assert_eq!(log.user_principal, Some(uids[2]));
+    assert_eq!(
+        log.action_type,
+        ActionType::DisconnectProtection {
+            protections_left: 1
+        }
+    );
+
+    // The raise on top of the big blind goes into a side pot without them
+    assert_eq!(table.deal_stage, DealStage::Turn);
+    assert!(!table.side_pots.is_empty());
+    assert!(table
+        .side_pots
+        .iter()
+        .all(|side_pot| !side_pot.user_principals.contains(&uids[2])));
+
+    // The others play on without them
+    while table.is_game_ongoing() {
+        assert!(!table.is_players_turn(uids[2]));
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+    let total: u64 = uids.iter().map(|uid| balance(&table, *uid)).sum();
+    assert_eq!(total, convert_to_e8s(300.0));
+    assert!(balance(&table, uids[2]) <= convert_to_e8s(104.0));
+}
+
+#[test]
+fn test_protections_are_counted_per_day() {
+    let (mut table, uids) = setup_table(Some(1));
+    assert_eq!(table.get_disconnect_protections_left(uids[2], NOW), 1);
+    raise_to_the_big_blind(&mut table, &uids);
+    assert_eq!(table.protect_disconnected_user(uids[2], NOW), Ok(true));
+
+    assert_eq!(table.get_disconnect_protections_left(uids[2], NOW), 0);
+    assert_eq!(
+        table.get_disconnect_protections_left(uids[2], NOW + NANOS_PER_DAY),
+        1
+    );
+    assert_eq!(table.get_disconnect_protections_left(uids[1], NOW), 1);
+
+    // Leaving the table does not give the protections back
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+    assert!(table.remove_user(uids[2], ActionType::Leave).is_ok());
+    assert_eq!(table.get_disconnect_protections_left(uids[2], NOW), 0);
+}
+
+#[test]
+fn test_player_without_protection_is_not_protected() {
+    // No protection on the table
+    let (mut table, uids) = setup_table(None);
+    raise_to_the_big_blind(&mut table, &uids);
+    assert_eq!(table.protect_disconnected_user(uids[2], NOW), Ok(false));
+    assert_eq!(table.get_disconnect_protections_left(uids[2], NOW), 0);
+
+    // No protections left today
+    let (mut table, uids) = setup_table(Some(0));
+    raise_to_the_big_blind(&mut table, &uids);
+    assert_eq!(table.protect_disconnected_user(uids[2], NOW), Ok(false));
+
+    // Not their turn
+    let (mut table, uids) = setup_table(Some(1));
+    assert_eq!(table.protect_disconnected_user(uids[2], NOW), Ok(false));
+
+    // Nothing in the pot yet, so folding costs them nothing
+    assert!(table.is_players_turn(uids[0]));
+    assert_eq!(table.protect_disconnected_user(uids[0], NOW), Ok(false));
+    assert_eq!(table.get_disconnect_protections_left(uids[0], NOW), 1);
+    assert_eq!(
+        table.get_user_table_data(uids[0]).unwrap().player_action,
+        PlayerAction::None
+    );
+}
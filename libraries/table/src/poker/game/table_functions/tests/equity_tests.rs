@@ -0,0 +1,172 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, Hand, EQUITY_SCALE},
+    game::{
+        table_functions::{
+            action_log::ActionType,
+            table::{Table, TableId},
+            tests::{create_user, current_player, get_table_config},
+            types::{BetType, DealStage},
+        },
+        types::GameType,
+        utils::convert_to_e8s,
+    },
+};
+
+/// Seats two players at a table of the given game type and starts the hand.
+fn setup_heads_up_table(game_type: GameType) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(game_type, 2),
+        vec![1, 2, 3],
+    );
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    (table, vec![player1_uid, player2_uid])
+}
+
+fn logged_community_cards(table: &Table) -> Option<(Vec<Card>, bool)> {
+    table
+        .action_logs
+        .iter()
+        .find_map(|log| match &log.action_type {
+            ActionType::AllInEquity {
+                community_cards,
+                is_exhaustive,
+                ..
+            } => Some((community_cards.clone(), *is_exhaustive)),
+            _ => None,
+        })
+}
+
+fn cards(cards: &str) -> Vec<Card> {
+    Hand::new_from_str(cards).unwrap().iter().copied().collect()
+}
+
+#[test]
+fn test_all_in_preflop_logs_equities() {
+    let (mut table, uids) = setup_heads_up_table(GameType::NoLimit(convert_to_e8s(1.0)));
+    let hole_cards: Vec<Vec<Card>> = uids
+        .iter()
+        .map(|uid| table.get_user_table_data(*uid).unwrap().cards.clone())
+        .collect();
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+    assert!(table.sorted_users.is_some());
+
+    let equities = table.get_all_in_equities().unwrap();
+    assert_eq!(equities.len(), 2);
+    for (_, cards, _) in &equities {
+        assert!(hole_cards.contains(cards));
+    }
+    let total: u32 = equities.iter().map(|(_, _, equity)| equity.equity).sum();
+    assert!(total.abs_diff(EQUITY_SCALE) <= 1);
+
+    // Preflop there are too many boards to enumerate
+    assert_eq!(logged_community_cards(&table), Some((vec![], false)));
+}
+
+#[test]
+fn test_all_in_on_the_flop_enumerates_every_board() {
+    let (mut table, _) = setup_heads_up_table(GameType::NoLimit(convert_to_e8s(1.0)));
+
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    let uid = current_player(&table);
+    assert_eq!(table.user_check(uid, false), Ok(()));
+    assert_eq!(table.deal_stage, DealStage::Turn);
+    let flop = table.community_cards.clone();
+    assert_eq!(flop.len(), 3);
+
+    let uid = current_player(&table);
+    let balance = table.users.get(&uid).unwrap().balance.0;
+    assert_eq!(table.bet(uid, BetType::Raised(balance)), Ok(()));
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    assert!(table.sorted_users.is_some());
+
+    assert_eq!(logged_community_cards(&table), Some((flop, true)));
+    assert_eq!(table.get_all_in_equities().unwrap().len(), 2);
+}
+
+#[test]
+fn test_no_equities_when_the_hand_is_folded() {
+    let (mut table, uids) = setup_heads_up_table(GameType::NoLimit(convert_to_e8s(1.0)));
+
+    assert_eq!(table.user_fold(uids[0], false), Ok(()));
+    assert_eq!(table.get_all_in_equities(), None);
+}
+
+#[test]
+fn test_calculate_equity_for_review() {
+    let (table, _) = setup_heads_up_table(GameType::PotLimitOmaha4(convert_to_e8s(1.0)));
+
+    let result = table
+        .calculate_equity(
+            &[cards("AsAhKdQd"), cards("7c8c9hTh")],
+            &cards("2c7d9s"),
+            &cards("3h"),
+        )
+        .unwrap();
+    assert!(result.is_exhaustive);
+    // Two cards from the 40 that are left
+    assert_eq!(result.boards, 780);
+
+    let error = table
+        .calculate_equity(&[cards("AsAh"), cards("7c8c")], &[], &[])
+        .unwrap_err()
+        .into_inner();
+    assert!(matches!(error, GameError::Other(_)));
+
+    // Five card hands are Omaha, but not the Omaha played at this table
+    let error = table
+        .calculate_equity(&[cards("AsAhKdQdJd"), cards("7c8c9hTh2h")], &[], &[])
+        .unwrap_err()
+        .into_inner();
+    assert!(matches!(error, GameError::Other(_)));
+
+    // No more hands than the two seats of the table
+    let error = table
+        .calculate_equity(
+            &[cards("AsAhKdQd"), cards("7c8c9hTh"), cards("2s3s4s5s")],
+            &[],
+            &[],
+        )
+        .unwrap_err()
+        .into_inner();
+    assert!(matches!(error, GameError::ActionNotAllowed { .. }));
+}
+
+#[test]
+fn test_no_equities_for_hi_lo() {
+    let (table, _) = setup_heads_up_table(GameType::PotLimitOmahaHiLo4(convert_to_e8s(1.0)));
+
+    let error = table
+        .calculate_equity(&[cards("AsAhKdQd"), cards("7c8c9hTh")], &[], &[])
+        .unwrap_err()
+        .into_inner();
+    assert!(matches!(error, GameError::ActionNotAllowed { .. }));
+}
@@ -75,7 +75,7 @@ fn test_correct_blinds_fixed_limit() {
             .get(&user1.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(1.0)
+        convert_to_e8s(0.5)
     );
     assert_eq!(
         table
@@ -83,7 +83,7 @@ fn test_correct_blinds_fixed_limit() {
             .get(&user2.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(0.5)
+        convert_to_e8s(1.0)
     );
 }
 
@@ -115,7 +115,7 @@ fn test_invalid_opening_raise_fixed_limit() {
 
     // Test that raising by more than the fix limit is not allowed
     assert!(table
-        .bet(user2.principal_id, BetType::Raised(convert_to_e8s(3.0)))
+        .bet(user1.principal_id, BetType::Raised(convert_to_e8s(3.0)))
         .is_err());
 }
 
@@ -222,8 +222,8 @@ fn test_raise_amount_validation_small_bet() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     // Raise with valid small bet amount
     assert_eq!(
@@ -270,8 +270,8 @@ fn test_raise_amount_validation_big_bet() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     // Move to turn or river stage to test big bet amount
     table.set_deal_stage(DealStage::River);
@@ -321,8 +321,8 @@ fn test_raise_amount_validation_different_stages() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     println!("Pot: {}", table.pot.0);
     println!("User1 balance: {}", user1.balance.0);
@@ -384,8 +384,8 @@ fn test_raise_amount_validation_fixed_limit() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     // Opening stage should allow small bet raises
     assert!(table
@@ -1229,11 +1229,10 @@ fn test_fixed_limit_user_below_minimum() {
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
 
-    // User2 raises
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-
     assert!(table.bet(user1.principal_id, BetType::Called).is_ok());
 
+    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
+
     assert_eq!(table.deal_stage, DealStage::Turn);
 
     // User2 raises
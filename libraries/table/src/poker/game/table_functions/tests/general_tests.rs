@@ -311,14 +311,14 @@ fn test_folding_transfers_balance() {
         .is_ok());
 
     assert_eq!(
-        table.bet(user2.principal_id, BetType::Raised(convert_to_e8s(50.0))),
+        table.bet(user1.principal_id, BetType::Raised(convert_to_e8s(50.0))),
         Ok(())
     );
-    assert_eq!(table.user_fold(user1.principal_id, false), Ok(()));
+    assert_eq!(table.user_fold(user2.principal_id, false), Ok(()));
 
     assert_eq!(table.deal_stage, DealStage::Showdown);
-    let user2 = table.users.get(&user2.principal_id).unwrap();
-    assert_eq!(user2.balance.0, convert_to_e8s(102.0));
+    let user1 = table.users.get(&user1.principal_id).unwrap();
+    assert_eq!(user1.balance.0, convert_to_e8s(102.0));
 }
 
 #[test]
@@ -345,10 +345,10 @@ fn test_start_betting_round_after_folding() {
         .is_ok());
 
     assert_eq!(
-        table.bet(user2.principal_id, BetType::Raised(convert_to_e8s(50.0))),
+        table.bet(user1.principal_id, BetType::Raised(convert_to_e8s(50.0))),
         Ok(())
     );
-    assert_eq!(table.user_fold(user1.principal_id, false), Ok(()));
+    assert_eq!(table.user_fold(user2.principal_id, false), Ok(()));
 
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
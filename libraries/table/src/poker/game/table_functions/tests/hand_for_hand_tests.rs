@@ -0,0 +1,25 @@
+use crate::poker::game::{
+    table_functions::tests::{get_table_config, setup_table},
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+#[test]
+fn test_busted_players_report_their_starting_stack() {
+    let (mut table, uids) = setup_table(
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3),
+        &[100.0, 1.0, 0.0],
+    );
+    // Chips put in the pot during the hand count towards the starting stack
+    table.get_user_table_data_mut(uids[2]).unwrap().total_bet = convert_to_e8s(5.0);
+
+    let mut busted = table.get_busted_players();
+    busted.sort_by_key(|(uid, _)| uid.0);
+    assert_eq!(
+        busted,
+        vec![
+            (uids[1], convert_to_e8s(1.0)),
+            (uids[2], convert_to_e8s(5.0))
+        ]
+    );
+}
@@ -0,0 +1,217 @@
+use candid::Principal;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        table::{Table, TableConfig, TableId, TableType},
+        tests::{
+            self, balance, fold_to_the_last_player, get_table_config, seat_player,
+            turn_tests::is_it_users_turn,
+        },
+        types::{BetType, DealStage, PlayerAction},
+    },
+    types::{GameType, PublicTable},
+    utils::convert_to_e8s,
+};
+
+/// Seats a player in each of the given seats.
+fn seat_players(table: &mut Table, seats: &[u8]) -> Vec<WalletPrincipalId> {
+    seats
+        .iter()
+        .map(|seat| seat_player(table, *seat, 100.0))
+        .collect()
+}
+
+/// Seats two players at a dedicated heads-up table with $1/$2 blinds.
+fn setup_heads_up_table(table_type: TableType) -> (Table, Vec<WalletPrincipalId>) {
+    tests::setup_table(
+        TableConfig::default_heads_up(GameType::NoLimit(convert_to_e8s(1.0)), table_type),
+        &[100.0; 2],
+    )
+}
+
+#[test]
+fn test_default_heads_up_config() {
+    let config =
+        TableConfig::default_heads_up(GameType::NoLimit(convert_to_e8s(1.0)), TableType::SitAndGo);
+    assert!(config.is_heads_up());
+    assert_eq!(config.table_type, Some(TableType::SitAndGo));
+    assert_eq!(config.enable_rake, Some(false));
+    assert_eq!(config.straddle_type, None);
+
+    let config =
+        TableConfig::default_heads_up(GameType::NoLimit(convert_to_e8s(1.0)), TableType::Cash);
+    assert_eq!(config.seats, 2);
+    assert_eq!(config.enable_rake, Some(true));
+}
+
+#[test]
+fn test_button_posts_the_small_blind_and_acts_first_preflop() {
+    let (mut table, uids) = setup_heads_up_table(TableType::Cash);
+    assert!(table.is_heads_up_hand());
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(table.dealer_position, 0);
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[1]));
+    assert_eq!(balance(&table, uids[0]), convert_to_e8s(99.0));
+    assert_eq!(balance(&table, uids[1]), convert_to_e8s(98.0));
+    assert!(is_it_users_turn(&table, uids[0]));
+
+    let public_table = PublicTable::from(&table);
+    assert!(public_table.is_heads_up_hand());
+    assert_eq!(public_table.get_big_blind_user_index(), Some(1));
+    assert_eq!(public_table.get_big_blind_user_principal(), Some(uids[1]));
+}
+
+#[test]
+fn test_big_blind_acts_first_after_the_flop() {
+    let (mut table, uids) = setup_heads_up_table(TableType::Cash);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(table.bet(uids[0], BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, uids[1]));
+    assert_eq!(table.user_check(uids[1], false), Ok(()));
+
+    // Flop
+    assert_eq!(table.deal_stage, DealStage::Turn);
+    assert_eq!(table.community_cards.len(), 3);
+    assert!(is_it_users_turn(&table, uids[1]));
+    assert_eq!(table.user_check(uids[1], false), Ok(()));
+    assert!(is_it_users_turn(&table, uids[0]));
+    assert_eq!(table.user_check(uids[0], false), Ok(()));
+
+    // Turn
+    assert_eq!(table.deal_stage, DealStage::River);
+    assert!(is_it_users_turn(&table, uids[1]));
+    assert_eq!(
+        table.bet(uids[1], BetType::Raised(convert_to_e8s(4.0))),
+        Ok(())
+    );
+    assert!(is_it_users_turn(&table, uids[0]));
+    assert_eq!(table.bet(uids[0], BetType::Called), Ok(()));
+
+    // River
+    assert_eq!(table.deal_stage, DealStage::Showdown);
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_button_and_blinds_alternate_between_hands() {
+    let (mut table, uids) = setup_heads_up_table(TableType::SitAndGo);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.user_fold(uids[0], false), Ok(()));
+    assert_eq!(balance(&table, uids[1]), convert_to_e8s(101.0));
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.dealer_position, 1);
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[1]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(balance(&table, uids[1]), convert_to_e8s(100.0));
+    assert_eq!(balance(&table, uids[0]), convert_to_e8s(97.0));
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_table_dropping_to_two_players_plays_heads_up() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3),
+        vec![1, 2, 3],
+    );
+    table.config.table_type = Some(TableType::Cash);
+    let uids = seat_players(&mut table, &[0, 1, 2]);
+
+    // Three handed the small blind sits left of the button
+    assert!(!table.is_heads_up_hand());
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[1]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[2]));
+
+    assert!(table.remove_user(uids[2], ActionType::Leave).is_ok());
+    fold_to_the_last_player(&mut table);
+    assert!(table.handle_queue_items().is_ok());
+    assert!(table.is_heads_up_hand());
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.dealer_position, 1);
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[1]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[0]));
+    assert!(is_it_users_turn(&table, uids[1]));
+
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+    assert_eq!(table.user_check(uids[0], false), Ok(()));
+    assert!(is_it_users_turn(&table, uids[0]));
+}
+
+#[test]
+fn test_heads_up_at_a_tournament_final_table() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 6),
+        vec![1, 2, 3],
+    );
+    table.config.table_type = Some(TableType::Tournament {
+        tournament_id: Principal::anonymous(),
+        is_final_table: true,
+    });
+    let uids = seat_players(&mut table, &[2, 5]);
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.dealer_position, 2);
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[1]));
+    assert!(is_it_users_turn(&table, uids[0]));
+
+    fold_to_the_last_player(&mut table);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.dealer_position, 5);
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[1]));
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_player_sitting_out_leaves_two_players_heads_up() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3),
+        vec![1, 2, 3],
+    );
+    table.config.table_type = Some(TableType::Cash);
+    let uids = seat_players(&mut table, &[0, 1, 2]);
+    table
+        .get_user_table_data_mut(uids[2])
+        .unwrap()
+        .player_action = PlayerAction::SittingOut;
+
+    assert!(!table.config.is_heads_up());
+    assert!(table.is_heads_up_hand());
+
+    table.dealer_position = 0;
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[1]));
+
+    table.dealer_position = 1;
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[1]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[0]));
+
+    // The blinds skip the player sitting out on the button
+    table.dealer_position = 2;
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[1]));
+
+    let public_table = PublicTable::from(&table);
+    assert!(public_table.is_heads_up_hand());
+    assert_eq!(public_table.get_big_blind_user_index(), Some(1));
+    assert_eq!(public_table.get_big_blind_user_principal(), Some(uids[1]));
+
+    // Once they are dealt back in the table is three handed again
+    table
+        .get_user_table_data_mut(uids[2])
+        .unwrap()
+        .player_action = PlayerAction::None;
+    assert!(!table.is_heads_up_hand());
+    assert_eq!(table.get_small_blind_user_principal(), Ok(uids[0]));
+    assert_eq!(table.get_big_blind_user_principal(), Ok(uids[1]));
+}
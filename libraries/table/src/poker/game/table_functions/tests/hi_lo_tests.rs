@@ -0,0 +1,306 @@
+use candid::Principal;
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, Suit, Value},
+    game::{
+        table_functions::{
+            action_log::ActionType,
+            table::{Table, TableId},
+            tests::{balance, create_user, get_table_config},
+            types::BetType,
+        },
+        types::GameType,
+        utils::convert_to_e8s,
+    },
+};
+
+/// Seats three players at a hi-lo table and has them all put 6 into the pot.
+fn setup_hi_lo_table(game_type: GameType) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(game_type, 3),
+        vec![1, 2, 3],
+    );
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user3 = create_user(
+        Principal::from_text("bw4dl-smaaa-aaaaa-qaacq-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+    assert!(table.add_user(user3, 2, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+    let player3_uid = table.get_player_at_seat(2).unwrap();
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(
+        table.bet(player1_uid, BetType::Raised(convert_to_e8s(6.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(player2_uid, BetType::Called), Ok(()));
+    assert_eq!(table.bet(player3_uid, BetType::Called), Ok(()));
+
+    (table, vec![player1_uid, player2_uid, player3_uid])
+}
+
+#[test]
+fn test_hi_lo_high_and_low_split_pot() {
+    let (mut table, players) = setup_hi_lo_table(GameType::PotLimitOmahaHiLo4(convert_to_e8s(1.0)));
+
+    // Board: [A♠, 2♦, 7♣, K♥, Q♦]
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Two, Suit::Diamond),
+        Card::new(Value::Seven, Suit::Club),
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::Queen, Suit::Diamond),
+    ];
+
+    // Player 1: [K♠, K♣, 9♦, 9♥] (Three of a kind: Kings, no low)
+    table.get_user_table_data_mut(players[0]).unwrap().cards = vec![
+        Card::new(Value::King, Suit::Spade),
+        Card::new(Value::King, Suit::Club),
+        Card::new(Value::Nine, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Heart),
+    ];
+
+    // Player 2: [3♥, 4♠, J♣, J♦] (Pair of Jacks, 7-4-3-2-A low)
+    table.get_user_table_data_mut(players[1]).unwrap().cards = vec![
+        Card::new(Value::Three, Suit::Heart),
+        Card::new(Value::Four, Suit::Spade),
+        Card::new(Value::Jack, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+    ];
+
+    // Player 3: [T♠, T♥, 9♣, 9♠] (Pair of Tens, no low)
+    table.get_user_table_data_mut(players[2]).unwrap().cards = vec![
+        Card::new(Value::Ten, Suit::Spade),
+        Card::new(Value::Ten, Suit::Heart),
+        Card::new(Value::Nine, Suit::Club),
+        Card::new(Value::Nine, Suit::Spade),
+    ];
+
+    table.showdown().unwrap();
+
+    // Pot of 18 split 9 high / 9 low
+    assert_eq!(balance(&table, players[0]), convert_to_e8s(103.0));
+    assert_eq!(balance(&table, players[1]), convert_to_e8s(103.0));
+    assert_eq!(balance(&table, players[2]), convert_to_e8s(94.0));
+}
+
+#[test]
+fn test_hi_lo_no_qualifying_low_high_scoops() {
+    let (mut table, players) = setup_hi_lo_table(GameType::PotLimitOmahaHiLo4(convert_to_e8s(1.0)));
+
+    // Board: [A♠, 9♦, T♣, K♥, Q♦] (only one low card, no low possible)
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Nine, Suit::Diamond),
+        Card::new(Value::Ten, Suit::Club),
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::Queen, Suit::Diamond),
+    ];
+
+    // Player 1: [J♠, K♣, 3♦, 4♥] (Broadway straight)
+    table.get_user_table_data_mut(players[0]).unwrap().cards = vec![
+        Card::new(Value::Jack, Suit::Spade),
+        Card::new(Value::King, Suit::Club),
+        Card::new(Value::Three, Suit::Diamond),
+        Card::new(Value::Four, Suit::Heart),
+    ];
+
+    // Player 2: [2♥, 3♠, 4♣, 5♦] (Would be a low with a better board)
+    table.get_user_table_data_mut(players[1]).unwrap().cards = vec![
+        Card::new(Value::Two, Suit::Heart),
+        Card::new(Value::Three, Suit::Spade),
+        Card::new(Value::Four, Suit::Club),
+        Card::new(Value::Five, Suit::Diamond),
+    ];
+
+    // Player 3: [T♠, T♥, 8♣, 8♠] (Three of a kind: Tens)
+    table.get_user_table_data_mut(players[2]).unwrap().cards = vec![
+        Card::new(Value::Ten, Suit::Spade),
+        Card::new(Value::Ten, Suit::Heart),
+        Card::new(Value::Eight, Suit::Club),
+        Card::new(Value::Eight, Suit::Spade),
+    ];
+
+    table.showdown().unwrap();
+
+    assert_eq!(balance(&table, players[0]), convert_to_e8s(112.0));
+    assert_eq!(balance(&table, players[1]), convert_to_e8s(94.0));
+    assert_eq!(balance(&table, players[2]), convert_to_e8s(94.0));
+}
+
+#[test]
+fn test_hi_lo_scoop_with_wheel() {
+    let (mut table, players) = setup_hi_lo_table(GameType::PotLimitOmahaHiLo4(convert_to_e8s(1.0)));
+
+    // Board: [A♠, 2♦, 3♣, K♥, Q♦]
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Two, Suit::Diamond),
+        Card::new(Value::Three, Suit::Club),
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::Queen, Suit::Diamond),
+    ];
+
+    // Player 1: [4♠, 5♣, 9♦, 9♥] (Wheel straight and the nut low)
+    table.get_user_table_data_mut(players[0]).unwrap().cards = vec![
+        Card::new(Value::Four, Suit::Spade),
+        Card::new(Value::Five, Suit::Club),
+        Card::new(Value::Nine, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Heart),
+    ];
+
+    // Player 2: [6♥, 8♠, J♣, J♦] (Pair of Jacks, 8-6-3-2-A low)
+    table.get_user_table_data_mut(players[1]).unwrap().cards = vec![
+        Card::new(Value::Six, Suit::Heart),
+        Card::new(Value::Eight, Suit::Spade),
+        Card::new(Value::Jack, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+    ];
+
+    // Player 3: [T♠, T♥, 9♣, 9♠] (Pair of Tens, no low)
+    table.get_user_table_data_mut(players[2]).unwrap().cards = vec![
+        Card::new(Value::Ten, Suit::Spade),
+        Card::new(Value::Ten, Suit::Heart),
+        Card::new(Value::Nine, Suit::Club),
+        Card::new(Value::Nine, Suit::Spade),
+    ];
+
+    table.showdown().unwrap();
+
+    assert_eq!(balance(&table, players[0]), convert_to_e8s(112.0));
+    assert_eq!(balance(&table, players[1]), convert_to_e8s(94.0));
+    assert_eq!(balance(&table, players[2]), convert_to_e8s(94.0));
+}
+
+#[test]
+fn test_hi_lo_quartered_low() {
+    let (mut table, players) = setup_hi_lo_table(GameType::PotLimitOmahaHiLo4(convert_to_e8s(1.0)));
+
+    // Board: [A♠, 2♦, 7♣, K♥, K♦]
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Two, Suit::Diamond),
+        Card::new(Value::Seven, Suit::Club),
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Diamond),
+    ];
+
+    // Player 1: [A♣, A♥, 9♦, 9♥] (Full house: Aces over Kings, no low)
+    table.get_user_table_data_mut(players[0]).unwrap().cards = vec![
+        Card::new(Value::Ace, Suit::Club),
+        Card::new(Value::Ace, Suit::Heart),
+        Card::new(Value::Nine, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Heart),
+    ];
+
+    // Player 2: [3♥, 4♠, J♣, J♦] (7-4-3-2-A low)
+    table.get_user_table_data_mut(players[1]).unwrap().cards = vec![
+        Card::new(Value::Three, Suit::Heart),
+        Card::new(Value::Four, Suit::Spade),
+        Card::new(Value::Jack, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+    ];
+
+    // Player 3: [3♠, 4♣, Q♠, Q♥] (7-4-3-2-A low)
+    table.get_user_table_data_mut(players[2]).unwrap().cards = vec![
+        Card::new(Value::Three, Suit::Spade),
+        Card::new(Value::Four, Suit::Club),
+        Card::new(Value::Queen, Suit::Spade),
+        Card::new(Value::Queen, Suit::Heart),
+    ];
+
+    table.showdown().unwrap();
+
+    // Pot of 18: 9 to the high, the low half is quartered 4.5 / 4.5
+    assert_eq!(balance(&table, players[0]), convert_to_e8s(103.0));
+    assert_eq!(balance(&table, players[1]), convert_to_e8s(98.5));
+    assert_eq!(balance(&table, players[2]), convert_to_e8s(98.5));
+}
+
+#[test]
+fn test_hi_lo_main_pot_log_shows_both_halves() {
+    let (mut table, players) = setup_hi_lo_table(GameType::PotLimitOmahaHiLo5(convert_to_e8s(1.0)));
+
+    // Board: [A♠, 2♦, 7♣, K♥, Q♦]
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Two, Suit::Diamond),
+        Card::new(Value::Seven, Suit::Club),
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::Queen, Suit::Diamond),
+    ];
+
+    // Player 1: [K♠, K♣, 9♦, 9♥, T♦] (Three of a kind: Kings, no low)
+    table.get_user_table_data_mut(players[0]).unwrap().cards = vec![
+        Card::new(Value::King, Suit::Spade),
+        Card::new(Value::King, Suit::Club),
+        Card::new(Value::Nine, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Heart),
+        Card::new(Value::Ten, Suit::Diamond),
+    ];
+
+    // Player 2: [3♥, 4♠, J♣, J♦, T♣] (Pair of Jacks, 7-4-3-2-A low)
+    table.get_user_table_data_mut(players[1]).unwrap().cards = vec![
+        Card::new(Value::Three, Suit::Heart),
+        Card::new(Value::Four, Suit::Spade),
+        Card::new(Value::Jack, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+        Card::new(Value::Ten, Suit::Club),
+    ];
+
+    // Player 3: [5♠, 6♥, 9♣, 9♠, T♥] (Pair of Nines, 7-6-5-2-A low)
+    table.get_user_table_data_mut(players[2]).unwrap().cards = vec![
+        Card::new(Value::Five, Suit::Spade),
+        Card::new(Value::Six, Suit::Heart),
+        Card::new(Value::Nine, Suit::Club),
+        Card::new(Value::Nine, Suit::Spade),
+        Card::new(Value::Ten, Suit::Heart),
+    ];
+
+    table.showdown().unwrap();
+
+    let main_pot_log = table
+        .action_logs
+        .iter()
+        .find_map(|log| match &log.action_type {
+            ActionType::PlayersHandsRankedMainPot { hands, low_hands } => {
+                Some((hands.clone(), low_hands.clone()))
+            }
+            _ => None,
+        })
+        .expect("Main pot should be logged for hi-lo games");
+
+    let (hands, low_hands) = main_pot_log;
+    let low_hands = low_hands.expect("Hi-lo main pot log should include the low half");
+
+    assert_eq!(hands.len(), 3);
+    assert_eq!(hands[0].2, convert_to_e8s(9.0));
+    assert_eq!(hands[1].2, 0);
+    assert_eq!(hands[2].2, 0);
+
+    // Only the two qualifying lows are listed, best first.
+    assert_eq!(low_hands.len(), 2);
+    assert_eq!(low_hands[0].2, convert_to_e8s(9.0));
+    assert_eq!(low_hands[1].2, 0);
+    assert_eq!(
+        low_hands[0].0,
+        table.users.get(&players[1]).unwrap().user_name
+    );
+}
@@ -1,9 +1,9 @@
 use candid::Principal;
-use user::user::{User, UserBalance, UsersCanisterId};
+use user::user::{User, UserBalance, UsersCanisterId, WalletPrincipalId};
 
-use crate::poker::game::types::GameType;
+use crate::poker::game::{types::GameType, utils::convert_to_e8s};
 
-use super::table::TableConfig;
+use super::table::{Table, TableConfig, TableId};
 
 pub mod action_logs;
 
@@ -13,26 +13,68 @@ pub mod all_in_tests_side_pot;
 
 pub mod ante_tests;
 
+pub mod auto_rebuy_tests;
+
 pub mod betting_order;
 
+pub mod bomb_pot_tests;
+
+pub mod buy_in_tests;
+
+pub mod chip_conservation_tests;
+
+pub mod dead_button_tests;
+
+pub mod disconnect_protection_tests;
+
+pub mod equity_tests;
+
 pub mod fixed_limit_tests;
 
 pub mod general_tests;
 
+pub mod hand_for_hand_tests;
+
+pub mod heads_up_tests;
+
+pub mod hi_lo_tests;
+
 pub mod no_limit_tests;
 
+pub mod odd_chip_tests;
+
 pub mod plo_tests;
 
+pub mod post_hand_tests;
+
 pub mod pot_distribution_tests;
 
 pub mod pot_limit;
 
+pub mod pre_action_tests;
+
 pub mod rake;
 
+pub mod rng_archive_tests;
+
+pub mod run_it_tests;
+
+pub mod short_deck_tests;
+
+pub mod shuffle_seed_tests;
+
 pub mod spread_limit_tests;
 
+pub mod straddle_tests;
+
+pub mod stud_tests;
+
+pub mod time_bank_tests;
+
 pub mod turn_tests;
 
+pub mod waitlist_tests;
+
 pub fn create_user(canister_id: Principal, balance: u64) -> User {
     User::new(
         user::user::WalletPrincipalId(canister_id),
@@ -67,5 +109,52 @@ pub fn get_table_config(game_type: GameType, seats: u8) -> TableConfig {
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )
 }
+
+/// Seats a player with a stack of `stack` in `seat`.
+///
+/// The player in seat `n` always has the principal `[n + 1]`.
+pub fn seat_player(table: &mut Table, seat: u8, stack: f64) -> WalletPrincipalId {
+    let user = create_user(Principal::from_slice(&[seat + 1]), convert_to_e8s(stack));
+    let uid = user.principal_id;
+    assert!(table.add_user(user, seat, false).is_ok());
+    uid
+}
+
+/// Creates a table with `config` and seats a player in each of the first seats,
+/// one for each of the `stacks`.
+pub fn setup_table(config: TableConfig, stacks: &[f64]) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(TableId(Principal::anonymous()), config, vec![1, 2, 3]);
+    let uids = stacks
+        .iter()
+        .enumerate()
+        .map(|(seat, stack)| seat_player(&mut table, seat as u8, *stack))
+        .collect();
+    (table, uids)
+}
+
+pub fn current_player(table: &Table) -> WalletPrincipalId {
+    table
+        .get_player_at_seat(table.current_player_index)
+        .unwrap()
+}
+
+pub fn balance(table: &Table, uid: WalletPrincipalId) -> u64 {
+    table.users.get(&uid).unwrap().balance.0
+}
+
+/// Folds every player in turn until the hand is over.
+pub fn fold_to_the_last_player(table: &mut Table) {
+    while table.is_game_ongoing() {
+        let uid = current_player(table);
+        assert_eq!(table.user_fold(uid, false), Ok(()));
+    }
+}
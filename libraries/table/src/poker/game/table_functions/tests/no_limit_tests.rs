@@ -40,7 +40,7 @@ fn test_correct_blinds_no_limit() {
             .get(&user1.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(2.0)
+        convert_to_e8s(1.0)
     );
     assert_eq!(
         table
@@ -48,7 +48,7 @@ fn test_correct_blinds_no_limit() {
             .get(&user2.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(1.0)
+        convert_to_e8s(2.0)
     );
 }
 
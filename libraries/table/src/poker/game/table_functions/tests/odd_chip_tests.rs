@@ -0,0 +1,246 @@
+use candid::Principal;
+use proptest::prelude::*;
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, Suit, Value},
+    game::{
+        table_functions::{
+            side_pot::SidePot,
+            table::{Pot, Table, TableId},
+            tests::{create_user, get_table_config},
+            types::PlayerAction,
+        },
+        types::GameType,
+        utils::convert_to_e8s,
+    },
+};
+
+/// Ace king offsuit, all four tie for ace high on `board()`.
+fn winning_hands() -> [[Card; 2]; 4] {
+    [
+        [
+            Card::new(Value::Ace, Suit::Spade),
+            Card::new(Value::King, Suit::Heart),
+        ],
+        [
+            Card::new(Value::Ace, Suit::Heart),
+            Card::new(Value::King, Suit::Spade),
+        ],
+        [
+            Card::new(Value::Ace, Suit::Diamond),
+            Card::new(Value::King, Suit::Club),
+        ],
+        [
+            Card::new(Value::Ace, Suit::Club),
+            Card::new(Value::King, Suit::Diamond),
+        ],
+    ]
+}
+
+/// Queen three offsuit, all four tie for queen high on `board()`.
+fn losing_hands() -> [[Card; 2]; 4] {
+    [
+        [
+            Card::new(Value::Queen, Suit::Spade),
+            Card::new(Value::Three, Suit::Heart),
+        ],
+        [
+            Card::new(Value::Queen, Suit::Heart),
+            Card::new(Value::Three, Suit::Spade),
+        ],
+        [
+            Card::new(Value::Queen, Suit::Diamond),
+            Card::new(Value::Three, Suit::Club),
+        ],
+        [
+            Card::new(Value::Queen, Suit::Club),
+            Card::new(Value::Three, Suit::Diamond),
+        ],
+    ]
+}
+
+/// A board without a possible straight or flush: [2♣, 7♦, 9♠, J♥, 4♣]
+fn board() -> Vec<Card> {
+    vec![
+        Card::new(Value::Two, Suit::Club),
+        Card::new(Value::Seven, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Spade),
+        Card::new(Value::Jack, Suit::Heart),
+        Card::new(Value::Four, Suit::Club),
+    ]
+}
+
+/// Seats a player with no chips behind for each entry of `is_winner`, ready for the showdown.
+fn setup_showdown_table(is_winner: &[bool], button: usize) -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(
+            GameType::NoLimit(convert_to_e8s(1.0)),
+            is_winner.len() as u8,
+        ),
+        vec![1, 2, 3],
+    );
+
+    let mut uids = Vec::new();
+    let (mut winners, mut losers) = (0, 0);
+    for (seat, is_winner) in is_winner.iter().enumerate() {
+        let user = create_user(Principal::from_slice(&[seat as u8 + 1]), 0);
+        assert!(table.add_user(user, seat as u8, false).is_ok());
+        let uid = table.get_player_at_seat(seat).unwrap();
+
+        let user_table_data = table.get_user_table_data_mut(uid).unwrap();
+        user_table_data.player_action = PlayerAction::Called;
+        user_table_data.cards = if *is_winner {
+            winners += 1;
+            winning_hands()[winners - 1].to_vec()
+        } else {
+            losers += 1;
+            losing_hands()[losers - 1].to_vec()
+        };
+        uids.push(uid);
+    }
+
+    table.dealer_position = button % is_winner.len();
+    table.community_cards = board();
+    (table, uids)
+}
+
+fn total_balance(table: &Table, uids: &[WalletPrincipalId]) -> u64 {
+    uids.iter()
+        .map(|uid| table.users.get(uid).unwrap().balance.0)
+        .sum()
+}
+
+/// Between 2 and 8 players with at most four winning and four losing hands.
+fn players() -> impl Strategy<Value = Vec<bool>> {
+    prop::collection::vec(any::<bool>(), 2..=8).prop_filter("at most four of each hand", |p| {
+        p.iter().filter(|w| **w).count() <= 4 && p.iter().filter(|w| !**w).count() <= 4
+    })
+}
+
+#[test]
+fn test_odd_chip_goes_to_first_winner_left_of_button() {
+    // Seats 0, 1 and 3 tie, seat 2 loses and seat 1 has the button
+    let (mut table, uids) = setup_showdown_table(&[true, true, false, true], 1);
+    table.pot = Pot(11);
+
+    table.showdown().unwrap();
+
+    // 11 / 3 = 3 each, the remaining 2 go to seat 3, the first winner left of the button
+    assert_eq!(table.users.get(&uids[0]).unwrap().balance.0, 3);
+    assert_eq!(table.users.get(&uids[1]).unwrap().balance.0, 3);
+    assert_eq!(table.users.get(&uids[2]).unwrap().balance.0, 0);
+    assert_eq!(table.users.get(&uids[3]).unwrap().balance.0, 5);
+}
+
+#[test]
+fn test_odd_chip_wraps_around_the_table() {
+    // The button is on the last seat, so seat 0 is first to its left
+    let (mut table, uids) = setup_showdown_table(&[true, false, true], 2);
+    table.pot = Pot(7);
+
+    table.showdown().unwrap();
+
+    assert_eq!(table.users.get(&uids[0]).unwrap().balance.0, 4);
+    assert_eq!(table.users.get(&uids[2]).unwrap().balance.0, 3);
+}
+
+#[test]
+fn test_odd_chip_per_side_pot() {
+    let (mut table, uids) = setup_showdown_table(&[true, true, true], 0);
+    table.pot = Pot(5);
+    // Only seats 0 and 2 are in the side pot, seat 2 is first left of the button
+    table.side_pots = vec![SidePot {
+        pot: 3,
+        confirmed_pot: 3,
+        user_principals: vec![uids[0], uids[2]],
+        highest_bet: 0,
+    }];
+
+    table.showdown().unwrap();
+
+    // Main pot: 1 each and 2 odd chips to seat 1, side pot: 1 each and 1 odd chip to seat 2
+    assert_eq!(table.users.get(&uids[0]).unwrap().balance.0, 2);
+    assert_eq!(table.users.get(&uids[1]).unwrap().balance.0, 3);
+    assert_eq!(table.users.get(&uids[2]).unwrap().balance.0, 3);
+}
+
+proptest! {
+    #[test]
+    fn prop_payouts_plus_rake_equal_pot(
+        is_winner in players(),
+        button in 0usize..8,
+        main_pot in 0u64..1_000_000_000_000,
+        side_pots in prop::collection::vec((1u64..1_000_000_000_000, any::<u8>()), 0..4),
+        enable_rake in any::<bool>(),
+        runs in 1usize..=3,
+    ) {
+        let (mut table, uids) = setup_showdown_table(&is_winner, button);
+        table.config.enable_rake = Some(enable_rake);
+        table.pot = Pot(main_pot);
+
+        let mut total_pot = main_pot;
+        for (amount, contributors) in side_pots {
+            let mut user_principals: Vec<WalletPrincipalId> = uids
+                .iter()
+                .enumerate()
+                .filter(|(seat, _)| contributors & (1 << seat) != 0)
+                .map(|(_, uid)| *uid)
+                .collect();
+            if user_principals.is_empty() {
+                user_principals.push(uids[0]);
+            }
+            table.side_pots.push(SidePot {
+                pot: amount,
+                confirmed_pot: amount,
+                user_principals,
+                highest_bet: 0,
+            });
+            total_pot += amount;
+        }
+
+        table.showdown_boards(vec![board(); runs]).unwrap();
+
+        let rake = table.rake_total.unwrap_or(0);
+        prop_assert_eq!(total_balance(&table, &uids) + rake, total_pot);
+        if !enable_rake {
+            prop_assert_eq!(rake, 0);
+        }
+    }
+
+    #[test]
+    fn prop_tied_winners_differ_only_by_the_odd_chips(
+        is_winner in players(),
+        button in 0usize..8,
+        main_pot in 0u64..1_000_000_000_000,
+    ) {
+        let (mut table, uids) = setup_showdown_table(&is_winner, button);
+        table.pot = Pot(main_pot);
+
+        table.showdown().unwrap();
+
+        let button = button % uids.len();
+        let winners: Vec<usize> = (0..uids.len())
+            .filter(|seat| is_winner[*seat] || !is_winner.contains(&true))
+            .collect();
+        let share = main_pot / winners.len() as u64;
+        let odd_chips = main_pot % winners.len() as u64;
+        let first_left_of_button = *winners
+            .iter()
+            .min_by_key(|seat| (**seat + uids.len() - button - 1) % uids.len())
+            .unwrap();
+
+        for (seat, uid) in uids.iter().enumerate() {
+            let balance = table.users.get(uid).unwrap().balance.0;
+            let expected = if seat == first_left_of_button {
+                share + odd_chips
+            } else if winners.contains(&seat) {
+                share
+            } else {
+                0
+            };
+            prop_assert_eq!(balance, expected);
+        }
+    }
+}
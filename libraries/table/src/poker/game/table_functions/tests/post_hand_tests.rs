@@ -0,0 +1,268 @@
+use errors::{game_error::GameError, traced_error::TracedError};
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::Card,
+    game::{
+        table_functions::{
+            action_log::ActionType,
+            table::Table,
+            tests::{self, balance, current_player, get_table_config},
+            types::BetType,
+        },
+        types::{CardProvenance, GameType},
+        utils::convert_to_e8s,
+    },
+};
+
+/// Starts a hand on a two seat table with $1/$2 blinds.
+fn setup_heads_up_table() -> (Table, Vec<WalletPrincipalId>) {
+    let (mut table, uids) = tests::setup_table(
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 2),
+        &[100.0; 2],
+    );
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    (table, uids)
+}
+
+/// Records a provenance entry for the hole cards and every card left in the deck.
+fn add_card_provenance(table: &mut Table) {
+    let mut cards: Vec<(Card, Option<WalletPrincipalId>)> = table
+        .user_table_data
+        .iter()
+        .flat_map(|(uid, data)| data.cards.iter().map(|card| (*card, Some(*uid))))
+        .collect();
+    cards.extend(table.deck.cards().iter().map(|card| (*card, None)));
+
+    for (position, (card, dealt_to)) in cards.into_iter().enumerate() {
+        let card_hash = format!("{}-{:?}-{:?}", table.round_ticker, card.value, card.suit);
+        table.card_provenance.insert(
+            card_hash.clone(),
+            CardProvenance {
+                round_id: table.round_ticker,
+                card,
+                original_position: position as u8,
+                shuffled_position: position as u8,
+                card_hash,
+                dealt_to,
+                dealt_at_stage: None,
+                dealt_in_run: None,
+                is_revealed: None,
+            },
+        );
+    }
+}
+
+fn hole_cards(table: &Table, uid: WalletPrincipalId) -> Vec<Card> {
+    table.get_user_table_data(uid).unwrap().cards.clone()
+}
+
+fn is_not_allowed(res: Result<(), TracedError<GameError>>) -> bool {
+    matches!(
+        res.map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    )
+}
+
+#[test]
+fn test_board_is_not_dealt_when_everyone_folds() {
+    let (mut table, uids) = setup_heads_up_table();
+    let folding_uid = current_player(&table);
+    let winner_uid = *uids.iter().find(|uid| **uid != folding_uid).unwrap();
+    assert!(!table.is_post_hand_window_open());
+
+    assert_eq!(table.user_fold(folding_uid, false), Ok(()));
+
+    assert!(table.is_post_hand_window_open());
+    assert!(table.community_cards.is_empty());
+    assert_eq!(table.deck.len(), 48);
+    assert_eq!(balance(&table, winner_uid), convert_to_e8s(101.0));
+}
+
+#[test]
+fn test_rabbit_hunt_reveals_the_rest_of_the_board() {
+    let (mut table, uids) = setup_heads_up_table();
+    assert!(is_not_allowed(table.rabbit_hunt(uids[0])));
+    let folding_uid = current_player(&table);
+    assert_eq!(table.user_fold(folding_uid, false), Ok(()));
+    add_card_provenance(&mut table);
+
+    // The flop, turn and river each come after a burnt card
+    let mut deck = table.deck.cards().to_vec();
+    let expected: Vec<Card> = [2, 3, 4, 6, 8]
+        .iter()
+        .map(|i| deck[deck.len() - i])
+        .collect();
+    assert_eq!(table.rabbit_hunt(folding_uid), Ok(()));
+
+    assert_eq!(table.rabbit_hunt_cards, Some(expected.clone()));
+    assert!(table.community_cards.is_empty());
+    deck.truncate(deck.len() - 8);
+    assert_eq!(table.deck.cards(), deck.as_slice());
+    assert!(table
+        .action_logs
+        .iter()
+        .any(|log| log.user_principal == Some(folding_uid)
+            && log.action_type
+                == ActionType::RabbitHunt {
+                    community_cards: expected.clone(),
+                }));
+
+    assert!(is_not_allowed(table.rabbit_hunt(uids[0])));
+
+    // The next hand starts with a fresh board
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.rabbit_hunt_cards, None);
+}
+
+#[test]
+fn test_rabbit_hunt_after_the_flop() {
+    let (mut table, _) = setup_heads_up_table();
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    let uid = current_player(&table);
+    assert_eq!(table.user_check(uid, false), Ok(()));
+    assert_eq!(table.community_cards.len(), 3);
+
+    let uid = current_player(&table);
+    assert_eq!(table.user_fold(uid, false), Ok(()));
+    assert_eq!(table.community_cards.len(), 3);
+
+    assert_eq!(table.rabbit_hunt(uid), Ok(()));
+    assert_eq!(table.rabbit_hunt_cards.as_ref().map(Vec::len), Some(2));
+}
+
+#[test]
+fn test_winner_shows_one_card() {
+    let (mut table, uids) = setup_heads_up_table();
+    let folding_uid = current_player(&table);
+    let winner_uid = *uids.iter().find(|uid| **uid != folding_uid).unwrap();
+    assert!(is_not_allowed(
+        table.show_or_muck_cards(winner_uid, vec![true, false])
+    ));
+    assert_eq!(table.user_fold(folding_uid, false), Ok(()));
+    add_card_provenance(&mut table);
+
+    // Nobody sees the cards of an uncontested hand
+    let mut others_view = table.clone();
+    assert!(others_view.hide_cards(folding_uid).is_ok());
+    assert!(hole_cards(&others_view, winner_uid).is_empty());
+
+    assert!(is_not_allowed(
+        table.show_or_muck_cards(winner_uid, vec![true])
+    ));
+    assert_eq!(
+        table.show_or_muck_cards(winner_uid, vec![true, false]),
+        Ok(())
+    );
+
+    let cards = hole_cards(&table, winner_uid);
+    let mut others_view = table.clone();
+    assert!(others_view.hide_cards(folding_uid).is_ok());
+    assert_eq!(hole_cards(&others_view, winner_uid), vec![cards[0]]);
+    assert!(table
+        .action_logs
+        .iter()
+        .any(|log| log.user_principal == Some(winner_uid)
+            && log.action_type
+                == ActionType::ShowCards {
+                    cards: vec![cards[0]],
+                    mucked: 1,
+                }));
+}
+
+#[test]
+fn test_shown_down_cards_cannot_be_mucked() {
+    let (mut table, uids) = setup_heads_up_table();
+    let uid = current_player(&table);
+    assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_check(uid, false), Ok(()));
+    }
+    assert_eq!(table.community_cards.len(), 5);
+
+    // Both hands are tabled
+    let mut others_view = table.clone();
+    assert!(others_view.hide_cards(uids[0]).is_ok());
+    assert_eq!(hole_cards(&others_view, uids[1]).len(), 2);
+
+    assert!(is_not_allowed(
+        table.show_or_muck_cards(uids[1], vec![false, true])
+    ));
+    assert!(is_not_allowed(table.rabbit_hunt(uids[1])));
+}
+
+#[test]
+fn test_shown_cards_must_match_their_provenance() {
+    let (mut table, uids) = setup_heads_up_table();
+    let folding_uid = current_player(&table);
+    assert_eq!(table.user_fold(folding_uid, false), Ok(()));
+    add_card_provenance(&mut table);
+
+    // The provenance says the card went to the other player
+    let other_uid = *uids.iter().find(|uid| **uid != folding_uid).unwrap();
+    let card = hole_cards(&table, folding_uid)[0];
+    for provenance in table.card_provenance.values_mut() {
+        if provenance.card == card {
+            provenance.dealt_to = Some(other_uid);
+        }
+    }
+
+    assert!(matches!(
+        table
+            .show_or_muck_cards(folding_uid, vec![true, true])
+            .map_err(|e| e.into_inner()),
+        Err(GameError::Other(_))
+    ));
+    assert_eq!(
+        table.show_or_muck_cards(folding_uid, vec![false, true]),
+        Ok(())
+    );
+}
+
+fn is_public_after_hand(table: &Table, card: Card) -> bool {
+    let provenance = table
+        .card_provenance
+        .values()
+        .find(|provenance| provenance.card == card)
+        .unwrap();
+    table.is_card_public_after_hand(provenance)
+}
+
+#[test]
+fn test_mucked_cards_stay_private() {
+    let (mut table, uids) = setup_heads_up_table();
+    table.config.keep_mucked_cards_private = Some(true);
+    let folding_uid = current_player(&table);
+    let winner_uid = *uids.iter().find(|uid| **uid != folding_uid).unwrap();
+    assert_eq!(table.user_fold(folding_uid, false), Ok(()));
+    add_card_provenance(&mut table);
+    assert_eq!(
+        table.show_or_muck_cards(winner_uid, vec![true, false]),
+        Ok(())
+    );
+
+    // Only the shown card is public, not the mucked ones or the rest of the deck
+    let cards = hole_cards(&table, winner_uid);
+    assert!(is_public_after_hand(&table, cards[0]));
+    assert!(!is_public_after_hand(&table, cards[1]));
+    assert!(hole_cards(&table, folding_uid)
+        .into_iter()
+        .all(|card| !is_public_after_hand(&table, card)));
+    let burnt_card = *table.deck.cards().last().unwrap();
+    assert!(!is_public_after_hand(&table, burnt_card));
+
+    // The rabbit hunted board was seen by everyone, the burnt cards were not
+    assert_eq!(table.rabbit_hunt(folding_uid), Ok(()));
+    let rabbit_hunt_cards = table.rabbit_hunt_cards.clone().unwrap();
+    assert!(rabbit_hunt_cards
+        .into_iter()
+        .all(|card| is_public_after_hand(&table, card)));
+    assert!(!is_public_after_hand(&table, burnt_card));
+
+    // Other tables reveal the whole deck after the hand
+    table.config.keep_mucked_cards_private = None;
+    assert!(is_public_after_hand(&table, cards[1]));
+    assert!(is_public_after_hand(&table, burnt_card));
+}
@@ -7,7 +7,7 @@ use crate::poker::{
         table_functions::{
             table::{Table, TableId},
             tests::{create_user, get_table_config, turn_tests::is_it_users_turn},
-            types::{BetType, DealStage, SeatStatus},
+            types::{BetType, DealStage, PlayerAction, SeatStatus},
         },
         types::GameType,
         utils::convert_to_e8s,
@@ -750,3 +750,55 @@ fn test_correct_pot_side_pot_distribution() {
 
     assert_eq!(table.side_pots.len(), 0);
 }
+
+#[test]
+fn test_sitting_out_blind_is_dead_money_in_main_pot() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3),
+        vec![1, 2, 3],
+    );
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user3 = create_user(
+        Principal::from_text("bw4dl-smaaa-aaaaa-qaacq-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1.clone(), 0, false).is_ok());
+    assert!(table.add_user(user2.clone(), 1, false).is_ok());
+    assert!(table.add_user(user3.clone(), 2, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+    let player3_uid = table.get_player_at_seat(2).unwrap();
+
+    // Player 1 posted a blind of 1 and then sat out; players 2 and 3 put in 2 each
+    let data = table.get_user_table_data_mut(player1_uid).unwrap();
+    data.player_action = PlayerAction::SittingOut;
+    data.current_total_bet = convert_to_e8s(1.0);
+    for uid in [player2_uid, player3_uid] {
+        let data = table.get_user_table_data_mut(uid).unwrap();
+        data.player_action = PlayerAction::Called;
+        data.current_total_bet = convert_to_e8s(2.0);
+    }
+
+    table.calculate_pots().unwrap();
+
+    // The blind stays in the main pot even though player 1 can no longer win it
+    assert_eq!(table.pot.0, convert_to_e8s(5.0));
+    let data = table.get_user_table_data(player1_uid).unwrap();
+    assert_eq!(data.current_total_bet, 0);
+    assert_eq!(data.total_bet, convert_to_e8s(1.0));
+    assert!(table
+        .side_pots
+        .iter()
+        .all(|side_pot| !side_pot.user_principals.contains(&player1_uid)));
+}
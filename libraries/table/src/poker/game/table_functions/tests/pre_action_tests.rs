@@ -0,0 +1,176 @@
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        pre_action::PreAction,
+        table::Table,
+        tests::{self, get_table_config, turn_tests::is_it_users_turn},
+        types::{BetType, PlayerAction},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+/// Starts a hand on a four seat table with $1/$2 blinds.
+///
+/// The first player is on the button and the last one acts first.
+fn setup_table() -> (Table, Vec<WalletPrincipalId>) {
+    let (mut table, uids) = tests::setup_table(
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 4),
+        &[100.0; 4],
+    );
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert!(is_it_users_turn(&table, uids[3]));
+    (table, uids)
+}
+
+fn pre_action(table: &Table, uid: WalletPrincipalId) -> Option<PreAction> {
+    table.get_user_table_data(uid).unwrap().pre_action
+}
+
+fn current_total_bet(table: &Table, uid: WalletPrincipalId) -> u64 {
+    table.get_user_table_data(uid).unwrap().current_total_bet
+}
+
+#[test]
+fn test_call_any_is_taken_at_the_players_turn() {
+    let (mut table, uids) = setup_table();
+    assert_eq!(
+        table.set_user_pre_action(uids[0], Some(PreAction::CallAny)),
+        Ok(())
+    );
+
+    assert_eq!(
+        table.bet(uids[3], BetType::Raised(convert_to_e8s(6.0))),
+        Ok(())
+    );
+
+    assert_eq!(current_total_bet(&table, uids[0]), convert_to_e8s(6.0));
+    assert_eq!(pre_action(&table, uids[0]), None);
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_call_is_cancelled_by_a_raise() {
+    let (mut table, uids) = setup_table();
+    assert_eq!(
+        table.set_user_pre_action(uids[0], Some(PreAction::Call(convert_to_e8s(2.0)))),
+        Ok(())
+    );
+
+    assert_eq!(
+        table.bet(uids[3], BetType::Raised(convert_to_e8s(6.0))),
+        Ok(())
+    );
+
+    // The price changed, so the player decides again
+    assert_eq!(pre_action(&table, uids[0]), None);
+    assert_eq!(current_total_bet(&table, uids[0]), 0);
+    assert!(is_it_users_turn(&table, uids[0]));
+}
+
+#[test]
+fn test_check_fold_folds_to_a_bet() {
+    let (mut table, uids) = setup_table();
+    assert_eq!(
+        table.set_user_pre_action(uids[1], Some(PreAction::CheckFold)),
+        Ok(())
+    );
+
+    assert_eq!(table.bet(uids[3], BetType::Called), Ok(()));
+    assert_eq!(table.bet(uids[0], BetType::Called), Ok(()));
+
+    let user_table_data = table.get_user_table_data(uids[1]).unwrap();
+    assert_eq!(user_table_data.player_action, PlayerAction::Folded);
+    assert_eq!(user_table_data.pre_action, None);
+    assert!(is_it_users_turn(&table, uids[2]));
+}
+
+#[test]
+fn test_pre_actions_are_taken_one_after_another() {
+    let (mut table, uids) = setup_table();
+    for (uid, action) in [
+        (uids[0], PreAction::CallAny),
+        (uids[1], PreAction::Call(convert_to_e8s(2.0))),
+        (uids[2], PreAction::Check),
+    ] {
+        assert_eq!(table.set_user_pre_action(uid, Some(action)), Ok(()));
+    }
+
+    assert_eq!(table.bet(uids[3], BetType::Called), Ok(()));
+
+    // Everyone called or checked, so the flop is dealt
+    assert_eq!(table.community_cards.len(), 3);
+    for uid in uids.iter() {
+        assert_eq!(current_total_bet(&table, *uid), 0);
+        assert_eq!(pre_action(&table, *uid), None);
+    }
+    assert_eq!(table.pot.0, convert_to_e8s(8.0));
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_raise_to_is_taken_at_the_players_turn() {
+    let (mut table, uids) = setup_table();
+    assert_eq!(
+        table.set_user_pre_action(uids[0], Some(PreAction::RaiseTo(convert_to_e8s(8.0)))),
+        Ok(())
+    );
+
+    assert_eq!(table.bet(uids[3], BetType::Called), Ok(()));
+
+    assert_eq!(current_total_bet(&table, uids[0]), convert_to_e8s(8.0));
+    assert_eq!(table.highest_bet, convert_to_e8s(8.0));
+    assert!(is_it_users_turn(&table, uids[1]));
+}
+
+#[test]
+fn test_pre_action_must_match_the_current_bet() {
+    let (mut table, uids) = setup_table();
+    let is_not_allowed = |res: Result<(), _>| {
+        matches!(
+            res.map_err(|e: errors::traced_error::TracedError<GameError>| e.into_inner()),
+            Err(GameError::ActionNotAllowed { .. })
+        )
+    };
+
+    assert!(is_not_allowed(
+        table.set_user_pre_action(uids[3], Some(PreAction::CallAny))
+    ));
+    assert!(is_not_allowed(
+        table.set_user_pre_action(uids[0], Some(PreAction::Check))
+    ));
+    assert!(is_not_allowed(table.set_user_pre_action(
+        uids[0],
+        Some(PreAction::Call(convert_to_e8s(1.0)))
+    )));
+    assert!(is_not_allowed(table.set_user_pre_action(
+        uids[0],
+        Some(PreAction::RaiseTo(convert_to_e8s(2.0)))
+    )));
+
+    // The big blind can check if nobody raises
+    assert_eq!(
+        table.set_user_pre_action(uids[2], Some(PreAction::Check)),
+        Ok(())
+    );
+    assert_eq!(table.set_user_pre_action(uids[2], None), Ok(()));
+    assert_eq!(pre_action(&table, uids[2]), None);
+}
+
+#[test]
+fn test_pre_actions_are_private() {
+    let (mut table, uids) = setup_table();
+    assert_eq!(
+        table.set_user_pre_action(uids[0], Some(PreAction::CallAny)),
+        Ok(())
+    );
+
+    let mut owners_view = table.clone();
+    owners_view.hide_pre_actions(uids[0]);
+    assert_eq!(pre_action(&owners_view, uids[0]), Some(PreAction::CallAny));
+
+    assert!(table.hide_cards(uids[1]).is_ok());
+    assert_eq!(pre_action(&table, uids[0]), None);
+}
@@ -0,0 +1,134 @@
+use candid::Principal;
+
+use crate::poker::game::{
+    table_functions::{
+        rng_archive::paginate,
+        table::{Table, TableId},
+        tests::get_table_config,
+    },
+    types::{CardProvenance, GameType, RngMetadata},
+    utils::convert_to_e8s,
+};
+
+fn table_with_rounds(rounds: u64) -> Table {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 2),
+        vec![1, 2, 3],
+    );
+    for round_id in 1..=rounds {
+        table.rng_history.push(RngMetadata {
+            round_id,
+            ..RngMetadata::default()
+        });
+        for shuffled_position in (0..3u8).rev() {
+            let card_hash = format!("{}-{}", round_id, shuffled_position);
+            table.card_provenance.insert(
+                card_hash.clone(),
+                CardProvenance {
+                    round_id,
+                    shuffled_position,
+                    card_hash,
+                    ..CardProvenance::default()
+                },
+            );
+        }
+    }
+    table
+}
+
+#[test]
+fn test_take_archivable_rounds_keeps_the_latest_rounds() {
+    let mut table = table_with_rounds(5);
+
+    let archived = table.take_archivable_rounds(2, 10);
+
+    let archived_round_ids: Vec<u64> = archived
+        .iter()
+        .map(|round| round.rng_metadata.round_id)
+        .collect();
+    assert_eq!(archived_round_ids, vec![1, 2, 3]);
+    for round in &archived {
+        let positions: Vec<u8> = round
+            .card_provenance
+            .iter()
+            .map(|provenance| provenance.shuffled_position)
+            .collect();
+        assert_eq!(positions, vec![0, 1, 2]);
+        assert!(round
+            .card_provenance
+            .iter()
+            .all(|provenance| provenance.round_id == round.rng_metadata.round_id));
+    }
+
+    let kept_round_ids: Vec<u64> = table.rng_history.iter().map(|rng| rng.round_id).collect();
+    assert_eq!(kept_round_ids, vec![4, 5]);
+    assert_eq!(table.card_provenance.len(), 6);
+    assert!(table
+        .card_provenance
+        .values()
+        .all(|provenance| provenance.round_id >= 4));
+}
+
+#[test]
+fn test_take_archivable_rounds_within_the_limit() {
+    let mut table = table_with_rounds(3);
+
+    assert!(table.take_archivable_rounds(3, 10).is_empty());
+    assert!(table.take_archivable_rounds(10, 10).is_empty());
+    assert_eq!(table.rng_history.len(), 3);
+    assert_eq!(table.card_provenance.len(), 9);
+}
+
+#[test]
+fn test_take_archivable_rounds_works_off_a_backlog() {
+    let mut table = table_with_rounds(8);
+
+    let archived = table.take_archivable_rounds(2, 4);
+    let archived_round_ids: Vec<u64> = archived
+        .iter()
+        .map(|round| round.rng_metadata.round_id)
+        .collect();
+    assert_eq!(archived_round_ids, vec![1, 2, 3, 4]);
+    assert_eq!(table.rng_history.len(), 4);
+    assert_eq!(table.card_provenance.len(), 12);
+
+    let archived = table.take_archivable_rounds(2, 4);
+    let archived_round_ids: Vec<u64> = archived
+        .iter()
+        .map(|round| round.rng_metadata.round_id)
+        .collect();
+    assert_eq!(archived_round_ids, vec![5, 6]);
+    assert_eq!(table.rng_history.len(), 2);
+    assert!(table.take_archivable_rounds(2, 4).is_empty());
+}
+
+#[test]
+fn test_paginate_follows_the_cursor() {
+    let round_ids = [3u64, 4, 5, 7, 8];
+
+    let (page, next_cursor) = paginate(round_ids, None, 2, |round_id| *round_id);
+    assert_eq!(page, vec![3, 4]);
+    assert_eq!(next_cursor, Some(5));
+
+    let (page, next_cursor) = paginate(round_ids, next_cursor, 2, |round_id| *round_id);
+    assert_eq!(page, vec![5, 7]);
+    assert_eq!(next_cursor, Some(8));
+
+    let (page, next_cursor) = paginate(round_ids, next_cursor, 2, |round_id| *round_id);
+    assert_eq!(page, vec![8]);
+    assert_eq!(next_cursor, None);
+}
+
+#[test]
+fn test_paginate_cursor_between_items() {
+    let round_ids = [3u64, 4, 5, 7, 8];
+
+    let (page, next_cursor) = paginate(round_ids, Some(6), 2, |round_id| *round_id);
+    assert_eq!(page, vec![7, 8]);
+    assert_eq!(next_cursor, None);
+
+    let (page, next_cursor) = paginate(round_ids, Some(9), 2, |round_id| *round_id);
+    assert!(page.is_empty());
+    assert_eq!(next_cursor, None);
+}
@@ -0,0 +1,334 @@
+use candid::Principal;
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, Suit, Value},
+    game::{
+        table_functions::{
+            action_log::ActionType,
+            table::{Table, TableId, TableType},
+            tests::{balance, create_user, fold_to_the_last_player, get_table_config},
+            types::{BetType, NotificationMessage},
+        },
+        types::{CardProvenance, GameType},
+        utils::convert_to_e8s,
+    },
+};
+
+/// Seats two players at a no limit table and starts the hand.
+fn setup_heads_up_table() -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 2),
+        vec![1, 2, 3],
+    );
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    (table, vec![player1_uid, player2_uid])
+}
+
+/// Records a provenance entry for every card left in the deck, like the canister does.
+fn add_card_provenance(table: &mut Table) {
+    for (position, card) in table.deck.cards().iter().enumerate() {
+        let card_hash = format!("{}-{:?}-{:?}", table.round_ticker, card.value, card.suit);
+        table.card_provenance.insert(
+            card_hash.clone(),
+            CardProvenance {
+                round_id: table.round_ticker,
+                card: *card,
+                original_position: position as u8,
+                shuffled_position: position as u8,
+                card_hash,
+                dealt_to: None,
+                dealt_at_stage: None,
+                dealt_in_run: None,
+                is_revealed: None,
+            },
+        );
+    }
+}
+
+fn board_runs(table: &Table) -> Vec<(u8, Vec<Card>)> {
+    table
+        .action_logs
+        .iter()
+        .filter_map(|log| match &log.action_type {
+            ActionType::BoardRun {
+                run,
+                community_cards,
+            } => Some((*run, community_cards.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_all_in_runs_it_twice_when_everyone_agrees() {
+    let (mut table, uids) = setup_heads_up_table();
+    add_card_provenance(&mut table);
+
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+    assert!(table.set_user_run_it_times(uids[1], 3).is_ok());
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    // Everyone agreed to at least two runs
+    let runs = board_runs(&table);
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].0, 1);
+    assert_eq!(runs[1].0, 2);
+    assert_eq!(runs[0].1.len(), 5);
+    assert_eq!(runs[1].1.len(), 5);
+    assert!(runs[0].1.iter().all(|card| !runs[1].1.contains(card)));
+    assert_eq!(table.community_cards, runs[0].1);
+
+    for (run, board) in runs.iter() {
+        for card in board {
+            let provenance = table
+                .card_provenance
+                .values()
+                .find(|provenance| provenance.card == *card)
+                .unwrap();
+            assert_eq!(provenance.dealt_in_run, Some(*run));
+        }
+    }
+
+    assert!(table.sorted_users.is_some());
+    assert_eq!(
+        balance(&table, uids[0]) + balance(&table, uids[1]),
+        convert_to_e8s(200.0)
+    );
+}
+
+#[test]
+fn test_all_in_runs_once_without_agreement() {
+    let (mut table, uids) = setup_heads_up_table();
+
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    assert!(board_runs(&table).is_empty());
+    assert_eq!(table.community_cards.len(), 5);
+    assert!(table.sorted_users.is_some());
+}
+
+#[test]
+fn test_run_it_window_opens_once_everyone_is_all_in() {
+    let (mut table, uids) = setup_heads_up_table();
+    table.config.table_type = Some(TableType::Cash);
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    // The board waits for the players to agree
+    let expires_at = table.run_it_window_closes_at.unwrap();
+    assert!(table.community_cards.is_empty());
+    assert!(table.sorted_users.is_none());
+    for uid in &uids {
+        assert!(table
+            .notifications
+            .notifications
+            .iter()
+            .any(|notification| {
+                notification.user_principal == *uid
+                    && notification.message == NotificationMessage::RunItWindowOpened { expires_at }
+            }));
+    }
+
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+    assert!(table.sorted_users.is_none());
+    assert!(table.set_user_run_it_times(uids[1], 3).is_ok());
+
+    assert_eq!(table.run_it_window_closes_at, None);
+    assert_eq!(board_runs(&table).len(), 2);
+    assert!(table.sorted_users.is_some());
+}
+
+#[test]
+fn test_run_it_window_runs_out_once_when_it_closes() {
+    let (mut table, uids) = setup_heads_up_table();
+    table.config.table_type = Some(TableType::Cash);
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+
+    let expires_at = table.run_it_window_closes_at.unwrap();
+    assert_eq!(table.close_run_it_window(expires_at - 1), Ok(()));
+    assert!(table.sorted_users.is_none());
+
+    assert_eq!(table.close_run_it_window(expires_at), Ok(()));
+    assert_eq!(table.run_it_window_closes_at, None);
+    assert!(board_runs(&table).is_empty());
+    assert_eq!(table.community_cards.len(), 5);
+    assert!(table.sorted_users.is_some());
+}
+
+#[test]
+fn test_no_run_it_window_when_everyone_agreed_before_the_all_in() {
+    let (mut table, uids) = setup_heads_up_table();
+    table.config.table_type = Some(TableType::Cash);
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+    assert!(table.set_user_run_it_times(uids[1], 1).is_ok());
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(100.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    assert_eq!(table.run_it_window_closes_at, None);
+    assert!(board_runs(&table).is_empty());
+    assert!(table.sorted_users.is_some());
+}
+
+#[test]
+fn test_run_it_twice_splits_pot_across_boards() {
+    let (mut table, uids) = setup_heads_up_table();
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+
+    // Player 1: [A♠, A♥], Player 2: [K♠, K♥]
+    table.get_user_table_data_mut(uids[0]).unwrap().cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Ace, Suit::Heart),
+    ];
+    table.get_user_table_data_mut(uids[1]).unwrap().cards = vec![
+        Card::new(Value::King, Suit::Spade),
+        Card::new(Value::King, Suit::Heart),
+    ];
+
+    // The aces hold on the first board, a king hits the second
+    let first_board = vec![
+        Card::new(Value::Two, Suit::Club),
+        Card::new(Value::Seven, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+        Card::new(Value::Four, Suit::Heart),
+    ];
+    let second_board = vec![
+        Card::new(Value::King, Suit::Club),
+        Card::new(Value::Eight, Suit::Diamond),
+        Card::new(Value::Three, Suit::Club),
+        Card::new(Value::Ten, Suit::Diamond),
+        Card::new(Value::Five, Suit::Heart),
+    ];
+
+    table
+        .showdown_boards(vec![first_board.clone(), second_board.clone()])
+        .unwrap();
+
+    assert_eq!(balance(&table, uids[0]), convert_to_e8s(100.0));
+    assert_eq!(balance(&table, uids[1]), convert_to_e8s(100.0));
+    assert_eq!(
+        board_runs(&table),
+        vec![(1, first_board.clone()), (2, second_board)]
+    );
+    assert_eq!(table.community_cards, first_board);
+    assert_eq!(table.winners.as_ref().unwrap().len(), 2);
+}
+
+#[test]
+fn test_run_it_twice_odd_chip_goes_to_first_board() {
+    let (mut table, uids) = setup_heads_up_table();
+
+    assert_eq!(
+        table.bet(uids[0], BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(uids[1], BetType::Called), Ok(()));
+    table.calculate_pots().unwrap();
+    table.pot.0 += 1;
+
+    table.get_user_table_data_mut(uids[0]).unwrap().cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Ace, Suit::Heart),
+    ];
+    table.get_user_table_data_mut(uids[1]).unwrap().cards = vec![
+        Card::new(Value::King, Suit::Spade),
+        Card::new(Value::King, Suit::Heart),
+    ];
+
+    let board = vec![
+        Card::new(Value::Two, Suit::Club),
+        Card::new(Value::Seven, Suit::Diamond),
+        Card::new(Value::Nine, Suit::Club),
+        Card::new(Value::Jack, Suit::Diamond),
+        Card::new(Value::Four, Suit::Heart),
+    ];
+    let king_board = vec![
+        Card::new(Value::King, Suit::Club),
+        Card::new(Value::Eight, Suit::Diamond),
+        Card::new(Value::Three, Suit::Club),
+        Card::new(Value::Ten, Suit::Diamond),
+        Card::new(Value::Five, Suit::Heart),
+    ];
+
+    table.showdown_boards(vec![board, king_board]).unwrap();
+
+    assert_eq!(balance(&table, uids[0]), convert_to_e8s(100.0) + 1);
+    assert_eq!(balance(&table, uids[1]), convert_to_e8s(100.0));
+}
+
+#[test]
+fn test_run_it_times_validation() {
+    let (mut table, uids) = setup_heads_up_table();
+
+    assert!(table.set_user_run_it_times(uids[0], 0).is_err());
+    assert!(table.set_user_run_it_times(uids[0], 4).is_err());
+    assert!(table.set_user_run_it_times(uids[0], 3).is_ok());
+
+    table.config.table_type = Some(TableType::SitAndGo);
+    assert!(table.set_user_run_it_times(uids[0], 2).is_err());
+}
+
+#[test]
+fn test_run_it_times_only_hold_for_the_hand() {
+    let (mut table, uids) = setup_heads_up_table();
+    assert!(table.set_user_run_it_times(uids[0], 2).is_ok());
+    assert!(table.set_user_run_it_times(uids[1], 2).is_ok());
+    fold_to_the_last_player(&mut table);
+
+    // There is no all-in to agree to between hands
+    assert!(table.set_user_run_it_times(uids[0], 2).is_err());
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    for uid in uids {
+        assert_eq!(table.get_user_table_data(uid).unwrap().run_it_times, None);
+    }
+}
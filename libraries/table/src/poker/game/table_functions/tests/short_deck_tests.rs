@@ -0,0 +1,219 @@
+use candid::Principal;
+
+use crate::poker::{
+    core::{Card, Suit, Value},
+    game::{
+        table_functions::{
+            ante::AnteType,
+            table::{Table, TableId},
+            tests::{create_user, get_table_config},
+            types::BetType,
+        },
+        types::GameType,
+        utils::convert_to_e8s,
+    },
+};
+
+fn setup_short_deck_table(seats: u8) -> Table {
+    Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::ShortDeck(convert_to_e8s(1.0)), seats),
+        vec![1, 2],
+    )
+}
+
+#[test]
+fn test_short_deck_table_uses_36_card_deck() {
+    let mut table = setup_short_deck_table(2);
+
+    assert_eq!(table.deck.len(), 36);
+    assert_eq!(table.small_blind.0, convert_to_e8s(1.0));
+    assert_eq!(table.big_blind.0, convert_to_e8s(2.0));
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+
+    assert!(table.start_betting_round(vec![3, 4, 5]).is_ok());
+
+    // Two hole cards each were dealt from a fresh short deck
+    assert_eq!(table.deck.len(), 32);
+    for user_table_data in table.user_table_data.values() {
+        assert_eq!(user_table_data.cards.len(), 2);
+        assert!(user_table_data.cards.iter().all(|c| c.value >= Value::Six));
+    }
+    assert!(table.deck.cards().iter().all(|c| c.value >= Value::Six));
+}
+
+#[test]
+fn test_short_deck_flush_beats_full_house() {
+    let mut table = setup_short_deck_table(2);
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+
+    assert!(table.start_betting_round(vec![0, 1]).is_ok());
+
+    assert_eq!(
+        table.bet(player1_uid, BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(player2_uid, BetType::Called), Ok(()));
+
+    // Board: [6♠, 8♠, T♠, K♦, K♣]
+    table.community_cards = vec![
+        Card::new(Value::Six, Suit::Spade),
+        Card::new(Value::Eight, Suit::Spade),
+        Card::new(Value::Ten, Suit::Spade),
+        Card::new(Value::King, Suit::Diamond),
+        Card::new(Value::King, Suit::Club),
+    ];
+
+    // Player 1: [A♠, Q♠] (Ace high flush)
+    table.get_user_table_data_mut(player1_uid).unwrap().cards = vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::Queen, Suit::Spade),
+    ];
+
+    // Player 2: [K♥, 6♦] (Full house: Kings over Sixes)
+    table.get_user_table_data_mut(player2_uid).unwrap().cards = vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::Six, Suit::Diamond),
+    ];
+
+    table.showdown().unwrap();
+
+    assert_eq!(
+        table.users.get(&player1_uid).unwrap().balance.0,
+        convert_to_e8s(110.0)
+    );
+    assert_eq!(
+        table.users.get(&player2_uid).unwrap().balance.0,
+        convert_to_e8s(90.0)
+    );
+}
+
+#[test]
+fn test_short_deck_ace_six_straight() {
+    let mut table = setup_short_deck_table(2);
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+
+    let player1_uid = table.get_player_at_seat(0).unwrap();
+    let player2_uid = table.get_player_at_seat(1).unwrap();
+
+    assert!(table.start_betting_round(vec![0, 1]).is_ok());
+
+    assert_eq!(
+        table.bet(player1_uid, BetType::Raised(convert_to_e8s(10.0))),
+        Ok(())
+    );
+    assert_eq!(table.bet(player2_uid, BetType::Called), Ok(()));
+
+    // Board: [A♦, 7♣, 8♥, 9♠, Q♦]
+    table.community_cards = vec![
+        Card::new(Value::Ace, Suit::Diamond),
+        Card::new(Value::Seven, Suit::Club),
+        Card::new(Value::Eight, Suit::Heart),
+        Card::new(Value::Nine, Suit::Spade),
+        Card::new(Value::Queen, Suit::Diamond),
+    ];
+
+    // Player 1: [Q♣, Q♥] (Three of a kind: Queens)
+    table.get_user_table_data_mut(player1_uid).unwrap().cards = vec![
+        Card::new(Value::Queen, Suit::Club),
+        Card::new(Value::Queen, Suit::Heart),
+    ];
+
+    // Player 2: [6♣, J♥] (Straight: A-6-7-8-9)
+    table.get_user_table_data_mut(player2_uid).unwrap().cards = vec![
+        Card::new(Value::Six, Suit::Club),
+        Card::new(Value::Jack, Suit::Heart),
+    ];
+
+    table.showdown().unwrap();
+
+    assert_eq!(
+        table.users.get(&player1_uid).unwrap().balance.0,
+        convert_to_e8s(90.0)
+    );
+    assert_eq!(
+        table.users.get(&player2_uid).unwrap().balance.0,
+        convert_to_e8s(110.0)
+    );
+}
+
+#[test]
+fn test_short_deck_button_ante() {
+    let mut config = get_table_config(GameType::ShortDeck(convert_to_e8s(1.0)), 3);
+    config.ante_type = Some(AnteType::ButtonAnte(convert_to_e8s(3.0)));
+    let mut table = Table::new(TableId(Principal::anonymous()), config, vec![1, 2, 3]);
+
+    let user1 = create_user(
+        Principal::from_text("2chl6-4hpzw-vqaaa-aaaaa-c").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user2 = create_user(
+        Principal::from_text("br5f7-7uaaa-aaaaa-qaaca-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+    let user3 = create_user(
+        Principal::from_text("bw4dl-smaaa-aaaaa-qaacq-cai").expect("Could not decode principal"),
+        convert_to_e8s(100.0),
+    );
+
+    assert!(table.add_user(user1, 0, false).is_ok());
+    assert!(table.add_user(user2, 1, false).is_ok());
+    assert!(table.add_user(user3, 2, false).is_ok());
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    let dealer = table.get_player_at_seat(table.dealer_position).unwrap();
+    let small_blind = table.get_small_blind_user_principal().unwrap();
+    let big_blind = table.get_big_blind_user_principal().unwrap();
+
+    // Only the button pays the ante, for the whole table
+    assert_eq!(table.pot.0, convert_to_e8s(3.0));
+    assert_eq!(
+        table.users.get(&dealer).unwrap().balance.0,
+        convert_to_e8s(97.0)
+    );
+    assert_eq!(
+        table.users.get(&small_blind).unwrap().balance.0,
+        convert_to_e8s(99.0)
+    );
+    assert_eq!(
+        table.users.get(&big_blind).unwrap().balance.0,
+        convert_to_e8s(98.0)
+    );
+}
@@ -0,0 +1,121 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use sha2::{Digest, Sha256};
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        shuffle_seed::{combine_shuffle_seeds, shuffle_commitment, CommittedShuffle, PlayerSeed},
+        table::Table,
+        tests::{self, get_table_config},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+fn setup_table() -> (Table, Vec<WalletPrincipalId>) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3);
+    config.commit_reveal_shuffle = Some(true);
+    tests::setup_table(config, &[100.0; 3])
+}
+
+fn commitment(seed: &[u8]) -> Vec<u8> {
+    Sha256::digest(seed).to_vec()
+}
+
+#[test]
+fn test_revealed_seed_must_match_the_commitment() {
+    let (mut table, uids) = setup_table();
+
+    assert!(matches!(
+        table
+            .reveal_shuffle_seed(uids[0], b"seed".to_vec())
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+    assert!(matches!(
+        table
+            .commit_shuffle_seed(uids[0], b"not a hash".to_vec())
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+
+    assert_eq!(
+        table.commit_shuffle_seed(uids[0], commitment(b"seed")),
+        Ok(())
+    );
+    // Seeds are only revealed once the table has committed to its randomness
+    assert!(matches!(
+        table
+            .reveal_shuffle_seed(uids[0], b"seed".to_vec())
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+    table.committed_shuffle = Some(CommittedShuffle::new(vec![7; 32], 0));
+    assert_eq!(
+        table.get_shuffle_commitment(),
+        Some(shuffle_commitment(&[7; 32], 0))
+    );
+    assert!(matches!(
+        table
+            .reveal_shuffle_seed(uids[0], b"other seed".to_vec())
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+    assert_eq!(table.reveal_shuffle_seed(uids[0], b"seed".to_vec()), Ok(()));
+    assert!(table.player_seeds[&uids[0]].matches_commitment());
+
+    // Only seated players on commit-reveal tables take part
+    assert!(matches!(
+        table
+            .commit_shuffle_seed(
+                WalletPrincipalId(Principal::from_slice(&[9])),
+                commitment(b"seed")
+            )
+            .map_err(|e| e.into_inner()),
+        Err(GameError::PlayerNotFound)
+    ));
+    table.config.commit_reveal_shuffle = None;
+    assert!(matches!(
+        table
+            .commit_shuffle_seed(uids[1], commitment(b"seed"))
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+}
+
+#[test]
+fn test_seeds_are_mixed_in_seat_order() {
+    let (mut table, uids) = setup_table();
+    table.committed_shuffle = Some(CommittedShuffle::new(vec![7; 32], 0));
+    for (uid, seed) in uids.iter().zip([b"one", b"two", b"six"]) {
+        assert_eq!(table.commit_shuffle_seed(*uid, commitment(seed)), Ok(()));
+    }
+    assert_eq!(table.reveal_shuffle_seed(uids[2], b"six".to_vec()), Ok(()));
+    assert_eq!(table.reveal_shuffle_seed(uids[0], b"one".to_vec()), Ok(()));
+
+    let player_seeds = table.get_shuffle_player_seeds();
+    assert_eq!(
+        player_seeds
+            .iter()
+            .map(|player_seed| player_seed.user_principal)
+            .collect::<Vec<_>>(),
+        uids
+    );
+
+    let random_bytes = [7u8; 32];
+    let seed = combine_shuffle_seeds(&random_bytes, &player_seeds);
+    assert_eq!(seed.len(), 32);
+    assert_ne!(seed, combine_shuffle_seeds(&random_bytes, &[]));
+
+    // The player who did not reveal has no say in the shuffle
+    let revealed: Vec<PlayerSeed> = player_seeds
+        .iter()
+        .filter(|player_seed| player_seed.seed.is_some())
+        .cloned()
+        .collect();
+    assert_eq!(seed, combine_shuffle_seeds(&random_bytes, &revealed));
+
+    table.clear_shuffle_seeds();
+    assert!(table.get_shuffle_player_seeds().is_empty());
+}
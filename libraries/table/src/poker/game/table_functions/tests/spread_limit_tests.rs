@@ -75,7 +75,7 @@ fn test_correct_blinds_spread_limit() {
             .get(&user1.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(1.0)
+        convert_to_e8s(0.5)
     );
     assert_eq!(
         table
@@ -83,7 +83,7 @@ fn test_correct_blinds_spread_limit() {
             .get(&user2.principal_id)
             .unwrap()
             .current_total_bet,
-        convert_to_e8s(0.5)
+        convert_to_e8s(1.0)
     );
 }
 
@@ -115,7 +115,7 @@ fn test_invalid_opening_raise_spread_limit() {
 
     // Test that raising by more than the max limit is not allowed
     assert!(table
-        .bet(user2.principal_id, BetType::Raised(convert_to_e8s(7.0)))
+        .bet(user1.principal_id, BetType::Raised(convert_to_e8s(7.0)))
         .is_err());
 }
 
@@ -219,8 +219,8 @@ fn test_raise_amount_validation_spread_limit() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     // Raise with valid amount within spread limit
     assert_eq!(
@@ -272,8 +272,8 @@ fn test_mutliple_raise_amount_validation_spread_limit() {
     assert!(table
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-    assert!(table.user_check(user1.principal_id, false).is_ok());
+    assert_eq!(table.bet(user1.principal_id, BetType::Called), Ok(()));
+    assert!(table.user_check(user2.principal_id, false).is_ok());
 
     // Raise with valid amount within spread limit
     assert_eq!(
@@ -490,11 +490,10 @@ fn test_spread_limit_user_below_minimum() {
         .start_betting_round(vec![0, 1, 2, 3, 4, 5, 6, 7, 8])
         .is_ok());
 
-    // User2 raises
-    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
-
     assert!(table.bet(user1.principal_id, BetType::Called).is_ok());
 
+    assert_eq!(table.bet(user2.principal_id, BetType::Called), Ok(()));
+
     assert_eq!(table.deal_stage, DealStage::Turn);
 
     // User2 raises
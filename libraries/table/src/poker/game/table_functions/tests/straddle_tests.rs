@@ -0,0 +1,230 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        straddle::StraddleType,
+        table::{Table, TableId},
+        tests::{self, get_table_config, seat_player, turn_tests::is_it_users_turn},
+        types::{BetType, DealStage, PlayerAction},
+    },
+    types::GameType,
+    utils::convert_to_e8s,
+};
+
+/// The players of a four handed table by position.
+struct Positions {
+    button: WalletPrincipalId,
+    small_blind: WalletPrincipalId,
+    big_blind: WalletPrincipalId,
+    under_the_gun: WalletPrincipalId,
+}
+
+/// Seats four players at a no limit table with $1/$2 blinds and the given straddle type.
+fn setup_table(straddle_type: Option<StraddleType>) -> (Table, Positions) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 4);
+    config.straddle_type = straddle_type;
+    let (table, _) = tests::setup_table(config, &[100.0; 4]);
+
+    let positions = Positions {
+        button: table.get_player_at_seat(0).unwrap(),
+        small_blind: table.get_small_blind_user_principal().unwrap(),
+        big_blind: table.get_big_blind_user_principal().unwrap(),
+        under_the_gun: table.get_player_at_seat(3).unwrap(),
+    };
+    assert_eq!(positions.small_blind, table.get_player_at_seat(1).unwrap());
+    assert_eq!(positions.big_blind, table.get_player_at_seat(2).unwrap());
+
+    (table, positions)
+}
+
+fn current_total_bet(table: &Table, uid: WalletPrincipalId) -> u64 {
+    table.get_user_table_data(uid).unwrap().current_total_bet
+}
+
+#[test]
+fn test_under_the_gun_straddle_gets_the_last_option() {
+    let (mut table, players) = setup_table(Some(StraddleType::UnderTheGun));
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.straddle_user_principal, Some(players.under_the_gun));
+    assert_eq!(
+        current_total_bet(&table, players.under_the_gun),
+        convert_to_e8s(4.0)
+    );
+    assert_eq!(table.highest_bet, convert_to_e8s(4.0));
+    assert!(table
+        .action_logs
+        .iter()
+        .any(|log| log.action_type == ActionType::Straddle
+            && log.user_principal == Some(players.under_the_gun)));
+
+    // Action starts left of the straddler
+    assert!(is_it_users_turn(&table, players.button));
+    assert_eq!(table.bet(players.button, BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, players.small_blind));
+    assert_eq!(table.bet(players.small_blind, BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, players.big_blind));
+    assert_eq!(table.bet(players.big_blind, BetType::Called), Ok(()));
+
+    // Everyone called, but the straddler still has the option
+    assert_eq!(table.deal_stage, DealStage::Flop);
+    assert!(is_it_users_turn(&table, players.under_the_gun));
+    assert_eq!(table.user_check(players.under_the_gun, false), Ok(()));
+
+    assert_eq!(table.deal_stage, DealStage::Turn);
+    assert_eq!(table.pot.0, convert_to_e8s(16.0));
+}
+
+#[test]
+fn test_straddler_can_raise_on_the_option() {
+    let (mut table, players) = setup_table(Some(StraddleType::UnderTheGun));
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    assert_eq!(table.bet(players.button, BetType::Called), Ok(()));
+    assert_eq!(table.user_fold(players.small_blind, false), Ok(()));
+    assert_eq!(table.user_fold(players.big_blind, false), Ok(()));
+    assert_eq!(
+        table.bet(players.under_the_gun, BetType::Raised(convert_to_e8s(12.0))),
+        Ok(())
+    );
+
+    assert_eq!(table.deal_stage, DealStage::Flop);
+    assert!(is_it_users_turn(&table, players.button));
+    assert_eq!(table.bet(players.button, BetType::Called), Ok(()));
+    assert_eq!(table.deal_stage, DealStage::Turn);
+}
+
+#[test]
+fn test_straddle_is_the_new_big_blind_for_min_raise() {
+    let (mut table, players) = setup_table(Some(StraddleType::UnderTheGun));
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.last_raise, convert_to_e8s(4.0));
+
+    // A raise has to be at least the size of the straddle
+    let result = table.bet(players.button, BetType::Raised(convert_to_e8s(6.0)));
+    assert!(matches!(
+        result.unwrap_err().into_inner(),
+        GameError::ActionNotAllowed { .. }
+    ));
+    assert_eq!(
+        table.bet(players.button, BetType::Raised(convert_to_e8s(8.0))),
+        Ok(())
+    );
+    assert_eq!(table.last_raise, convert_to_e8s(4.0));
+    assert!(is_it_users_turn(&table, players.small_blind));
+}
+
+#[test]
+fn test_no_straddle_without_opting_in() {
+    let (mut table, players) = setup_table(Some(StraddleType::UnderTheGun));
+    // Only the player under the gun can straddle
+    assert!(table.set_user_straddle(players.button, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    assert_eq!(table.straddle_user_principal, None);
+    assert_eq!(table.highest_bet, convert_to_e8s(2.0));
+    assert!(is_it_users_turn(&table, players.under_the_gun));
+    assert_eq!(
+        table
+            .get_user_table_data(players.button)
+            .unwrap()
+            .player_action,
+        PlayerAction::None
+    );
+}
+
+#[test]
+fn test_straddle_not_allowed_without_a_straddle_type() {
+    let (mut table, players) = setup_table(None);
+
+    let result = table.set_user_straddle(players.under_the_gun, true);
+    assert!(matches!(
+        result.unwrap_err().into_inner(),
+        GameError::ActionNotAllowed { .. }
+    ));
+    // Turning it off is always allowed
+    assert!(table
+        .set_user_straddle(players.under_the_gun, false)
+        .is_ok());
+}
+
+#[test]
+fn test_button_straddle_action_starts_with_the_small_blind() {
+    let (mut table, players) = setup_table(Some(StraddleType::Button));
+    assert!(table.set_user_straddle(players.button, true).is_ok());
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    assert_eq!(table.straddle_user_principal, Some(players.button));
+    assert!(is_it_users_turn(&table, players.small_blind));
+    assert_eq!(table.bet(players.small_blind, BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, players.big_blind));
+    assert_eq!(table.bet(players.big_blind, BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, players.under_the_gun));
+    assert_eq!(table.bet(players.under_the_gun, BetType::Called), Ok(()));
+
+    assert_eq!(table.deal_stage, DealStage::Flop);
+    assert!(is_it_users_turn(&table, players.button));
+    assert_eq!(table.user_check(players.button, false), Ok(()));
+    assert_eq!(table.deal_stage, DealStage::Turn);
+}
+
+#[test]
+fn test_mississippi_straddle_goes_to_the_latest_position() {
+    let (mut table, players) = setup_table(Some(StraddleType::Mississippi));
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+    assert!(table.set_user_straddle(players.button, true).is_ok());
+    // The blinds cannot straddle
+    assert!(table.set_user_straddle(players.big_blind, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+
+    assert_eq!(table.straddle_user_principal, Some(players.button));
+    assert_eq!(current_total_bet(&table, players.under_the_gun), 0);
+
+    // Without the button, the player under the gun straddles
+    let (mut table, players) = setup_table(Some(StraddleType::Mississippi));
+    assert!(table.set_user_straddle(players.under_the_gun, true).is_ok());
+    assert!(table.start_betting_round(vec![0, 1, 2, 3]).is_ok());
+    assert_eq!(table.straddle_user_principal, Some(players.under_the_gun));
+}
+
+#[test]
+fn test_no_straddle_heads_up() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 2),
+        vec![1, 2],
+    );
+    table.config.straddle_type = Some(StraddleType::Mississippi);
+    for seat in 0..2u8 {
+        let uid = seat_player(&mut table, seat, 100.0);
+        assert!(table.set_user_straddle(uid, true).is_ok());
+    }
+
+    assert!(table.start_betting_round(vec![0, 1]).is_ok());
+    assert_eq!(table.straddle_user_principal, None);
+    assert_eq!(table.highest_bet, convert_to_e8s(2.0));
+}
+
+#[test]
+fn test_no_straddle_in_fixed_limit() {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(
+            GameType::FixedLimit(convert_to_e8s(2.0), convert_to_e8s(4.0)),
+            4,
+        ),
+        vec![1, 2, 3, 4],
+    );
+    table.config.straddle_type = Some(StraddleType::UnderTheGun);
+    let uid = seat_player(&mut table, 0, 100.0);
+
+    assert!(!table.is_straddle_allowed());
+    assert!(table.set_user_straddle(uid, true).is_err());
+}
@@ -0,0 +1,221 @@
+use candid::Principal;
+use user::user::WalletPrincipalId;
+
+use crate::poker::{
+    core::{Card, Hand, Rankable, Suit, Value},
+    game::{
+        table_functions::{
+            table::{Table, TableId},
+            tests::{create_user, get_table_config},
+            types::{BetType, DealStage},
+        },
+        types::GameType,
+        utils::convert_to_e8s,
+    },
+};
+
+fn setup_stud_table() -> (Table, Vec<WalletPrincipalId>) {
+    let mut table = Table::new(
+        TableId(Principal::anonymous()),
+        get_table_config(
+            GameType::SevenCardStud(convert_to_e8s(2.0), convert_to_e8s(4.0)),
+            4,
+        ),
+        vec![1, 2, 3],
+    );
+
+    let principals = [
+        "2chl6-4hpzw-vqaaa-aaaaa-c",
+        "br5f7-7uaaa-aaaaa-qaaca-cai",
+        "bw4dl-smaaa-aaaaa-qaacq-cai",
+    ];
+    for (seat, principal) in principals.iter().enumerate() {
+        let user = create_user(
+            Principal::from_text(principal).expect("Could not decode principal"),
+            convert_to_e8s(100.0),
+        );
+        assert!(table.add_user(user, seat as u8, false).is_ok());
+    }
+
+    let uids = (0..principals.len())
+        .map(|seat| table.get_player_at_seat(seat).unwrap())
+        .collect();
+    (table, uids)
+}
+
+/// Checks or calls for every player until the street is over.
+fn check_down_street(table: &mut Table) {
+    let deal_stage = table.deal_stage;
+    for _ in 0..table.seats.len() * 2 {
+        if table.deal_stage != deal_stage || table.sorted_users.is_some() {
+            return;
+        }
+        let uid = table
+            .get_player_at_seat(table.current_player_index)
+            .unwrap();
+        let current_total_bet = table.get_user_table_data(uid).unwrap().current_total_bet;
+        if current_total_bet < table.highest_bet {
+            assert_eq!(table.bet(uid, BetType::Called), Ok(()));
+        } else {
+            assert_eq!(table.user_check(uid, false), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn test_stud_third_street_and_bring_in() {
+    let (mut table, uids) = setup_stud_table();
+
+    assert_eq!(table.small_blind.0, convert_to_e8s(1.0));
+    assert_eq!(table.big_blind.0, convert_to_e8s(2.0));
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.deal_stage, DealStage::Flop);
+    assert!(table.community_cards.is_empty());
+    assert_eq!(table.deck.len(), 52 - 9);
+
+    for uid in &uids {
+        let user_table_data = table.get_user_table_data(*uid).unwrap();
+        assert_eq!(user_table_data.cards.len(), 3);
+        assert_eq!(user_table_data.visible_cards().len(), 1);
+        assert_eq!(user_table_data.cards[2], user_table_data.visible_cards()[0]);
+    }
+
+    // The lowest door card brings it in, clubs being the lowest suit
+    let suit_rank = |suit: Suit| match suit {
+        Suit::Club => 0,
+        Suit::Diamond => 1,
+        Suit::Heart => 2,
+        Suit::Spade => 3,
+    };
+    let (bring_in_seat, bring_in_uid) = uids
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, uid)| {
+            let door_card = table.get_user_table_data(**uid).unwrap().visible_cards()[0];
+            (door_card.value, suit_rank(door_card.suit))
+        })
+        .unwrap();
+
+    for uid in &uids {
+        let expected = if uid == bring_in_uid {
+            convert_to_e8s(1.0)
+        } else {
+            0
+        };
+        assert_eq!(
+            table.get_user_table_data(*uid).unwrap().current_total_bet,
+            expected
+        );
+    }
+    assert_eq!(table.current_player_index, (bring_in_seat + 1) % 3);
+}
+
+#[test]
+fn test_stud_complete_bring_in() {
+    let (mut table, _) = setup_stud_table();
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    let uid = table
+        .get_player_at_seat(table.current_player_index)
+        .unwrap();
+
+    // Raising by less than the small bet is not allowed, completing to it is
+    assert!(table
+        .bet(uid, BetType::Raised(convert_to_e8s(1.5)))
+        .is_err());
+    assert_eq!(table.bet(uid, BetType::Raised(convert_to_e8s(2.0))), Ok(()));
+    assert_eq!(table.highest_bet, convert_to_e8s(2.0));
+
+    check_down_street(&mut table);
+    assert_eq!(table.deal_stage, DealStage::Turn);
+    assert_eq!(table.pot.0, convert_to_e8s(6.0));
+}
+
+#[test]
+fn test_stud_best_visible_hand_acts_first() {
+    let (mut table, uids) = setup_stud_table();
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    check_down_street(&mut table);
+    assert_eq!(table.deal_stage, DealStage::Turn);
+
+    let best_seat = (0..uids.len())
+        .max_by_key(|seat| {
+            let up_cards = table
+                .get_user_table_data(uids[*seat])
+                .unwrap()
+                .visible_cards();
+            assert_eq!(up_cards.len(), 2);
+            (
+                Hand::new_with_cards(up_cards).rank(),
+                // Ties go to the player closest to the left of the dealer
+                std::cmp::Reverse((*seat + uids.len() - table.dealer_position - 1) % uids.len()),
+            )
+        })
+        .unwrap();
+    assert_eq!(table.current_player_index, best_seat);
+
+    // A pair showing beats a higher card
+    table.get_user_table_data_mut(uids[0]).unwrap().up_cards = Some(vec![
+        Card::new(Value::Ace, Suit::Spade),
+        Card::new(Value::King, Suit::Spade),
+    ]);
+    table.get_user_table_data_mut(uids[1]).unwrap().up_cards = Some(vec![
+        Card::new(Value::Three, Suit::Club),
+        Card::new(Value::Three, Suit::Heart),
+    ]);
+    table.get_user_table_data_mut(uids[2]).unwrap().up_cards = Some(vec![
+        Card::new(Value::Queen, Suit::Diamond),
+        Card::new(Value::Jack, Suit::Diamond),
+    ]);
+    assert!(table.set_current_player_to_best_visible_hand().is_ok());
+    assert_eq!(table.current_player_index, 1);
+}
+
+#[test]
+fn test_stud_hand_to_showdown() {
+    let (mut table, uids) = setup_stud_table();
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    for deal_stage in [
+        DealStage::Turn,
+        DealStage::River,
+        DealStage::SeventhStreet,
+        DealStage::Showdown,
+    ] {
+        check_down_street(&mut table);
+        assert_eq!(table.deal_stage, deal_stage);
+    }
+
+    for uid in &uids {
+        let user_table_data = table.get_user_table_data(*uid).unwrap();
+        assert_eq!(user_table_data.cards.len(), 7);
+        assert_eq!(user_table_data.visible_cards().len(), 4);
+    }
+    assert!(table.community_cards.is_empty());
+
+    check_down_street(&mut table);
+    assert!(table.sorted_users.is_some());
+
+    let total: u64 = uids
+        .iter()
+        .map(|uid| table.users.get(uid).unwrap().balance.0)
+        .sum();
+    assert_eq!(total, convert_to_e8s(300.0));
+}
+
+#[test]
+fn test_stud_hide_cards_shows_up_cards() {
+    let (mut table, uids) = setup_stud_table();
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    table.hide_cards(uids[0]).unwrap();
+
+    assert_eq!(table.get_user_table_data(uids[0]).unwrap().cards.len(), 3);
+    for uid in &uids[1..] {
+        let user_table_data = table.get_user_table_data(*uid).unwrap();
+        assert_eq!(user_table_data.cards, user_table_data.visible_cards());
+        assert_eq!(user_table_data.cards.len(), 1);
+    }
+}
@@ -0,0 +1,169 @@
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        table::{BigBlind, SmallBlind, Table},
+        tests::{self, current_player, fold_to_the_last_player, get_table_config},
+        time_bank::{TimeBankConfig, TimeBankRefill},
+        types::NotificationMessage,
+    },
+    types::{GameType, QueueItem},
+    utils::convert_to_e8s,
+};
+
+const SECOND: u64 = 1_000_000_000;
+
+/// Starts a hand on a three seat table with the given time bank.
+fn setup_table(time_bank: Option<TimeBankConfig>) -> (Table, Vec<WalletPrincipalId>) {
+    let mut config = get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), 3);
+    config.time_bank = time_bank;
+
+    let (mut table, uids) = tests::setup_table(config, &[100.0; 3]);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    (table, uids)
+}
+
+fn time_bank_config(refill: TimeBankRefill, is_automatic: bool) -> TimeBankConfig {
+    TimeBankConfig {
+        initial_seconds: 30,
+        max_seconds: 40,
+        refill_seconds: 20,
+        refill,
+        is_automatic,
+    }
+}
+
+#[test]
+fn test_time_bank_starts_automatically() {
+    let (mut table, _) = setup_table(Some(time_bank_config(TimeBankRefill::EveryHands(10), true)));
+    let uid = current_player(&table);
+    assert_eq!(table.get_time_bank_seconds(uid), 30);
+
+    assert_eq!(table.start_time_bank(uid, 5 * SECOND), Ok(Some(30)));
+    assert_eq!(table.last_timer_started_timestamp, 5 * SECOND);
+    let user_table_data = table.get_user_table_data(uid).unwrap();
+    assert_eq!(user_table_data.is_using_time_bank, Some(true));
+    let notification = table.notifications.notifications.last().unwrap();
+    assert_eq!(notification.user_principal, uid);
+    assert_eq!(
+        notification.message,
+        NotificationMessage::TimeBankStarted { seconds: 30 }
+    );
+}
+
+#[test]
+fn test_time_bank_has_to_be_requested_when_not_automatic() {
+    let (mut table, uids) = setup_table(Some(time_bank_config(
+        TimeBankRefill::EveryHands(10),
+        false,
+    )));
+    let uid = current_player(&table);
+    assert_eq!(table.start_time_bank(uid, 0), Ok(None));
+
+    // Only the player whose turn it is can ask for their time bank
+    let other_uid = *uids.iter().find(|other| **other != uid).unwrap();
+    assert!(matches!(
+        table
+            .request_time_bank(other_uid)
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+
+    assert_eq!(table.request_time_bank(uid), Ok(()));
+    assert_eq!(table.start_time_bank(uid, 0), Ok(Some(30)));
+}
+
+#[test]
+fn test_time_bank_is_charged_for_the_time_used() {
+    let (mut table, _) = setup_table(Some(time_bank_config(TimeBankRefill::EveryHands(10), true)));
+    let uid = current_player(&table);
+    assert_eq!(table.start_time_bank(uid, 10 * SECOND), Ok(Some(30)));
+
+    // Part of a second counts as a whole one
+    table.stop_time_bank(uid, 22 * SECOND + 1);
+    assert_eq!(table.get_time_bank_seconds(uid), 17);
+    let user_table_data = table.get_user_table_data(uid).unwrap();
+    assert_eq!(user_table_data.is_using_time_bank, None);
+    assert_eq!(user_table_data.is_time_bank_requested, None);
+
+    // Acting without the time bank leaves it alone
+    table.stop_time_bank(uid, 60 * SECOND);
+    assert_eq!(table.get_time_bank_seconds(uid), 17);
+}
+
+#[test]
+fn test_time_bank_runs_out() {
+    let (mut table, _) = setup_table(Some(time_bank_config(TimeBankRefill::EveryHands(10), true)));
+    let uid = current_player(&table);
+    assert_eq!(table.start_time_bank(uid, 0), Ok(Some(30)));
+
+    // The timer ran out again, so the turn times out
+    assert_eq!(table.start_time_bank(uid, 30 * SECOND), Ok(None));
+    assert_eq!(table.get_time_bank_seconds(uid), 0);
+    assert_eq!(table.start_time_bank(uid, 30 * SECOND), Ok(None));
+    assert!(matches!(
+        table.request_time_bank(uid).map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+}
+
+#[test]
+fn test_time_bank_refills_every_few_hands() {
+    let (mut table, uids) =
+        setup_table(Some(time_bank_config(TimeBankRefill::EveryHands(2), true)));
+    let uid = current_player(&table);
+    assert_eq!(table.start_time_bank(uid, 0), Ok(Some(30)));
+    table.stop_time_bank(uid, 25 * SECOND);
+    assert_eq!(table.get_time_bank_seconds(uid), 5);
+    fold_to_the_last_player(&mut table);
+
+    // The refill is capped at the maximum
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.get_time_bank_seconds(uid), 25);
+    for other_uid in uids.iter().filter(|other| **other != uid) {
+        assert_eq!(table.get_time_bank_seconds(*other_uid), 40);
+    }
+    fold_to_the_last_player(&mut table);
+
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.get_time_bank_seconds(uid), 25);
+}
+
+#[test]
+fn test_time_bank_refills_every_level() {
+    let (mut table, uids) = setup_table(Some(time_bank_config(TimeBankRefill::EveryLevel, true)));
+    let uid = current_player(&table);
+    assert_eq!(table.start_time_bank(uid, 0), Ok(Some(30)));
+    table.stop_time_bank(uid, 30 * SECOND);
+    assert_eq!(table.get_time_bank_seconds(uid), 0);
+    fold_to_the_last_player(&mut table);
+
+    // Starting hands does not refill the time banks
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+    assert_eq!(table.get_time_bank_seconds(uid), 0);
+    fold_to_the_last_player(&mut table);
+
+    table.append_to_queue(QueueItem::UpdateBlinds(
+        SmallBlind(convert_to_e8s(2.0)),
+        BigBlind(convert_to_e8s(4.0)),
+        None,
+    ));
+    assert!(table.handle_queue_items().is_ok());
+    assert_eq!(table.get_time_bank_seconds(uid), 20);
+    for other_uid in uids.iter().filter(|other| **other != uid) {
+        assert_eq!(table.get_time_bank_seconds(*other_uid), 40);
+    }
+}
+
+#[test]
+fn test_no_time_bank_without_config() {
+    let (mut table, _) = setup_table(None);
+    let uid = current_player(&table);
+    assert_eq!(table.get_time_bank_seconds(uid), 0);
+    assert_eq!(table.start_time_bank(uid, 0), Ok(None));
+    assert!(matches!(
+        table.request_time_bank(uid).map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+}
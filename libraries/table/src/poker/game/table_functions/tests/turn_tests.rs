@@ -629,17 +629,11 @@ fn two_users_check_to_showdown() {
 
     assert_eq!(table.deal_stage, DealStage::Turn);
 
-    assert!(is_it_users_turn(&table, small_blind_uid));
-
-    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
-
-    assert_eq!(table.deal_stage, DealStage::Turn);
-
     assert!(is_it_users_turn(&table, big_blind_uid));
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
-    assert_eq!(table.deal_stage, DealStage::River);
+    assert_eq!(table.deal_stage, DealStage::Turn);
 
     assert!(is_it_users_turn(&table, small_blind_uid));
 
@@ -651,6 +645,12 @@ fn two_users_check_to_showdown() {
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
+    assert_eq!(table.deal_stage, DealStage::River);
+
+    assert!(is_it_users_turn(&table, small_blind_uid));
+
+    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
+
     assert_eq!(table.deal_stage, DealStage::Showdown);
 }
 
@@ -692,17 +692,11 @@ fn two_users_check_to_showdown_two_rounds() {
 
     assert_eq!(table.deal_stage, DealStage::Turn);
 
-    assert!(is_it_users_turn(&table, small_blind_uid));
-
-    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
-
-    assert_eq!(table.deal_stage, DealStage::Turn);
-
     assert!(is_it_users_turn(&table, big_blind_uid));
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
-    assert_eq!(table.deal_stage, DealStage::River);
+    assert_eq!(table.deal_stage, DealStage::Turn);
 
     assert!(is_it_users_turn(&table, small_blind_uid));
 
@@ -714,6 +708,12 @@ fn two_users_check_to_showdown_two_rounds() {
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
+    assert_eq!(table.deal_stage, DealStage::River);
+
+    assert!(is_it_users_turn(&table, small_blind_uid));
+
+    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
+
     assert_eq!(table.deal_stage, DealStage::Showdown);
 
     assert!(table
@@ -735,17 +735,11 @@ fn two_users_check_to_showdown_two_rounds() {
 
     assert_eq!(table.deal_stage, DealStage::Turn);
 
-    assert!(is_it_users_turn(&table, small_blind_uid));
-
-    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
-
-    assert_eq!(table.deal_stage, DealStage::Turn);
-
     assert!(is_it_users_turn(&table, big_blind_uid));
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
-    assert_eq!(table.deal_stage, DealStage::River);
+    assert_eq!(table.deal_stage, DealStage::Turn);
 
     assert!(is_it_users_turn(&table, small_blind_uid));
 
@@ -757,6 +751,12 @@ fn two_users_check_to_showdown_two_rounds() {
 
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
+    assert_eq!(table.deal_stage, DealStage::River);
+
+    assert!(is_it_users_turn(&table, small_blind_uid));
+
+    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
+
     assert_eq!(table.deal_stage, DealStage::Showdown);
 }
 
@@ -1731,9 +1731,10 @@ fn turn_test_sitting_out_are_blinds_eaten() {
 
     assert_eq!(table.deal_stage, DealStage::Flop);
 
+    // With them sitting out the other two play heads-up, so no blind is taken from them
     assert_eq!(
         table.users.get(&small_blind_uid).unwrap().balance.0,
-        convert_to_e8s(97.0)
+        convert_to_e8s(98.0)
     );
 
     assert_eq!(
@@ -2238,26 +2239,26 @@ fn heads_up_all_in() {
     assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
     assert_eq!(table.deal_stage, DealStage::Turn);
-    assert!(is_it_users_turn(&table, small_blind_uid));
-    assert_eq!(table.user_check(small_blind_uid, false), Ok(()));
+    assert!(is_it_users_turn(&table, big_blind_uid));
+    assert_eq!(table.user_check(big_blind_uid, false), Ok(()));
 
     assert_eq!(table.deal_stage, DealStage::Turn);
-    assert!(is_it_users_turn(&table, big_blind_uid));
+    assert!(is_it_users_turn(&table, small_blind_uid));
     assert_eq!(
-        table.bet(big_blind_uid, BetType::Raised(convert_to_e8s(98.0))),
+        table.bet(small_blind_uid, BetType::Raised(convert_to_e8s(98.0))),
         Ok(())
     );
     assert_eq!(
         table
-            .get_user_table_data(big_blind_uid)
+            .get_user_table_data(small_blind_uid)
             .unwrap()
             .player_action,
         PlayerAction::AllIn
     );
 
     assert_eq!(table.deal_stage, DealStage::Turn);
-    assert!(is_it_users_turn(&table, small_blind_uid));
-    assert_eq!(table.bet(small_blind_uid, BetType::Called), Ok(()));
+    assert!(is_it_users_turn(&table, big_blind_uid));
+    assert_eq!(table.bet(big_blind_uid, BetType::Called), Ok(()));
 
     assert_eq!(table.deal_stage, DealStage::Showdown);
 }
@@ -0,0 +1,214 @@
+use candid::Principal;
+use errors::game_error::GameError;
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::{
+    table_functions::{
+        action_log::ActionType,
+        table::{Table, TableType},
+        tests::{self, create_user, current_player, get_table_config},
+        types::{NotificationMessage, SeatStatus},
+        waitlist::SEAT_OFFER_WINDOW_NS,
+    },
+    types::{GameType, QueueItem},
+    utils::convert_to_e8s,
+};
+
+/// Seats three players at a table with the given number of seats.
+fn setup_table(seats: u8) -> (Table, Vec<WalletPrincipalId>) {
+    tests::setup_table(
+        get_table_config(GameType::NoLimit(convert_to_e8s(1.0)), seats),
+        &[100.0; 3],
+    )
+}
+
+fn waiting_user(id: u8) -> WalletPrincipalId {
+    WalletPrincipalId(Principal::from_slice(&[id]))
+}
+
+fn is_reserved_for(table: &Table, seat_index: usize, uid: WalletPrincipalId) -> bool {
+    matches!(
+        table.seats[seat_index],
+        SeatStatus::Reserved { principal, .. } if principal == uid
+    )
+}
+
+#[test]
+fn test_open_seat_is_offered_in_waitlist_order() {
+    let (mut table, uids) = setup_table(3);
+    let (first, second) = (waiting_user(10), waiting_user(11));
+    assert_eq!(table.join_waitlist(first, 0), Ok(()));
+    assert_eq!(table.join_waitlist(second, 1), Ok(()));
+    assert_eq!(table.get_waitlist_position(first), Some(0));
+    assert_eq!(table.get_waitlist_position(second), Some(1));
+
+    assert!(table.remove_user(uids[1], ActionType::Leave).is_ok());
+
+    assert!(is_reserved_for(&table, 1, first));
+    assert_eq!(table.get_reserved_seat_index(first), Some(1));
+    assert_eq!(table.get_waitlist_position(second), Some(0));
+    let notification = table.notifications.notifications.last().unwrap();
+    assert_eq!(notification.user_principal, first);
+    assert!(matches!(
+        notification.message,
+        NotificationMessage::SeatOffered { seat_index: 1, .. }
+    ));
+
+    // The held seat is not free for anyone else
+    assert_eq!(table.get_free_seat_index(), None);
+    let user = create_user(second.0, convert_to_e8s(100.0));
+    assert!(table.add_user(user, 1, false).is_err());
+
+    let user = create_user(first.0, convert_to_e8s(100.0));
+    assert!(table.add_user(user, 1, false).is_ok());
+    assert_eq!(table.seats[1], SeatStatus::Occupied(first));
+    assert_eq!(table.waitlist.len(), 1);
+}
+
+#[test]
+fn test_rejected_add_user_keeps_the_held_seat() {
+    let (mut table, uids) = setup_table(4);
+    table.seats[3] = SeatStatus::Reserved {
+        principal: uids[0],
+        timestamp: 0,
+    };
+
+    let user = create_user(uids[0].0, convert_to_e8s(100.0));
+    assert_eq!(
+        table.add_user(user, 3, false).map_err(|e| e.into_inner()),
+        Err(GameError::UserAlreadyInGame)
+    );
+    assert!(is_reserved_for(&table, 3, uids[0]));
+}
+
+#[test]
+fn test_expired_seat_offer_moves_to_the_next_player() {
+    let (mut table, uids) = setup_table(3);
+    let (first, second) = (waiting_user(10), waiting_user(11));
+    assert_eq!(table.join_waitlist(first, 0), Ok(()));
+    assert_eq!(table.join_waitlist(second, 0), Ok(()));
+    assert!(table.remove_user(uids[0], ActionType::Leave).is_ok());
+    let SeatStatus::Reserved { timestamp, .. } = table.seats[0] else {
+        panic!("seat 0 should be held for the first player");
+    };
+
+    // Still inside the window
+    table.offer_open_seats(timestamp + SEAT_OFFER_WINDOW_NS - 1);
+    assert!(is_reserved_for(&table, 0, first));
+
+    table.offer_open_seats(timestamp + SEAT_OFFER_WINDOW_NS);
+    assert!(is_reserved_for(&table, 0, second));
+    assert!(table.waitlist.is_empty());
+    assert_eq!(table.get_reserved_seat_index(first), None);
+
+    // Nobody left in line, so the seat opens up
+    table.offer_open_seats(timestamp + 2 * SEAT_OFFER_WINDOW_NS);
+    assert_eq!(table.seats[0], SeatStatus::Empty);
+}
+
+#[test]
+fn test_leaving_the_waitlist_passes_the_seat_on() {
+    let (mut table, uids) = setup_table(3);
+    let (first, second) = (waiting_user(10), waiting_user(11));
+    assert_eq!(table.join_waitlist(first, 0), Ok(()));
+    assert_eq!(table.join_waitlist(second, 0), Ok(()));
+    assert!(table.remove_user(uids[2], ActionType::Leave).is_ok());
+
+    assert_eq!(table.leave_waitlist(first, 0), Ok(()));
+    assert!(is_reserved_for(&table, 2, second));
+
+    assert_eq!(table.leave_waitlist(second, 0), Ok(()));
+    assert_eq!(table.seats[2], SeatStatus::Empty);
+    assert_eq!(
+        table.leave_waitlist(second, 0).map_err(|e| e.into_inner()),
+        Err(GameError::PlayerNotFound)
+    );
+}
+
+#[test]
+fn test_join_waitlist_with_a_free_seat_offers_it_straight_away() {
+    let (mut table, _) = setup_table(4);
+    let uid = waiting_user(10);
+    assert_eq!(table.join_waitlist(uid, 0), Ok(()));
+
+    assert!(is_reserved_for(&table, 3, uid));
+    assert!(table.waitlist.is_empty());
+}
+
+#[test]
+fn test_join_waitlist_is_rejected() {
+    let (mut table, uids) = setup_table(3);
+    assert_eq!(
+        table.join_waitlist(uids[0], 0).map_err(|e| e.into_inner()),
+        Err(GameError::UserAlreadyInGame)
+    );
+
+    let uid = waiting_user(10);
+    assert_eq!(table.join_waitlist(uid, 0), Ok(()));
+    assert!(matches!(
+        table.join_waitlist(uid, 0).map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+
+    table.config.table_type = Some(TableType::Tournament {
+        tournament_id: Principal::anonymous(),
+        is_final_table: false,
+    });
+    assert!(matches!(
+        table
+            .join_waitlist(waiting_user(11), 0)
+            .map_err(|e| e.into_inner()),
+        Err(GameError::ActionNotAllowed { .. })
+    ));
+}
+
+#[test]
+fn test_seat_change_between_hands_is_immediate() {
+    let (mut table, uids) = setup_table(4);
+    assert_eq!(table.request_seat_change(uids[0], 3, 0), Ok(()));
+
+    assert_eq!(table.seats[0], SeatStatus::Empty);
+    assert_eq!(table.seats[3], SeatStatus::Occupied(uids[0]));
+    assert_eq!(
+        table.action_logs.last().unwrap().action_type,
+        ActionType::SeatChange { seat_index: 3 }
+    );
+
+    assert!(matches!(
+        table
+            .request_seat_change(uids[1], 2, 0)
+            .map_err(|e| e.into_inner()),
+        Err(GameError::Other(_))
+    ));
+    assert!(matches!(
+        table
+            .request_seat_change(waiting_user(10), 0, 0)
+            .map_err(|e| e.into_inner()),
+        Err(GameError::PlayerNotFound)
+    ));
+}
+
+#[test]
+fn test_seat_change_during_a_hand_waits_for_the_hand_to_end() {
+    let (mut table, uids) = setup_table(4);
+    assert!(table.start_betting_round(vec![0, 1, 2]).is_ok());
+
+    assert_eq!(table.request_seat_change(uids[0], 3, 0), Ok(()));
+    // Asking again replaces the earlier request
+    assert_eq!(table.request_seat_change(uids[0], 3, 0), Ok(()));
+    assert_eq!(table.queue.len(), 1);
+    assert!(matches!(
+        table.queue[0],
+        QueueItem::SeatChange(uid, 3) if uid == uids[0]
+    ));
+    assert_eq!(table.seats[0], SeatStatus::Occupied(uids[0]));
+
+    while table.is_game_ongoing() {
+        let uid = current_player(&table);
+        assert_eq!(table.user_fold(uid, false), Ok(()));
+    }
+    assert!(table.handle_queue_items().is_ok());
+
+    assert_eq!(table.seats[0], SeatStatus::Empty);
+    assert_eq!(table.seats[3], SeatStatus::Occupied(uids[0]));
+}
@@ -0,0 +1,200 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use super::{
+    table::Table,
+    types::{NotificationMessage, SeatStatus},
+};
+
+/// A reserve of seconds every player can use once their turn timer runs out.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct TimeBankConfig {
+    /// The seconds in a player's time bank when they join the table.
+    pub initial_seconds: u16,
+    /// The most seconds a time bank can be refilled to.
+    pub max_seconds: u16,
+    /// The seconds added to every time bank on each refill.
+    pub refill_seconds: u16,
+    /// When the time banks are refilled.
+    pub refill: TimeBankRefill,
+    /// Whether the time bank is used as soon as the turn timer runs out.
+    /// Otherwise the player has to ask for it during their turn.
+    pub is_automatic: bool,
+}
+
+/// When the time banks of a table are refilled.
+#[derive(Debug, Clone, Copy, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub enum TimeBankRefill {
+    /// After every given number of hands.
+    EveryHands(u16),
+    /// Whenever the blinds go up in a tournament.
+    EveryLevel,
+}
+
+impl Table {
+    /// The seconds left in the user's time bank.
+    pub fn get_time_bank_seconds(&self, user_principal: WalletPrincipalId) -> u16 {
+        let Some(time_bank) = &self.config.time_bank else {
+            return 0;
+        };
+        self.user_table_data
+            .get(&user_principal)
+            .and_then(|data| data.time_bank_seconds)
+            .unwrap_or(time_bank.initial_seconds)
+    }
+
+    /// Asks for the time bank to be used once the turn timer of the user runs out.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user whose turn it is
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table has no time bank, it is not the user's turn
+    ///   or their time bank is empty
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn request_time_bank(
+        &mut self,
+        user_principal: WalletPrincipalId,
+    ) -> Result<(), TracedError<GameError>> {
+        if self.config.time_bank.is_none() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "This table has no time bank".to_string(),
+            })));
+        }
+        if !self.is_game_ongoing() || !self.is_players_turn(user_principal) {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Not your turn".to_string(),
+            })));
+        }
+        if self.get_time_bank_seconds(user_principal) == 0 {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Your time bank is empty".to_string(),
+            })));
+        }
+
+        self.get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to request time bank."))?
+            .is_time_bank_requested = Some(true);
+        Ok(())
+    }
+
+    /// Starts using the time bank of the user when their turn timer runs out.
+    ///
+    /// Returns the seconds to extend the turn by, or `None` when the user cannot
+    /// use their time bank, in which case the turn times out as before.
+    /// A time bank that was already in use is emptied.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user whose timer ran out
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::Other`] if the user table data cannot be retrieved
+    pub fn start_time_bank(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        now: u64,
+    ) -> Result<Option<u16>, TracedError<GameError>> {
+        let Some(is_automatic) = self.config.time_bank.as_ref().map(|tb| tb.is_automatic) else {
+            return Ok(None);
+        };
+        if !self.is_players_turn(user_principal) {
+            return Ok(None);
+        }
+        let seconds = self.get_time_bank_seconds(user_principal);
+        let user_table_data = self
+            .get_user_table_data_mut(user_principal)
+            .map_err(|e| trace_err!(e, "Failed to get user table data to start time bank."))?;
+
+        if user_table_data.is_using_time_bank == Some(true) {
+            user_table_data.time_bank_seconds = Some(0);
+            user_table_data.is_using_time_bank = None;
+            user_table_data.is_time_bank_requested = None;
+            return Ok(None);
+        }
+        if seconds == 0 || !(is_automatic || user_table_data.is_time_bank_requested == Some(true)) {
+            return Ok(None);
+        }
+
+        user_table_data.is_using_time_bank = Some(true);
+        self.last_timer_started_timestamp = now;
+        self.notifications.add_notification(
+            user_principal,
+            NotificationMessage::TimeBankStarted { seconds },
+        );
+        Ok(Some(seconds))
+    }
+
+    /// Takes the time the user spent on their time bank this turn out of it.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user who acted
+    /// - `now` - The current time in nanoseconds
+    pub fn stop_time_bank(&mut self, user_principal: WalletPrincipalId, now: u64) {
+        let seconds = self.get_time_bank_seconds(user_principal);
+        let started = self.last_timer_started_timestamp;
+        let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) else {
+            return;
+        };
+
+        if user_table_data.is_using_time_bank == Some(true) {
+            let used_seconds = now.saturating_sub(started).div_ceil(1_000_000_000);
+            let used_seconds = u16::try_from(used_seconds).unwrap_or(u16::MAX);
+            user_table_data.time_bank_seconds = Some(seconds.saturating_sub(used_seconds));
+        }
+        user_table_data.is_using_time_bank = None;
+        user_table_data.is_time_bank_requested = None;
+    }
+
+    /// Refills the time banks when a hand starts, if they refill every few hands.
+    pub(crate) fn refill_time_banks_for_hand(&mut self) {
+        if let Some(TimeBankRefill::EveryHands(hands)) =
+            self.config.time_bank.as_ref().map(|tb| tb.refill)
+        {
+            if hands > 0 && self.round_ticker.is_multiple_of(u64::from(hands)) {
+                self.refill_time_banks();
+            }
+        }
+    }
+
+    /// Refills the time banks when the blinds go up, if they refill every level.
+    pub(crate) fn refill_time_banks_for_level(&mut self) {
+        if let Some(TimeBankRefill::EveryLevel) = self.config.time_bank.as_ref().map(|tb| tb.refill)
+        {
+            self.refill_time_banks();
+        }
+    }
+
+    /// Adds the refill seconds to the time bank of every seated player, up to the maximum.
+    fn refill_time_banks(&mut self) {
+        let Some(time_bank) = self.config.time_bank.clone() else {
+            return;
+        };
+        let user_principals: Vec<WalletPrincipalId> = self
+            .seats
+            .iter()
+            .filter_map(|seat| match seat {
+                SeatStatus::Occupied(user_principal) => Some(*user_principal),
+                _ => None,
+            })
+            .collect();
+
+        for user_principal in user_principals {
+            let seconds = self.get_time_bank_seconds(user_principal);
+            if let Some(user_table_data) = self.user_table_data.get_mut(&user_principal) {
+                user_table_data.time_bank_seconds = Some(
+                    seconds
+                        .saturating_add(time_bank.refill_seconds)
+                        .min(time_bank.max_seconds.max(seconds)),
+                );
+            }
+        }
+    }
+}
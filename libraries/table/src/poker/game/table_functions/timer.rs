@@ -1,12 +1,21 @@
 use std::time::Duration;
 
+use candid::Principal;
 use errors::{game_error::GameError, table_error::TableError};
 use ic_cdk::futures::spawn;
 use user::user::WalletPrincipalId;
 
-use crate::table_canister::{handle_timer_expiration_wrapper, start_new_betting_round_wrapper};
+use crate::table_canister::{
+    close_run_it_window_wrapper, handle_table_hand_finished_wrapper,
+    handle_timer_expiration_wrapper, start_new_betting_round_wrapper,
+};
 
-use super::table::Table;
+use super::table::{Table, TableId};
+
+/// How often a table tries to tell the tournament it finished its hand-for-hand hand.
+const MAX_HAND_FINISHED_REPORTS: u32 = 12;
+/// The seconds between two tries.
+const HAND_FINISHED_RETRY_DELAY_SECONDS: u64 = 5;
 
 impl Table {
     /// Sets a turn timer for a given user on a table.
@@ -78,6 +87,11 @@ impl Table {
                                     == TableError::Game(GameError::ActionNotAllowed {
                                         reason: "Game is paused".to_string(),
                                     })
+                                || err
+                                    == TableError::Game(GameError::ActionNotAllowed {
+                                        reason: "Waiting for the other tables to finish their hand"
+                                            .to_string(),
+                                    })
                             {
                                 return;
                             }
@@ -94,6 +108,36 @@ impl Table {
         });
     }
 
+    /// Sets a timer to run out the board once the run it window closes.
+    ///
+    /// # Parameters
+    ///
+    /// - `delay_ns`: The nanoseconds until the window closes
+    pub fn start_run_it_window_timer(&self, delay_ns: u64) {
+        let delay = Duration::from_nanos(delay_ns);
+
+        let table_principal = self.id;
+        let _: ic_cdk_timers::TimerId = ic_cdk_timers::set_timer(delay, move || {
+            spawn(async move {
+                if let Err(err) = close_run_it_window_wrapper(table_principal).await {
+                    ic_cdk::println!("Error closing the run it window: {:?}", err);
+                }
+            })
+        });
+    }
+
+    /// Tells the tournament the table finished its hand-for-hand hand.
+    ///
+    /// The table only deals again once the tournament starts its next hand, so a
+    /// report that fails is tried again a few seconds later.
+    ///
+    /// # Parameters
+    ///
+    /// - `tournament_id`: The principal of the tournament canister
+    pub fn report_hand_finished(&self, tournament_id: Principal) {
+        report_hand_finished(tournament_id, self.id, self.get_busted_players(), 1);
+    }
+
     /// Clears the timer for a user.
     pub fn clear_turn_timer(&mut self) {
         if let Some(timer_id) = self.timer {
@@ -102,3 +146,35 @@ impl Table {
         }
     }
 }
+
+fn report_hand_finished(
+    tournament_id: Principal,
+    table_id: TableId,
+    busted_players: Vec<(WalletPrincipalId, u64)>,
+    attempt: u32,
+) {
+    spawn(async move {
+        let err = match handle_table_hand_finished_wrapper(
+            tournament_id,
+            table_id,
+            busted_players.clone(),
+        )
+        .await
+        {
+            Ok(_) => return,
+            Err(err) => err,
+        };
+        if attempt >= MAX_HAND_FINISHED_REPORTS {
+            ic_cdk::println!("Error reporting the finished hand, giving up: {:?}", err);
+            return;
+        }
+        ic_cdk::println!(
+            "Error reporting the finished hand: {:?}\nAttempting retry after delay...",
+            err
+        );
+        let delay = Duration::from_secs(HAND_FINISHED_RETRY_DELAY_SECONDS);
+        let _: ic_cdk_timers::TimerId = ic_cdk_timers::set_timer(delay, move || {
+            report_hand_finished(tournament_id, table_id, busted_players, attempt + 1);
+        });
+    });
+}
@@ -9,11 +9,27 @@ use super::{
 
 impl Table {
     /// Check if the table should go to the next round or showdown
+    ///
+    /// Once the turn is settled, the pre-action of the player whose turn it is is taken.
     pub fn check_next_turn_or_showdown(&mut self) -> Result<(), TracedError<GameError>> {
+        self.handle_next_turn_or_showdown()?;
+        self.run_pre_action();
+        Ok(())
+    }
+
+    /// Goes to the next round or showdown for [`Table::check_next_turn_or_showdown`].
+    fn handle_next_turn_or_showdown(&mut self) -> Result<(), TracedError<GameError>> {
         if self.go_to_next_round().map_err(|e| trace_err!(e, ""))?
             && self.deal_stage != DealStage::Showdown
         {
             self.calculate_pots().map_err(|e| trace_err!(e, ""))?;
+            if self.is_stud() {
+                // The best hand showing acts first on every street after third street
+                self.deal_cards(false).map_err(|e| trace_err!(e, ""))?;
+                self.set_current_player_to_best_visible_hand()
+                    .map_err(|e| trace_err!(e, ""))?;
+                return Ok(());
+            }
             if self.deal_stage == DealStage::Flop
                 || self.deal_stage == DealStage::Turn
                 || self.deal_stage == DealStage::River
@@ -178,6 +194,9 @@ impl Table {
             return Ok(false);
         }
 
+        // The big blind, or the straddler, gets the last option before the flop
+        let pre_flop_option_user_principal = self.get_pre_flop_option_user_principal();
+
         // Now, check if all players have completed their actions for the current betting round
         for user_principal in self.seats.iter() {
             if let SeatStatus::Occupied(user_principal) = user_principal {
@@ -210,7 +229,7 @@ impl Table {
                     
                     if let Some(amount) = action_amount {
                         if amount == highest_bet
-                            && self.big_blind_user_principal == *user_principal
+                            && pre_flop_option_user_principal == *user_principal
                             && self.deal_stage == DealStage::Flop
                         {
                             return Ok(false);
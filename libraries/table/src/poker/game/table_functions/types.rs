@@ -4,6 +4,13 @@ use serde::{Deserialize, Serialize};
 use user::user::{User, WalletPrincipalId};
 
 use crate::poker::core::Card;
+pub use crate::poker::core::DealStage;
+
+use super::{
+    auto_rebuy::AutoRebuy,
+    dead_button::{MissedBlinds, MissedBlindsChoice},
+    pre_action::PreAction,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq)]
 pub enum SeatStatus {
@@ -23,25 +30,6 @@ pub enum CurrencyType {
     Fake,
 }
 
-/// The different stages of a deal.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
-pub enum DealStage {
-    /// Start a new deal.
-    Fresh = 0,
-    /// Place the blinds.
-    Blinds = 1,
-    /// Deal 2 cards to each player.
-    Opening = 2,
-    /// Deal 3 community cards.
-    Flop = 3,
-    /// Deal 1 community card.
-    Turn = 4,
-    /// Deal 1 community card.
-    River = 5,
-    /// Showdown stage.
-    Showdown = 6,
-}
-
 /// The different actions a player can take.
 /// The u64 corresponds to the amount of the raised bet.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, CandidType)]
@@ -80,9 +68,25 @@ pub enum BetType {
     SmallBlind,
     /// Bet is equal to the ante.
     Ante(u64),
+    /// Bet is equal to twice the big blind, posted before the cards are dealt.
+    Straddle,
+}
+
+impl BetType {
+    /// Whether the bet is a blind or straddle posted before the cards are dealt.
+    pub fn is_forced(&self) -> bool {
+        matches!(
+            self,
+            BetType::SmallBlind | BetType::BigBlind | BetType::Straddle
+        )
+    }
 }
 
 /// Data for a user at the table.
+///
+/// [`UserTableData::reset`] clears the state of the last hand between hands. The
+/// settings the user chooses, like straddling or auto-rebuy, are kept until
+/// they change them.
 #[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
 pub struct UserTableData {
     /// The hand of the user.
@@ -103,6 +107,32 @@ pub struct UserTableData {
     pub experience_points: u64,
     /// Whether the user has auto check fold enabled.
     pub auto_check_fold: bool,
+    /// The cards of the hand dealt face up in stud games.
+    /// These are also part of `cards`.
+    pub up_cards: Option<Vec<Card>>,
+    /// How many times the user agreed to run the board if everyone is all in this hand.
+    pub run_it_times: Option<u8>,
+    /// Whether the user posts a straddle when they are in a position to.
+    pub wants_to_straddle: Option<bool>,
+    /// The blinds the user owes for missing them on a cash table.
+    pub missed_blinds: Option<MissedBlinds>,
+    /// Whether the user posts their missed blinds or waits for the big blind when sitting back in.
+    pub missed_blinds_choice: Option<MissedBlindsChoice>,
+    /// Whether the user sat back in and is sitting out until the big blind reaches them.
+    pub is_waiting_for_big_blind: Option<bool>,
+    /// The seconds left in the user's time bank, `None` while it is still full.
+    pub time_bank_seconds: Option<u16>,
+    /// Whether the user is using their time bank this turn.
+    pub is_using_time_bank: Option<bool>,
+    /// Whether the user asked to use their time bank this turn.
+    pub is_time_bank_requested: Option<bool>,
+    /// The action the user takes once the turn reaches them this stage.
+    /// Only visible to the user.
+    pub pre_action: Option<PreAction>,
+    /// Which hole cards the user shows after the hand, in the order they were dealt.
+    pub shown_cards: Option<Vec<bool>>,
+    /// When and how far the user's stack is topped up from their wallet between hands.
+    pub auto_rebuy: Option<AutoRebuy>,
 }
 
 /// Data for a card request.
@@ -134,9 +164,43 @@ impl UserTableData {
             seated_out_turns: 0,
             experience_points: 0,
             auto_check_fold: false,
+            up_cards: None,
+            run_it_times: None,
+            wants_to_straddle: None,
+            missed_blinds: None,
+            missed_blinds_choice: None,
+            is_waiting_for_big_blind: None,
+            time_bank_seconds: None,
+            is_using_time_bank: None,
+            is_time_bank_requested: None,
+            pre_action: None,
+            shown_cards: None,
+            auto_rebuy: None,
         }
     }
 
+    /// The cards every player at the table can see.
+    pub fn visible_cards(&self) -> Vec<Card> {
+        let up_cards = self.up_cards.clone().unwrap_or_default();
+        let Some(shown_cards) = &self.shown_cards else {
+            return up_cards;
+        };
+        self.cards
+            .iter()
+            .zip(shown_cards)
+            .filter(|(card, shown)| **shown || up_cards.contains(card))
+            .map(|(card, _)| *card)
+            .collect()
+    }
+
+    /// Is the user dealt into the hand, rather than sitting out?
+    ///
+    /// A player who has just joined is still waiting on the next hand, which
+    /// they are dealt into, so they count.
+    pub fn is_dealt_in(&self) -> bool {
+        self.player_action != PlayerAction::SittingOut
+    }
+
     /// Resets the user table data to the default values.
     pub fn reset(&mut self) {
         self.cards.clear();
@@ -146,6 +210,12 @@ impl UserTableData {
         self.show_card_requests.clear();
         self.experience_points = 0;
         self.auto_check_fold = false;
+        self.up_cards = None;
+        self.run_it_times = None;
+        self.is_using_time_bank = None;
+        self.is_time_bank_requested = None;
+        self.pre_action = None;
+        self.shown_cards = None;
     }
 }
 
@@ -200,6 +270,23 @@ pub struct Notification {
 #[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
 pub enum NotificationMessage {
     UserTurnStarted,
+    /// The turn timer ran out and the user is now using their time bank.
+    TimeBankStarted {
+        seconds: u16,
+    },
+    /// A seat opened up for the user on the waitlist and is held for them until `expires_at`.
+    SeatOffered {
+        seat_index: u8,
+        expires_at: u64,
+    },
+    /// The user's auto-rebuy could not be taken from their wallet.
+    AutoRebuyFailed {
+        reason: String,
+    },
+    /// Everyone is all in and the user can agree to run the board more than once until `expires_at`.
+    RunItWindowOpened {
+        expires_at: u64,
+    },
 }
 
 impl Notification {
@@ -183,11 +183,27 @@ impl Table {
             ))));
         }
 
-        // Check if user is already at the table
-        if self.is_user_in_table(user.principal_id) {
+        // Check if user is already at the table, other than in a seat held for them
+        let reserved_seat_index = self.get_reserved_seat_index(user.principal_id);
+        if self.seats.iter().enumerate().any(|(index, status)| {
+            Some(index as u8) != reserved_seat_index
+                && matches!(status,
+                    SeatStatus::Occupied(p) | SeatStatus::QueuedForNextRound(p, _, _)
+                    if *p == user.principal_id
+                )
+        }) {
             return Err(trace_err!(TracedError::new(GameError::UserAlreadyInGame)));
         }
 
+        // A user from the waitlist sits in the seat held for them or in another free seat
+        if let Some(reserved_seat_index) = reserved_seat_index {
+            if reserved_seat_index == seat_index
+                || matches!(self.seats[seat_index as usize], SeatStatus::Empty)
+            {
+                self.seats[reserved_seat_index as usize] = SeatStatus::Empty;
+            }
+        }
+
         // Verify the seat is available
         match self.seats[seat_index as usize] {
             SeatStatus::Empty => {
@@ -214,9 +230,16 @@ impl Table {
 
                     // Add user and log action
                     self.users.add_user(user.clone())?;
+                    self.set_new_player_missed_blinds(user.principal_id);
                 }
 
                 self.log_action(Some(user.principal_id), ActionType::Join);
+                if let Some(position) = self.get_waitlist_position(user.principal_id) {
+                    self.waitlist.remove(position);
+                }
+                if reserved_seat_index.is_some_and(|index| index != seat_index) {
+                    self.offer_open_seats(user::user::time());
+                }
 
                 // Set dealer position if this is the first hand
                 if self.round_ticker == 0 && !self.is_game_ongoing() {
@@ -283,9 +306,12 @@ impl Table {
                 self.seats[player_seat_index] = SeatStatus::Empty;
             }
             // Clean up user data
+            let now = user::user::time();
             self.log_action(Some(user_principal), action_type);
+            self.record_departure(user_principal, now);
             self.user_table_data.remove(&user_principal);
             self.users.remove_user(user_principal);
+            self.offer_open_seats(now);
         }
 
         Ok(())
@@ -457,6 +483,10 @@ impl Table {
         amount: u64,
     ) -> Result<Option<UserBalance>, TracedError<GameError>> {
         if self.check_user_balance(amount, user_principal).is_err() {
+            // They stay seated until their auto-rebuy goes through
+            if self.is_waiting_for_auto_rebuy(user_principal) {
+                return Ok(None);
+            }
             let balance = self.kick_user(user_principal, "Insufficient funds".to_string())?;
             return Ok(Some(balance));
         }
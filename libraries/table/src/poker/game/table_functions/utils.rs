@@ -42,16 +42,48 @@ impl Table {
         ))))
     }
 
+    /// Are exactly two players dealt in?
+    ///
+    /// Heads-up the button posts the small blind, so it acts first preflop
+    /// and last on every later street. Unlike [`TableConfig::is_heads_up`]
+    /// this counts the players in the hand, so a table of three with one
+    /// player sitting out plays heads-up.
+    ///
+    /// [`TableConfig::is_heads_up`]: super::table::TableConfig::is_heads_up
+    pub fn is_heads_up_hand(&self) -> bool {
+        self.seats
+            .iter()
+            .filter(|seat| match seat {
+                SeatStatus::Occupied(user_principal) => self
+                    .user_table_data
+                    .get(user_principal)
+                    .is_some_and(|data| data.is_dealt_in()),
+                _ => false,
+            })
+            .count()
+            == 2
+    }
+
     /// Returns the principal of the player who is the small blind.
+    ///
+    /// This is the first player after the button, or the button itself when heads-up.
     pub fn get_small_blind_user_principal(
         &self,
     ) -> Result<WalletPrincipalId, TracedError<GameError>> {
-        let mut index = (self.dealer_position + 1) % (self.config.seats as usize);
+        let is_heads_up_hand = self.is_heads_up_hand();
+        let mut index = if is_heads_up_hand {
+            self.dealer_position
+        } else {
+            (self.dealer_position + 1) % (self.config.seats as usize)
+        };
 
         for _ in 0..self.config.seats {
             if let SeatStatus::Occupied(user_principal) = self.seats[index] {
-                if self.user_table_data.contains_key(&user_principal) {
-                    return Ok(user_principal);
+                if let Some(data) = self.user_table_data.get(&user_principal) {
+                    // Heads-up the blinds skip a player sitting out on the button
+                    if !is_heads_up_hand || data.is_dealt_in() {
+                        return Ok(user_principal);
+                    }
                 }
             }
             index = (index + 1) % self.seats.len();
@@ -65,8 +97,32 @@ impl Table {
     pub fn get_big_blind_user_principal(
         &self,
     ) -> Result<WalletPrincipalId, TracedError<GameError>> {
-        let mut index = (self.dealer_position + 1) % self.seats.len();
+        if self.is_heads_up_hand() {
+            // Heads-up the big blind is the other player dealt in
+            let small_blind_uid = self.get_small_blind_user_principal()?;
+            return self
+                .seats
+                .iter()
+                .find_map(|seat| match seat {
+                    SeatStatus::Occupied(user_principal)
+                        if *user_principal != small_blind_uid
+                            && self
+                                .user_table_data
+                                .get(user_principal)
+                                .is_some_and(|data| data.is_dealt_in()) =>
+                    {
+                        Some(*user_principal)
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    trace_err!(TracedError::new(GameError::Other(
+                        "Big blind not found".to_string()
+                    )))
+                });
+        }
 
+        let mut index = (self.dealer_position + 1) % self.seats.len();
         let mut found_small_blind = false;
         for _ in 0..self.config.seats * 2 {
             if let SeatStatus::Occupied(user_principal) = self.seats[index] {
@@ -160,6 +216,7 @@ impl Table {
                 .get_player_at_seat(self.current_player_index)
                 .map_err(|e| trace_err!(e, "Failed to get current player after loop."))?;
 
+            self.stop_time_bank(previous_player, ic_cdk::api::time());
             if previous_player == current_player {
                 return Ok(());
             }
@@ -235,8 +292,39 @@ impl Table {
             ))));
         }
 
+        if let Some(straddle_user_principal) = self.straddle_user_principal {
+            // The player left of the straddler acts first
+            let straddle_index = self
+                .seats
+                .iter()
+                .position(|seat| {
+                    matches!(seat, SeatStatus::Occupied(principal) if *principal == straddle_user_principal)
+                })
+                .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound)))?;
+            let mut starting_player_index = straddle_index;
+            for _ in 0..self.seats.len() {
+                starting_player_index = (starting_player_index + 1) % self.seats.len();
+                if matches!(self.seats[starting_player_index], SeatStatus::Occupied(_)) {
+                    return Ok(starting_player_index);
+                }
+            }
+        }
+
+        if self.uses_dead_button() && self.get_playing_users()? > 2 {
+            if let Some(big_blind_index) = self.get_seat_index(self.big_blind_user_principal) {
+                // The small blind or the button can be dead, so count from the big blind
+                let mut starting_player_index = big_blind_index as usize;
+                for _ in 0..self.seats.len() {
+                    starting_player_index = (starting_player_index + 1) % self.seats.len();
+                    if matches!(self.seats[starting_player_index], SeatStatus::Occupied(_)) {
+                        return Ok(starting_player_index);
+                    }
+                }
+            }
+        }
+
         if num_players == 2 {
-            // Heads-up play: find the small blind
+            // Heads-up play: the small blind is on the button and acts first
             return self.calculate_small_blind_user_index();
         } else if num_players == 3 {
             return Ok(self.dealer_position);
@@ -389,6 +477,7 @@ impl Table {
                     Some(table_data) => {
                         table_data.total_bet += table_data.current_total_bet;
                         table_data.current_total_bet = 0;
+                        table_data.pre_action = None;
                         if table_data.player_action != PlayerAction::Folded
                             && table_data.player_action != PlayerAction::AllIn
                             && table_data.player_action != PlayerAction::SittingOut
@@ -503,6 +592,7 @@ impl Table {
             .map_err(|e| trace_err!(e, "Could not get user table data to update highest bet."))?;
         if user_table_data.current_total_bet > self.highest_bet {
             self.highest_bet = user_table_data.current_total_bet;
+            self.cancel_pre_actions_on_raise();
         }
         Ok(())
     }
@@ -529,7 +619,7 @@ impl Table {
                     .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound), ""))?;
                 Ok(user.balance.0 < min)
             }
-            GameType::FixedLimit(min, _) => {
+            GameType::FixedLimit(min, _) | GameType::SevenCardStud(min, _) => {
                 let user = self
                     .users
                     .get(&user_principal)
@@ -654,6 +744,7 @@ impl Table {
             self.users.add_user(*user).map_err(|e| {
                 trace_err!(e, "Error adding user to users in activate queued players.")
             })?;
+            self.set_new_player_missed_blinds(*principal);
 
             // Update player action from Joining to None
             if let Some(table_data) = self.user_table_data.get_mut(principal) {
@@ -696,8 +787,35 @@ impl Table {
         Ok(highest_bet)
     }
 
-    /// Cycles through the deal stages to the showdown
+    /// Logs the all in equities and cycles through the deal stages to the showdown
     pub fn cycle_to_showdown(&mut self) -> Result<(), TracedError<GameError>> {
+        if self.is_won_uncontested() {
+            // The board is left for a rabbit hunt after the hand
+            self.prepare_user_actions(true).map_err(|e| {
+                trace_err!(e, "Failed to prepare user actions in cycle to showdown.")
+            })?;
+            self.deal_stage = DealStage::Showdown;
+            return self.showdown().map_err(|e| trace_err!(e, ""));
+        }
+        self.log_all_in_equities();
+        if self
+            .open_run_it_window(user::user::time())
+            .map_err(|e| trace_err!(e, "Failed to open the run it window."))?
+        {
+            // The board is run out once the players agreed or the window closed
+            return Ok(());
+        }
+        self.run_out_board()
+    }
+
+    /// Deals the rest of the board as many times as the players agreed and shows down.
+    pub fn run_out_board(&mut self) -> Result<(), TracedError<GameError>> {
+        let runs = self.get_agreed_runs();
+        if runs > 1 {
+            return self
+                .run_it_multiple_times(runs)
+                .map_err(|e| trace_err!(e, "Error running it in cycle to showdown."));
+        }
         while self.deal_stage != DealStage::Showdown {
             self.deal_cards(true)
                 .map_err(|e| trace_err!(e, "Error dealing cards in cycle to showdown."))?;
@@ -706,14 +824,37 @@ impl Table {
         Ok(())
     }
 
+    /// Whether everyone but one player folded.
+    pub fn is_won_uncontested(&self) -> bool {
+        self.user_table_data
+            .values()
+            .filter(|data| {
+                !matches!(
+                    data.player_action,
+                    PlayerAction::Folded | PlayerAction::SittingOut | PlayerAction::Joining
+                )
+            })
+            .count()
+            <= 1
+    }
+
     /// Adds an item to the action queue.
     pub fn append_to_queue(&mut self, item: QueueItem) {
         self.queue.push(item);
     }
 
     /// Handles the items in the queue.
-    #[allow(dependency_on_unit_never_type_fallback)]
+    ///
+    /// The chips of users leaving the table are withdrawn with them.
     pub fn handle_queue_items(&mut self) -> Result<(), TracedError<GameError>> {
+        let withdrawn = self.get_queued_withdrawals();
+        self.conserving_chips(withdrawn, |table| table.process_queue_items())
+    }
+
+    /// Pops and processes every item in the queue for [`Table::handle_queue_items`].
+    #[allow(dependency_on_unit_never_type_fallback)]
+    fn process_queue_items(&mut self) -> Result<(), TracedError<GameError>> {
+        let mut seat_changes = Vec::new();
         while let Some(item) = self.queue.pop() {
             match item {
                 QueueItem::SittingIn(user_principal, is_game_paused) => {
@@ -724,7 +865,7 @@ impl Table {
                         );
                         continue;
                     }
-                    self.set_player_action(user_principal, PlayerAction::None)
+                    self.user_sitting_in(user_principal)
                         .map_err(|e| trace_err!(e, ""))?;
                     if is_game_paused {
                         let table_principal = self.id;
@@ -744,6 +885,11 @@ impl Table {
                         );
                         continue;
                     }
+                    // Top-ups are capped at the maximum buy-in
+                    let amount = self.get_top_up_amount(user_id, amount);
+                    if amount == 0 {
+                        continue;
+                    }
                     let table_principal = self.id;
                     ic_cdk::futures::spawn(async move {
                         match deposit_to_table(
@@ -779,6 +925,7 @@ impl Table {
                     }
                     // Clean up user data
                     self.log_action(Some(user_principal), action_type);
+                    self.record_departure(user_principal, user::user::time());
                     self.user_table_data.remove(&user_principal);
                     self.users.remove_user(user_principal);
                 }
@@ -853,6 +1000,13 @@ impl Table {
                     self.small_blind = small_blind;
                     self.big_blind = big_blind;
                     self.config.ante_type = ante;
+                    self.refill_time_banks_for_level();
+                }
+                QueueItem::BombPot => {
+                    self.bomb_pot_ante = self.config.bomb_pot.as_ref().map(|config| config.ante);
+                }
+                QueueItem::SeatChange(user_principal, seat_index) => {
+                    seat_changes.push((user_principal, seat_index));
                 }
                 QueueItem::PauseTable => self.config.is_paused = Some(true),
                 QueueItem::PauseTableForAddon(duration) => {
@@ -893,10 +1047,17 @@ impl Table {
                 }
             }
         }
+
+        // Seats are changed once everyone leaving has left, in the order they were asked for
+        let now = user::user::time();
+        for (user_principal, seat_index) in seat_changes.into_iter().rev() {
+            self.change_seat(user_principal, seat_index, now);
+        }
+        self.offer_open_seats(now);
         Ok(())
     }
 
-    // Removes all the cards from the user table data apart from the user specified by the principal.
+    // Removes the cards and pre-actions of every user apart from the user specified by the principal.
     pub fn hide_cards(&mut self, user_id: WalletPrincipalId) -> Result<(), TracedError<GameError>> {
         for (user_principal, table_data) in self.user_table_data.iter_mut() {
             let user = self
@@ -904,9 +1065,11 @@ impl Table {
                 .get(user_principal)
                 .ok_or_else(|| trace_err!(TracedError::new(GameError::PlayerNotFound), ""))?;
             if user.principal_id != user_id {
-                table_data.cards.clear();
+                // In stud games the up cards are visible to everyone
+                table_data.cards = table_data.visible_cards();
             }
         }
+        self.hide_pre_actions(user_id);
         Ok(())
     }
 
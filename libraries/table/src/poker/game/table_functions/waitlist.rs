@@ -0,0 +1,247 @@
+use candid::CandidType;
+use errors::{game_error::GameError, trace_err, traced_error::TracedError};
+use serde::{Deserialize, Serialize};
+use user::user::WalletPrincipalId;
+
+use crate::poker::game::types::QueueItem;
+
+use super::{
+    action_log::ActionType,
+    table::{Table, TableType},
+    types::{NotificationMessage, SeatStatus},
+};
+
+/// How long a waitlisted player has to take the seat offered to them, in nanoseconds.
+pub const SEAT_OFFER_WINDOW_NS: u64 = 60 * 1_000_000_000;
+
+/// A player waiting for a seat at a full table.
+#[derive(Debug, Clone, Serialize, CandidType, Deserialize, PartialEq, Eq)]
+pub struct WaitlistEntry {
+    pub user_principal: WalletPrincipalId,
+    /// When the player joined the waitlist, in nanoseconds.
+    pub joined_at: u64,
+}
+
+impl Table {
+    /// Whether players can pick their seats at this table.
+    ///
+    /// Only cash tables have a waitlist and seat changes, tournaments seat players themselves.
+    pub fn is_seat_choice_allowed(&self) -> bool {
+        matches!(self.config.table_type, None | Some(TableType::Cash))
+    }
+
+    /// The place of the user on the waitlist, starting at 0.
+    pub fn get_waitlist_position(&self, user_principal: WalletPrincipalId) -> Option<usize> {
+        self.waitlist
+            .iter()
+            .position(|entry| entry.user_principal == user_principal)
+    }
+
+    /// The seat being held for the user after it was offered to them from the waitlist.
+    pub fn get_reserved_seat_index(&self, user_principal: WalletPrincipalId) -> Option<u8> {
+        self.seats
+            .iter()
+            .position(|seat| {
+                matches!(seat, SeatStatus::Reserved { principal, .. } if *principal == user_principal)
+            })
+            .map(|i| i as u8)
+    }
+
+    /// Adds the user to the end of the waitlist.
+    ///
+    /// If a seat is already free it is offered to the front of the waitlist straight away.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table has no waitlist or the user is already on it
+    /// - [`GameError::UserAlreadyInGame`] if the user is already seated
+    pub fn join_waitlist(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        now: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        if !self.is_seat_choice_allowed() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Waitlists are only available at cash tables".to_string(),
+            })));
+        }
+        if self.is_user_in_table(user_principal) {
+            return Err(trace_err!(TracedError::new(GameError::UserAlreadyInGame)));
+        }
+        if self.get_waitlist_position(user_principal).is_some() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Already on the waitlist".to_string(),
+            })));
+        }
+
+        self.waitlist.push(WaitlistEntry {
+            user_principal,
+            joined_at: now,
+        });
+        self.offer_open_seats(now);
+        Ok(())
+    }
+
+    /// Takes the user off the waitlist, giving up a seat offered to them.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::PlayerNotFound`] if the user is not on the waitlist and holds no offered seat
+    pub fn leave_waitlist(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        now: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        if let Some(position) = self.get_waitlist_position(user_principal) {
+            self.waitlist.remove(position);
+        } else if let Some(seat_index) = self.get_reserved_seat_index(user_principal) {
+            self.seats[seat_index as usize] = SeatStatus::Empty;
+            self.offer_open_seats(now);
+        } else {
+            return Err(trace_err!(TracedError::new(GameError::PlayerNotFound)));
+        }
+        Ok(())
+    }
+
+    /// Offers every free seat to the front of the waitlist.
+    ///
+    /// An offered seat is held for the player for [`SEAT_OFFER_WINDOW_NS`].
+    /// Seats whose offer ran out are offered to the next player in line,
+    /// the player who let it run out has to join the waitlist again.
+    ///
+    /// # Parameters
+    ///
+    /// - `now` - The current time in nanoseconds
+    pub fn offer_open_seats(&mut self, now: u64) {
+        for seat in self.seats.iter_mut() {
+            if let SeatStatus::Reserved { timestamp, .. } = seat {
+                if now >= timestamp.saturating_add(SEAT_OFFER_WINDOW_NS) {
+                    *seat = SeatStatus::Empty;
+                }
+            }
+        }
+        if !self.is_seat_choice_allowed() {
+            return;
+        }
+
+        for seat_index in 0..self.seats.len() {
+            if !matches!(self.seats[seat_index], SeatStatus::Empty) {
+                continue;
+            }
+            let Some(entry) = self.next_waitlist_entry() else {
+                break;
+            };
+            self.seats[seat_index] = SeatStatus::Reserved {
+                principal: entry.user_principal,
+                timestamp: now,
+            };
+            self.notifications.add_notification(
+                entry.user_principal,
+                NotificationMessage::SeatOffered {
+                    seat_index: seat_index as u8,
+                    expires_at: now.saturating_add(SEAT_OFFER_WINDOW_NS),
+                },
+            );
+        }
+    }
+
+    /// Takes the first player off the waitlist who is not seated already.
+    fn next_waitlist_entry(&mut self) -> Option<WaitlistEntry> {
+        while !self.waitlist.is_empty() {
+            let entry = self.waitlist.remove(0);
+            if !self.is_user_in_table(entry.user_principal) {
+                return Some(entry);
+            }
+        }
+        None
+    }
+
+    /// Asks to move the user to another seat.
+    ///
+    /// The move happens between hands, straight away if no hand is being played.
+    /// A new request replaces the one the user made before.
+    ///
+    /// # Parameters
+    ///
+    /// - `user_principal` - The principal of the user
+    /// - `seat_index` - The seat to move to
+    /// - `now` - The current time in nanoseconds
+    ///
+    /// # Errors
+    ///
+    /// - [`GameError::ActionNotAllowed`] if the table does not allow seat changes
+    /// - [`GameError::PlayerNotFound`] if the user is not seated at the table
+    /// - [`GameError::Other`] if the seat is out of bounds or not free
+    pub fn request_seat_change(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        seat_index: u8,
+        now: u64,
+    ) -> Result<(), TracedError<GameError>> {
+        if !self.is_seat_choice_allowed() {
+            return Err(trace_err!(TracedError::new(GameError::ActionNotAllowed {
+                reason: "Seat changes are only available at cash tables".to_string(),
+            })));
+        }
+        if !self.seats.iter().any(
+            |seat| matches!(seat, SeatStatus::Occupied(principal) if *principal == user_principal),
+        ) {
+            return Err(trace_err!(TracedError::new(GameError::PlayerNotFound)));
+        }
+        if !matches!(self.seats.get(seat_index as usize), Some(SeatStatus::Empty)) {
+            return Err(trace_err!(TracedError::new(GameError::Other(
+                "Seat is not available".to_string()
+            ))));
+        }
+
+        if self.is_game_ongoing() {
+            self.queue.retain(|item| {
+                !matches!(item, QueueItem::SeatChange(principal, _) if *principal == user_principal)
+            });
+            self.append_to_queue(QueueItem::SeatChange(user_principal, seat_index));
+        } else {
+            self.change_seat(user_principal, seat_index, now);
+        }
+        Ok(())
+    }
+
+    /// Moves the user to the given seat if it is still free.
+    ///
+    /// On a running table the user owes the big blind again,
+    /// and the seat they left is offered to the waitlist.
+    pub(crate) fn change_seat(
+        &mut self,
+        user_principal: WalletPrincipalId,
+        seat_index: u8,
+        now: u64,
+    ) {
+        let Some(from) = self.seats.iter().position(
+            |seat| matches!(seat, SeatStatus::Occupied(principal) if *principal == user_principal),
+        ) else {
+            return;
+        };
+        if !matches!(self.seats.get(seat_index as usize), Some(SeatStatus::Empty)) {
+            ic_cdk::println!(
+                "Seat {} is no longer free for {}",
+                seat_index,
+                user_principal.0.to_text()
+            );
+            return;
+        }
+
+        self.seats.swap(from, seat_index as usize);
+        self.log_action(Some(user_principal), ActionType::SeatChange { seat_index });
+        self.set_new_player_missed_blinds(user_principal);
+        self.offer_open_seats(now);
+    }
+}
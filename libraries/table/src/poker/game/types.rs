@@ -5,18 +5,24 @@ use errors::game_error::GameError;
 use serde::{Deserialize, Serialize};
 use user::user::{User, UsersCanisterId, WalletPrincipalId};
 
+pub use crate::poker::core::GameType;
 use crate::poker::{
     core::{Card, FlatDeck, Hand, Rank},
     game::table_functions::table::{BigBlind, Pot, SmallBlind, TableId},
 };
+pub use fairness::types::{CardProvenance, RngMetadata};
 
 use super::{
     table_functions::{
         action_log::{ActionLog, ActionType},
         ante::AnteType,
+        buy_in::DepartedPlayer,
+        disconnect_protection::DisconnectProtectionUsage,
+        shuffle_seed::{CommittedShuffle, PlayerSeed},
         side_pot::SidePot,
         table::{Table, TableConfig},
         types::{DealStage, Notifications, SeatStatus, UserTableData},
+        waitlist::WaitlistEntry,
     },
     users::Users,
 };
@@ -31,6 +37,10 @@ pub enum QueueItem {
     UpdateBlinds(SmallBlind, BigBlind, Option<AnteType>),
     PauseTable,
     PauseTableForAddon(u64),
+    /// The next hand is a bomb pot.
+    BombPot,
+    /// Moves a seated player to another seat.
+    SeatChange(WalletPrincipalId, u8),
 }
 
 /// The TableStatus enum determines
@@ -46,23 +56,6 @@ pub enum TableStatus {
     Reserved,
 }
 
-/// The different variants of Texas Hold'em.
-#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
-pub enum GameType {
-    /// Players can bet any amount.
-    NoLimit(u64),
-    /// Players can only bet a fixed amount.
-    FixedLimit(u64, u64), // (small_bet, big_bet)
-    // Players must bet between min_bet and max_bet
-    SpreadLimit(u64, u64), // (min_bet, max_bet)
-    // Players can bet up to the amount in the pot.
-    PotLimit(u64),
-    // Pot Limit Omaha 4-card
-    PotLimitOmaha4(u64),
-    // Pot Limit Omaha 5-card
-    PotLimitOmaha5(u64),
-}
-
 /// A struct that holds a user's hand and rank,
 /// used for comparing players' hands.
 #[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
@@ -94,6 +87,16 @@ pub struct PublicTable {
     pub config: TableConfig,
     pub seats: Vec<SeatStatus>,
     pub community_cards: Vec<Card>,
+    /// The board cards that would have come, once someone rabbit hunted after the hand.
+    pub rabbit_hunt_cards: Option<Vec<Card>>,
+    /// The ante everyone posted if this hand is a bomb pot.
+    pub bomb_pot_ante: Option<u64>,
+    /// The second board of a double board bomb pot.
+    pub second_board: Option<Vec<Card>>,
+    /// When the players still in the hand stop being able to agree on running it more than once.
+    pub run_it_window_closes_at: Option<u64>,
+    /// The players waiting for a seat, in the order the seats are offered to them.
+    pub waitlist: Vec<WaitlistEntry>,
     pub pot: Pot,
     pub side_pots: Vec<SidePot>,
     pub status: TableStatus,
@@ -140,10 +143,38 @@ impl PublicTable {
             .position(|seat| matches!(seat, SeatStatus::Empty))
     }
 
+    /// Are exactly two players dealt in? See [`Table::is_heads_up_hand`].
+    pub fn is_heads_up_hand(&self) -> bool {
+        self.seats
+            .iter()
+            .filter(|seat| self.is_dealt_in(seat))
+            .count()
+            == 2
+    }
+
+    fn is_dealt_in(&self, seat: &SeatStatus) -> bool {
+        match seat {
+            SeatStatus::Occupied(user_principal) => self
+                .user_table_data
+                .get(user_principal)
+                .is_some_and(|data| data.is_dealt_in()),
+            _ => false,
+        }
+    }
+
     pub fn get_big_blind_user_index(&self) -> Option<usize> {
+        if self.is_heads_up_hand() {
+            // Heads-up the button posts the small blind, skipping a player sitting out
+            let small_blind_index = (0..self.seats.len())
+                .map(|offset| (self.dealer_position + offset) % self.seats.len())
+                .find(|&index| self.is_dealt_in(&self.seats[index]))?;
+            return (1..self.seats.len())
+                .map(|offset| (small_blind_index + offset) % self.seats.len())
+                .find(|&index| self.is_dealt_in(&self.seats[index]));
+        }
+
         let mut index = (self.dealer_position + 1) % self.seats.len();
         let mut found_small_blind = false;
-
         for _ in 0..self.seats.len() {
             if let SeatStatus::Occupied(user_principal) = self.seats[index] {
                 if found_small_blind && self.user_table_data.contains_key(&user_principal) {
@@ -157,8 +188,14 @@ impl PublicTable {
     }
 
     pub fn get_big_blind_user_principal(&self) -> Option<WalletPrincipalId> {
-        let mut index = (self.dealer_position + 1) % self.seats.len();
+        if self.is_heads_up_hand() {
+            return match self.seats[self.get_big_blind_user_index()?] {
+                SeatStatus::Occupied(user_principal) => Some(user_principal),
+                _ => None,
+            };
+        }
 
+        let mut index = (self.dealer_position + 1) % self.seats.len();
         let mut found_small_blind = false;
         for _ in 0..self.config.seats * 2 {
             if let SeatStatus::Occupied(user_principal) = self.seats[index] {
@@ -180,6 +217,11 @@ impl Default for PublicTable {
             config: TableConfig::default(),
             seats: Vec::new(),
             community_cards: vec![],
+            rabbit_hunt_cards: None,
+            bomb_pot_ante: None,
+            second_board: None,
+            run_it_window_closes_at: None,
+            waitlist: Vec::new(),
             pot: Pot(0),
             side_pots: vec![],
             status: TableStatus::Open,
@@ -209,6 +251,11 @@ impl From<Table> for PublicTable {
             config: table.config,
             seats: table.seats.clone(),
             community_cards: table.community_cards.clone(),
+            rabbit_hunt_cards: table.rabbit_hunt_cards.clone(),
+            bomb_pot_ante: table.bomb_pot_ante,
+            second_board: table.second_board.clone(),
+            run_it_window_closes_at: table.run_it_window_closes_at,
+            waitlist: table.waitlist.clone(),
             pot: table.pot,
             side_pots: table.side_pots.clone(),
             status: table.status,
@@ -238,6 +285,11 @@ impl From<&Table> for PublicTable {
             config: table.config.clone(),
             seats: table.seats.clone(),
             community_cards: table.community_cards.clone(),
+            rabbit_hunt_cards: table.rabbit_hunt_cards.clone(),
+            bomb_pot_ante: table.bomb_pot_ante,
+            second_board: table.second_board.clone(),
+            run_it_window_closes_at: table.run_it_window_closes_at,
+            waitlist: table.waitlist.clone(),
             pot: table.pot,
             side_pots: table.side_pots.clone(),
             status: table.status,
@@ -267,6 +319,11 @@ impl From<&mut Table> for PublicTable {
             config: table.config.clone(),
             seats: table.seats.clone(),
             community_cards: table.community_cards.clone(),
+            rabbit_hunt_cards: table.rabbit_hunt_cards.clone(),
+            bomb_pot_ante: table.bomb_pot_ante,
+            second_board: table.second_board.clone(),
+            run_it_window_closes_at: table.run_it_window_closes_at,
+            waitlist: table.waitlist.clone(),
             pot: table.pot,
             side_pots: table.side_pots.clone(),
             status: table.status,
@@ -308,7 +365,19 @@ pub struct StorableTable {
     pub small_blind: SmallBlind,
     pub big_blind_user_principal: WalletPrincipalId,
     pub small_blind_user_principal: WalletPrincipalId,
+    pub straddle_user_principal: Option<WalletPrincipalId>,
     pub dealer_position: usize,
+    pub blind_seats: Option<(usize, usize)>,
+    pub rabbit_hunt_cards: Option<Vec<Card>>,
+    pub bomb_pot_ante: Option<u64>,
+    pub second_board: Option<Vec<Card>>,
+    pub run_it_window_closes_at: Option<u64>,
+    pub waitlist: Option<Vec<WaitlistEntry>>,
+    pub disconnect_protection_usage: Option<HashMap<WalletPrincipalId, DisconnectProtectionUsage>>,
+    pub departed_players: Option<HashMap<WalletPrincipalId, DepartedPlayer>>,
+    pub player_seeds: Option<HashMap<WalletPrincipalId, PlayerSeed>>,
+    pub committed_shuffle: Option<CommittedShuffle>,
+    pub is_hand_for_hand: Option<bool>,
     pub current_player_index: usize,
     pub winners: Option<Vec<User>>,
     pub sorted_users: Option<Vec<UserCards>>,
@@ -345,7 +414,19 @@ impl Default for StorableTable {
             small_blind: SmallBlind(0),
             big_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
             small_blind_user_principal: WalletPrincipalId(Principal::anonymous()),
+            straddle_user_principal: None,
             dealer_position: 0,
+            blind_seats: None,
+            rabbit_hunt_cards: None,
+            bomb_pot_ante: None,
+            second_board: None,
+            run_it_window_closes_at: None,
+            waitlist: None,
+            disconnect_protection_usage: None,
+            departed_players: None,
+            player_seeds: None,
+            committed_shuffle: None,
+            is_hand_for_hand: None,
             current_player_index: 0,
             winners: None,
             sorted_users: None,
@@ -382,7 +463,21 @@ impl From<StorableTable> for Table {
             small_blind: storable_table.small_blind,
             big_blind_user_principal: storable_table.big_blind_user_principal,
             small_blind_user_principal: storable_table.small_blind_user_principal,
+            straddle_user_principal: storable_table.straddle_user_principal,
             dealer_position: storable_table.dealer_position,
+            blind_seats: storable_table.blind_seats,
+            rabbit_hunt_cards: storable_table.rabbit_hunt_cards,
+            bomb_pot_ante: storable_table.bomb_pot_ante,
+            second_board: storable_table.second_board,
+            run_it_window_closes_at: storable_table.run_it_window_closes_at,
+            waitlist: storable_table.waitlist.unwrap_or_default(),
+            disconnect_protection_usage: storable_table
+                .disconnect_protection_usage
+                .unwrap_or_default(),
+            departed_players: storable_table.departed_players.unwrap_or_default(),
+            player_seeds: storable_table.player_seeds.unwrap_or_default(),
+            committed_shuffle: storable_table.committed_shuffle,
+            is_hand_for_hand: storable_table.is_hand_for_hand.unwrap_or(false),
             current_player_index: storable_table.current_player_index,
             winners: storable_table.winners,
             sorted_users: storable_table.sorted_users,
@@ -423,7 +518,19 @@ impl From<Table> for StorableTable {
             small_blind: table.small_blind,
             big_blind_user_principal: table.big_blind_user_principal,
             small_blind_user_principal: table.small_blind_user_principal,
+            straddle_user_principal: table.straddle_user_principal,
             dealer_position: table.dealer_position,
+            blind_seats: table.blind_seats,
+            rabbit_hunt_cards: table.rabbit_hunt_cards,
+            bomb_pot_ante: table.bomb_pot_ante,
+            second_board: table.second_board,
+            run_it_window_closes_at: table.run_it_window_closes_at,
+            waitlist: Some(table.waitlist),
+            disconnect_protection_usage: Some(table.disconnect_protection_usage),
+            departed_players: Some(table.departed_players),
+            player_seeds: Some(table.player_seeds),
+            committed_shuffle: table.committed_shuffle,
+            is_hand_for_hand: Some(table.is_hand_for_hand),
             current_player_index: table.current_player_index,
             winners: table.winners,
             sorted_users: table.sorted_users,
@@ -457,120 +564,6 @@ impl PublicTable {
 // RNG Transparency Structures
 // ============================================================================
 
-/// RNG Metadata for transparency and verification
-///
-/// This structure stores all information needed to verify the fairness
-/// of card dealing in a poker game, including the raw random bytes from
-/// the Internet Computer's management canister, time seeds, and the
-/// resulting shuffled deck.
-#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
-pub struct RngMetadata {
-    /// The game round ID (incrementing counter for each new shuffle)
-    pub round_id: u64,
-
-    /// Raw random bytes from IC management canister (32 bytes)
-    /// This is the source of randomness from the blockchain's VRF
-    pub raw_random_bytes: Vec<u8>,
-
-    /// Time seed used for reshuffling (nanoseconds since Unix epoch)
-    /// Adds additional entropy to the shuffle process
-    pub time_seed: u64,
-
-    /// Timestamp of RNG generation (nanoseconds since Unix epoch)
-    pub timestamp_ns: u64,
-
-    /// Hash of the final shuffled deck (for quick verification)
-    /// SHA-256 hash of the shuffled card order
-    pub deck_hash: String,
-
-    /// Transaction/call ID from IC management canister (if available)
-    /// Can be used to trace back to the on-chain randomness call
-    pub ic_transaction_id: Option<String>,
-
-    /// The shuffled card order (52 cards)
-    /// The complete deck after Fisher-Yates shuffle
-    pub shuffled_deck: Vec<Card>,
-}
-
-impl Default for RngMetadata {
-    fn default() -> Self {
-        RngMetadata {
-            round_id: 0,
-            raw_random_bytes: Vec::new(),
-            time_seed: 0,
-            timestamp_ns: 0,
-            deck_hash: String::new(),
-            ic_transaction_id: None,
-            shuffled_deck: Vec::new(),
-        }
-    }
-}
-
-/// Card Provenance tracks the journey of a single card through the shuffle
-///
-/// This allows players to verify exactly where each card came from and
-/// how it ended up in their hand, providing complete transparency.
-///
-/// **IMPORTANT:** `shuffled_position` is the position in the ORIGINAL
-/// shuffled deck (0-51), NOT the current deck state after dealing.
-/// This position remains constant throughout the game and is used for
-/// cryptographic hash verification. As cards are dealt from the deck,
-/// the remaining cards' positions in the current deck state change,
-/// but their `shuffled_position` values remain fixed.
-#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
-pub struct CardProvenance {
-    /// Round ID this card belongs to
-    pub round_id: u64,
-
-    /// The card (e.g., Ace of Spades)
-    pub card: Card,
-
-    /// Original position in unshuffled deck (0-51)
-    /// Standard order: A♠, 2♠, ..., K♠, A♥, ..., K♦
-    pub original_position: u8,
-
-    /// Position after Fisher-Yates shuffle (0-51)
-    /// 
-    /// **CRITICAL:** This is the position in the ORIGINAL shuffled deck,
-    /// before any cards were dealt. This position is used for hash calculation
-    /// and does NOT change as cards are dealt from the deck.
-    /// 
-    /// Example: If a card is at position 10 in the original shuffled deck,
-    /// it remains at `shuffled_position = 10` even if 5 cards are dealt
-    /// and it's now at position 5 in the current deck state.
-    pub shuffled_position: u8,
-
-    /// Hash identifier for this specific card in this game
-    /// Format: SHA-256(round_id || card || shuffled_position)
-    /// 
-    /// The hash uses `shuffled_position` (original position), not the
-    /// current deck position, ensuring consistent verification.
-    pub card_hash: String,
-
-    /// Which player received this card (if dealt)
-    pub dealt_to: Option<WalletPrincipalId>,
-
-    /// When was this card dealt (PreFlop, Flop, Turn, River)
-    pub dealt_at_stage: Option<DealStage>,
-}
-
-impl Default for CardProvenance {
-    fn default() -> Self {
-        CardProvenance {
-            round_id: 0,
-            card: Card::new(
-                crate::poker::core::Value::Two,
-                crate::poker::core::Suit::Spade,
-            ),
-            original_position: 0,
-            shuffled_position: 0,
-            card_hash: String::new(),
-            dealt_to: None,
-            dealt_at_stage: None,
-        }
-    }
-}
-
 /// Player card ownership information for RNG transparency
 /// Provides seat number and card positions without revealing card values
 /// Used to display other players' cards in RNG dashboard
@@ -604,3 +597,33 @@ pub struct RngStats {
     /// Current round ticker from the table
     pub current_round_ticker: u64,
 }
+
+/// The RNG data of a round that was moved out of the table into the archive
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
+pub struct ArchivedRound {
+    /// RNG metadata of the round
+    pub rng_metadata: RngMetadata,
+
+    /// Provenance of every card of the round, sorted by shuffled position
+    pub card_provenance: Vec<CardProvenance>,
+}
+
+/// A page of RNG history, oldest round first
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
+pub struct RngHistoryPage {
+    /// RNG metadata of the rounds on this page
+    pub rounds: Vec<RngMetadata>,
+
+    /// Round ID to pass as the cursor for the next page, if there is one
+    pub next_cursor: Option<u64>,
+}
+
+/// A page of the card provenance of a round, sorted by shuffled position
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
+pub struct CardProvenancePage {
+    /// Provenance of the cards on this page
+    pub provenance: Vec<CardProvenance>,
+
+    /// Shuffled position to pass as the cursor for the next page, if there is one
+    pub next_cursor: Option<u8>,
+}
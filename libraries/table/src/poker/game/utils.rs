@@ -1,4 +1,4 @@
-use crate::poker::core::{Card, Hand, Rank, Rankable};
+use crate::poker::core::{Card, CardBitSet, Rank};
 
 /// Rank a hand by Vec\<Card\>
 ///
@@ -6,8 +6,7 @@ use crate::poker::core::{Card, Hand, Rank, Rankable};
 ///
 /// - `cards` - The cards to rank.
 pub fn rank_hand(cards: Vec<Card>) -> Rank {
-    let hand = Hand::new_with_cards(cards);
-    hand.rank()
+    cards.into_iter().collect::<CardBitSet>().rank()
 }
 
 /// Increase the given `amount` by 1e8
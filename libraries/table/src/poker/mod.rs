@@ -1,2 +1,4 @@
-pub mod core;
+pub use poker_core as core;
 pub mod game;
+#[cfg(feature = "zk_shuffle")]
+pub mod zk_shuffle;
@@ -0,0 +1,212 @@
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT, ristretto::RistrettoPoint, scalar::Scalar,
+    traits::Identity,
+};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+use super::proof::ShuffleProofError;
+
+/// A card encrypted to the joint key of the players.
+///
+/// `c1 = r * G` and `c2 = M + r * K`, where `M` is the point the card stands for
+/// and `K` the joint public key. Only all the players together can take the
+/// `r * K` off again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ciphertext {
+    pub c1: RistrettoPoint,
+    pub c2: RistrettoPoint,
+}
+
+impl Ciphertext {
+    /// The card at `index` of the unshuffled deck, encrypted without randomness
+    /// so anyone can check the deck the first shuffle starts from.
+    pub fn open_card(index: usize) -> Self {
+        Ciphertext {
+            c1: RistrettoPoint::identity(),
+            c2: encode_card(index),
+        }
+    }
+
+    /// Encrypts the card at `index` of the unshuffled deck to `public_key`.
+    pub fn encrypt(public_key: &RistrettoPoint, index: usize, randomness: &Scalar) -> Self {
+        Ciphertext::open_card(index).reencrypt(public_key, randomness)
+    }
+
+    /// Encrypts the same card again, which changes the ciphertext beyond recognition.
+    pub fn reencrypt(&self, public_key: &RistrettoPoint, randomness: &Scalar) -> Self {
+        Ciphertext {
+            c1: self.c1 + randomness * RISTRETTO_BASEPOINT_POINT,
+            c2: self.c2 + randomness * public_key,
+        }
+    }
+
+    /// Takes the decryption shares of every player off the ciphertext and looks
+    /// up the card among the first `deck_size` of the unshuffled deck.
+    ///
+    /// Returns `None` if a share is missing or wrong.
+    pub fn decrypt(&self, shares: &[RistrettoPoint], deck_size: usize) -> Option<usize> {
+        let point = self.c2 - shares.iter().sum::<RistrettoPoint>();
+        (0..deck_size).find(|index| encode_card(*index) == point)
+    }
+
+    /// The compressed points, for hashing into a transcript.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(self.c1.compress().as_bytes());
+        bytes[32..].copy_from_slice(self.c2.compress().as_bytes());
+        bytes
+    }
+}
+
+/// A player's share of the key the deck is encrypted to.
+pub struct ShuffleKeyPair {
+    secret: Scalar,
+    pub public_key: RistrettoPoint,
+}
+
+impl ShuffleKeyPair {
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let secret = Scalar::random(rng);
+        ShuffleKeyPair {
+            secret,
+            public_key: secret * RISTRETTO_BASEPOINT_POINT,
+        }
+    }
+
+    /// The player's part of opening a card, `secret * c1`.
+    pub fn decryption_share(&self, ciphertext: &Ciphertext) -> RistrettoPoint {
+        self.secret * ciphertext.c1
+    }
+
+    /// The public key with a proof that the player knows its secret.
+    pub fn public_share<R: RngCore + CryptoRng>(&self, rng: &mut R) -> PublicKeyShare {
+        let nonce = Scalar::random(rng);
+        let commitment = nonce * RISTRETTO_BASEPOINT_POINT;
+        PublicKeyShare {
+            public_key: self.public_key,
+            commitment,
+            response: nonce + key_challenge(&self.public_key, &commitment) * self.secret,
+        }
+    }
+}
+
+/// A player's public key and a Schnorr proof that they know its secret.
+///
+/// `R = k * G` and `s = k + c * secret`, where the challenge `c` is hashed from
+/// the key and `R`. Without it, the last player to publish a key could pick one
+/// that cancels out the keys of the others and decrypt the deck alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKeyShare {
+    pub public_key: RistrettoPoint,
+    pub commitment: RistrettoPoint,
+    pub response: Scalar,
+}
+
+impl PublicKeyShare {
+    /// Whether the proof shows the secret of the key is known, `s * G == R + c * K`.
+    pub fn verify(&self) -> bool {
+        self.response * RISTRETTO_BASEPOINT_POINT
+            == self.commitment + key_challenge(&self.public_key, &self.commitment) * self.public_key
+    }
+}
+
+/// The key the deck is encrypted to, which needs every player to decrypt.
+///
+/// # Errors
+///
+/// - [`ShuffleProofError::InvalidKeyProof`] if a player did not prove they know their secret
+pub fn joint_public_key(shares: &[PublicKeyShare]) -> Result<RistrettoPoint, ShuffleProofError> {
+    if let Some(player) = shares.iter().position(|share| !share.verify()) {
+        return Err(ShuffleProofError::InvalidKeyProof(player));
+    }
+    Ok(shares.iter().map(|share| share.public_key).sum())
+}
+
+/// Fiat-Shamir challenge of a key proof.
+///
+/// Hash format: SHA-512("zk_shuffle key proof" || public_key || commitment), reduced to a scalar
+fn key_challenge(public_key: &RistrettoPoint, commitment: &RistrettoPoint) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(b"zk_shuffle key proof");
+    hasher.update(public_key.compress().as_bytes());
+    hasher.update(commitment.compress().as_bytes());
+    let mut hash = [0; 64];
+    hash.copy_from_slice(&hasher.finalize());
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+/// The unshuffled deck of `deck_size` cards, encrypted without randomness.
+pub fn open_deck(deck_size: usize) -> Vec<Ciphertext> {
+    (0..deck_size).map(Ciphertext::open_card).collect()
+}
+
+/// The point the card at `index` of the unshuffled deck stands for, `(index + 1) * G`.
+pub fn encode_card(index: usize) -> RistrettoPoint {
+    Scalar::from(index as u64 + 1) * RISTRETTO_BASEPOINT_POINT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_every_player_is_needed_to_decrypt() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let players: Vec<ShuffleKeyPair> =
+            (0..3).map(|_| ShuffleKeyPair::generate(&mut rng)).collect();
+        let shares: Vec<PublicKeyShare> = players
+            .iter()
+            .map(|player| player.public_share(&mut rng))
+            .collect();
+        let public_key = joint_public_key(&shares).unwrap();
+
+        let ciphertext = Ciphertext::encrypt(&public_key, 17, &Scalar::random(&mut rng))
+            .reencrypt(&public_key, &Scalar::random(&mut rng));
+        let shares: Vec<RistrettoPoint> = players
+            .iter()
+            .map(|player| player.decryption_share(&ciphertext))
+            .collect();
+        assert_eq!(ciphertext.decrypt(&shares, 52), Some(17));
+        assert_eq!(ciphertext.decrypt(&shares[1..], 52), None);
+    }
+
+    #[test]
+    fn test_rogue_key_is_rejected() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let honest = ShuffleKeyPair::generate(&mut rng);
+        let attacker = ShuffleKeyPair::generate(&mut rng);
+        let honest_share = honest.public_share(&mut rng);
+
+        // A key that cancels out the honest one, so the joint key is the attacker's own
+        let rogue_key = attacker.public_key - honest.public_key;
+        let rogue_share = PublicKeyShare {
+            public_key: rogue_key,
+            ..attacker.public_share(&mut rng)
+        };
+        assert!(honest_share.verify());
+        assert!(!rogue_share.verify());
+        assert_eq!(
+            joint_public_key(&[honest_share, rogue_share]),
+            Err(ShuffleProofError::InvalidKeyProof(1))
+        );
+
+        // A proof only holds for the key it was made for
+        let copied_share = PublicKeyShare {
+            public_key: attacker.public_key,
+            ..honest_share
+        };
+        assert!(!copied_share.verify());
+    }
+
+    #[test]
+    fn test_open_deck_decrypts_without_shares() {
+        let deck = open_deck(36);
+        assert_eq!(deck.len(), 36);
+        for (index, ciphertext) in deck.iter().enumerate() {
+            assert_eq!(ciphertext.decrypt(&[], 36), Some(index));
+        }
+    }
+}
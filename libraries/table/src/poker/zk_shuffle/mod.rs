@@ -0,0 +1,73 @@
+//! Prototype of a mental poker shuffle with a zero-knowledge shuffle proof.
+//!
+//! Every player holds a share of the key the deck is encrypted to with ElGamal
+//! over the Ristretto group, and proves they know its secret with a
+//! [`PublicKeyShare`]. The players shuffle the deck in turn, each one
+//! permuting and re-encrypting every card, and prove with a [`ShuffleProof`]
+//! that their deck holds the same cards as the one they were given. Once
+//! everyone has shuffled, no one knows the order, and a card can only be opened
+//! with a decryption share from every player.
+//!
+//! Only built with the `zk_shuffle` feature. [`measure_shuffle`] tells whether
+//! proving and verifying fit in a canister message.
+
+/// Keys, encrypted cards and decryption.
+pub mod elgamal;
+/// The shuffle proof.
+pub mod proof;
+
+use candid::CandidType;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+
+pub use self::elgamal::{joint_public_key, open_deck, Ciphertext, PublicKeyShare, ShuffleKeyPair};
+pub use self::proof::{
+    ShuffleOpening, ShuffleProof, ShuffleProofError, ShuffleProver, ShuffleVerifier, DEFAULT_ROUNDS,
+};
+
+/// What one proven shuffle of a deck costs, in whatever unit the counter counts.
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq)]
+pub struct ShuffleMeasurement {
+    pub deck_size: u64,
+    pub rounds: u64,
+    pub prove_cost: u64,
+    pub verify_cost: u64,
+}
+
+/// Shuffles and verifies a deck of `deck_size` cards with `rounds` shadow shuffles,
+/// drawing the keys and shuffles from `seed` and reading `counter` around each step.
+///
+/// Natively the counter can be a clock. In a canister it is the instruction counter,
+/// to compare against the limits of a message.
+///
+/// # Errors
+///
+/// - [`ShuffleProofError`] if the proof does not verify, which would be a bug
+pub fn measure_shuffle(
+    deck_size: usize,
+    rounds: usize,
+    seed: [u8; 32],
+    counter: impl Fn() -> u64,
+) -> Result<ShuffleMeasurement, ShuffleProofError> {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    let players = [
+        ShuffleKeyPair::generate(&mut rng),
+        ShuffleKeyPair::generate(&mut rng),
+    ];
+    let public_key = joint_public_key(&players.map(|player| player.public_share(&mut rng)))?;
+    let deck = open_deck(deck_size);
+
+    let start = counter();
+    let (shuffled_deck, proof) = ShuffleProver::new(public_key, rounds).shuffle(&deck, &mut rng);
+    let proved = counter();
+    ShuffleVerifier::new(public_key, rounds).verify(&deck, &shuffled_deck, &proof)?;
+    let verified = counter();
+
+    Ok(ShuffleMeasurement {
+        deck_size: deck_size as u64,
+        rounds: rounds as u64,
+        prove_cost: proved - start,
+        verify_cost: verified - proved,
+    })
+}
@@ -0,0 +1,355 @@
+use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+use rand::{seq::SliceRandom, CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::elgamal::Ciphertext;
+
+/// The number of shadow shuffles a proof holds by default.
+///
+/// One try of a cheating shuffler gets through with probability `2^-rounds`. As
+/// the challenge is a hash the shuffler computes itself, it can try again offline
+/// as often as it likes, so the rounds have to match the security level in bits.
+pub const DEFAULT_ROUNDS: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ShuffleProofError {
+    #[error("The shuffled deck has {output} cards instead of {input}")]
+    DeckSizeMismatch { input: usize, output: usize },
+    #[error("The proof has {found} rounds instead of {expected}")]
+    RoundCountMismatch { expected: usize, found: usize },
+    #[error("Round {0} does not open to a permutation of the deck")]
+    InvalidPermutation(usize),
+    #[error("Round {0} does not open to its shadow shuffle")]
+    OpeningMismatch(usize),
+    #[error("The key of player {0} does not come with a proof of its secret")]
+    InvalidKeyProof(usize),
+}
+
+/// How one deck was shuffled into another: card `i` of the new deck is card
+/// `permutation[i]` of the old one, re-encrypted with `randomness[i]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleOpening {
+    pub permutation: Vec<usize>,
+    pub randomness: Vec<Scalar>,
+}
+
+impl ShuffleOpening {
+    fn random<R: RngCore + CryptoRng>(deck_size: usize, rng: &mut R) -> Self {
+        let mut permutation: Vec<usize> = (0..deck_size).collect();
+        permutation.shuffle(rng);
+        ShuffleOpening {
+            permutation,
+            randomness: (0..deck_size).map(|_| Scalar::random(rng)).collect(),
+        }
+    }
+
+    fn apply(&self, public_key: &RistrettoPoint, deck: &[Ciphertext]) -> Vec<Ciphertext> {
+        self.permutation
+            .iter()
+            .zip(&self.randomness)
+            .map(|(index, randomness)| deck[*index].reencrypt(public_key, randomness))
+            .collect()
+    }
+
+    fn is_permutation_of(&self, deck_size: usize) -> bool {
+        let mut seen = vec![false; deck_size];
+        self.permutation.len() == deck_size
+            && self.randomness.len() == deck_size
+            && self
+                .permutation
+                .iter()
+                .all(|index| *index < deck_size && !std::mem::replace(&mut seen[*index], true))
+    }
+
+    /// The opening that takes this shadow shuffle on to the same deck as `shuffle`.
+    fn bridge_to(&self, shuffle: &ShuffleOpening) -> ShuffleOpening {
+        let mut inverse = vec![0; self.permutation.len()];
+        for (position, index) in self.permutation.iter().enumerate() {
+            inverse[*index] = position;
+        }
+        let permutation: Vec<usize> = shuffle
+            .permutation
+            .iter()
+            .map(|index| inverse[*index])
+            .collect();
+        let randomness = permutation
+            .iter()
+            .zip(&shuffle.randomness)
+            .map(|(shadow_index, randomness)| randomness - self.randomness[*shadow_index])
+            .collect();
+        ShuffleOpening {
+            permutation,
+            randomness,
+        }
+    }
+}
+
+/// Proof that a deck is a re-encrypted permutation of another, without saying which.
+///
+/// This is a cut-and-choose proof: the shuffler shuffles the input deck again into
+/// one shadow deck per round, and a challenge hashed from all the decks decides,
+/// round by round, whether it opens how the input became the shadow deck or how
+/// the shadow deck became the output. Either opening alone says nothing about
+/// the real permutation, and a shuffler who cheated can answer at most one of the
+/// two in every round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleProof {
+    pub shadow_decks: Vec<Vec<Ciphertext>>,
+    pub openings: Vec<ShuffleOpening>,
+}
+
+/// Shuffles decks encrypted to `public_key` and proves it did so honestly.
+pub struct ShuffleProver {
+    public_key: RistrettoPoint,
+    rounds: usize,
+}
+
+impl ShuffleProver {
+    pub fn new(public_key: RistrettoPoint, rounds: usize) -> Self {
+        ShuffleProver { public_key, rounds }
+    }
+
+    /// Shuffles and re-encrypts every card of the deck.
+    ///
+    /// Returns the shuffled deck and the proof it is a shuffle of `deck`.
+    pub fn shuffle<R: RngCore + CryptoRng>(
+        &self,
+        deck: &[Ciphertext],
+        rng: &mut R,
+    ) -> (Vec<Ciphertext>, ShuffleProof) {
+        let shuffle = ShuffleOpening::random(deck.len(), rng);
+        let shuffled_deck = shuffle.apply(&self.public_key, deck);
+
+        let shadow_shuffles: Vec<ShuffleOpening> = (0..self.rounds)
+            .map(|_| ShuffleOpening::random(deck.len(), rng))
+            .collect();
+        let shadow_decks: Vec<Vec<Ciphertext>> = shadow_shuffles
+            .iter()
+            .map(|shadow_shuffle| shadow_shuffle.apply(&self.public_key, deck))
+            .collect();
+
+        let challenge = challenge_bits(&self.public_key, deck, &shuffled_deck, &shadow_decks);
+        let openings = shadow_shuffles
+            .iter()
+            .zip(challenge)
+            .map(|(shadow_shuffle, opens_output)| {
+                if opens_output {
+                    shadow_shuffle.bridge_to(&shuffle)
+                } else {
+                    shadow_shuffle.clone()
+                }
+            })
+            .collect();
+
+        (
+            shuffled_deck,
+            ShuffleProof {
+                shadow_decks,
+                openings,
+            },
+        )
+    }
+}
+
+/// Checks that a deck encrypted to `public_key` was shuffled honestly.
+pub struct ShuffleVerifier {
+    public_key: RistrettoPoint,
+    rounds: usize,
+}
+
+impl ShuffleVerifier {
+    pub fn new(public_key: RistrettoPoint, rounds: usize) -> Self {
+        ShuffleVerifier { public_key, rounds }
+    }
+
+    /// Checks that `shuffled_deck` is a re-encrypted permutation of `deck`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ShuffleProofError::DeckSizeMismatch`] if the decks differ in size
+    /// - [`ShuffleProofError::RoundCountMismatch`] if the proof has too few or too many rounds
+    /// - [`ShuffleProofError::InvalidPermutation`] if an opening is not a permutation
+    /// - [`ShuffleProofError::OpeningMismatch`] if an opening does not lead to its deck
+    pub fn verify(
+        &self,
+        deck: &[Ciphertext],
+        shuffled_deck: &[Ciphertext],
+        proof: &ShuffleProof,
+    ) -> Result<(), ShuffleProofError> {
+        if deck.len() != shuffled_deck.len() {
+            return Err(ShuffleProofError::DeckSizeMismatch {
+                input: deck.len(),
+                output: shuffled_deck.len(),
+            });
+        }
+        if proof.shadow_decks.len() != self.rounds || proof.openings.len() != self.rounds {
+            return Err(ShuffleProofError::RoundCountMismatch {
+                expected: self.rounds,
+                found: proof.shadow_decks.len().min(proof.openings.len()),
+            });
+        }
+
+        let challenge = challenge_bits(&self.public_key, deck, shuffled_deck, &proof.shadow_decks);
+        for (round, ((shadow_deck, opening), opens_output)) in proof
+            .shadow_decks
+            .iter()
+            .zip(&proof.openings)
+            .zip(challenge)
+            .enumerate()
+        {
+            if shadow_deck.len() != deck.len() || !opening.is_permutation_of(deck.len()) {
+                return Err(ShuffleProofError::InvalidPermutation(round));
+            }
+            let is_matching = if opens_output {
+                opening.apply(&self.public_key, shadow_deck) == shuffled_deck
+            } else {
+                opening.apply(&self.public_key, deck) == *shadow_deck
+            };
+            if !is_matching {
+                return Err(ShuffleProofError::OpeningMismatch(round));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fiat-Shamir challenge: one bit per shadow deck, hashed from the key and all the decks.
+///
+/// Bit format: SHA-256(SHA-256(public_key || deck || shuffled_deck || shadow_decks) || block)
+fn challenge_bits(
+    public_key: &RistrettoPoint,
+    deck: &[Ciphertext],
+    shuffled_deck: &[Ciphertext],
+    shadow_decks: &[Vec<Ciphertext>],
+) -> Vec<bool> {
+    let mut hasher = Sha256::new();
+    hasher.update(public_key.compress().as_bytes());
+    for ciphertext in deck
+        .iter()
+        .chain(shuffled_deck)
+        .chain(shadow_decks.iter().flatten())
+    {
+        hasher.update(ciphertext.to_bytes());
+    }
+    let transcript = hasher.finalize();
+
+    (0..shadow_decks.len().div_ceil(256))
+        .flat_map(|block| {
+            let mut hasher = Sha256::new();
+            hasher.update(transcript);
+            hasher.update((block as u32).to_be_bytes());
+            hasher.finalize()
+        })
+        .flat_map(|byte| (0..8).map(move |bit| (byte >> bit) & 1 == 1))
+        .take(shadow_decks.len())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poker::zk_shuffle::elgamal::{
+        joint_public_key, open_deck, PublicKeyShare, ShuffleKeyPair,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    const ROUNDS: usize = 16;
+
+    fn setup(rng: &mut ChaCha20Rng) -> (Vec<ShuffleKeyPair>, RistrettoPoint) {
+        let players: Vec<ShuffleKeyPair> = (0..2).map(|_| ShuffleKeyPair::generate(rng)).collect();
+        let shares: Vec<PublicKeyShare> = players
+            .iter()
+            .map(|player| player.public_share(rng))
+            .collect();
+        let public_key = joint_public_key(&shares).unwrap();
+        (players, public_key)
+    }
+
+    #[test]
+    fn test_every_player_shuffles_in_turn() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let (players, public_key) = setup(&mut rng);
+        let prover = ShuffleProver::new(public_key, ROUNDS);
+        let verifier = ShuffleVerifier::new(public_key, ROUNDS);
+
+        let mut deck = open_deck(52);
+        for _ in &players {
+            let (shuffled_deck, proof) = prover.shuffle(&deck, &mut rng);
+            assert_eq!(verifier.verify(&deck, &shuffled_deck, &proof), Ok(()));
+            deck = shuffled_deck;
+        }
+
+        // The deck still holds every card once, in an order no one knows alone
+        let mut cards: Vec<usize> = deck
+            .iter()
+            .map(|ciphertext| {
+                let shares: Vec<RistrettoPoint> = players
+                    .iter()
+                    .map(|player| player.decryption_share(ciphertext))
+                    .collect();
+                ciphertext.decrypt(&shares, 52).unwrap()
+            })
+            .collect();
+        assert_ne!(cards, (0..52).collect::<Vec<usize>>());
+        cards.sort();
+        assert_eq!(cards, (0..52).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_swapped_card_is_caught() {
+        let mut rng = ChaCha20Rng::seed_from_u64(2);
+        let (_, public_key) = setup(&mut rng);
+        let prover = ShuffleProver::new(public_key, ROUNDS);
+        let verifier = ShuffleVerifier::new(public_key, ROUNDS);
+        let deck = open_deck(52);
+        let (mut shuffled_deck, proof) = prover.shuffle(&deck, &mut rng);
+
+        // Replacing a card with another one breaks every opening of the output
+        shuffled_deck[0] = shuffled_deck[1].reencrypt(&public_key, &Scalar::random(&mut rng));
+        assert!(matches!(
+            verifier.verify(&deck, &shuffled_deck, &proof),
+            Err(ShuffleProofError::OpeningMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_malformed_proofs_are_rejected() {
+        let mut rng = ChaCha20Rng::seed_from_u64(3);
+        let (_, public_key) = setup(&mut rng);
+        let prover = ShuffleProver::new(public_key, ROUNDS);
+        let deck = open_deck(52);
+        let (shuffled_deck, proof) = prover.shuffle(&deck, &mut rng);
+
+        assert_eq!(
+            ShuffleVerifier::new(public_key, ROUNDS + 1).verify(&deck, &shuffled_deck, &proof),
+            Err(ShuffleProofError::RoundCountMismatch {
+                expected: ROUNDS + 1,
+                found: ROUNDS,
+            })
+        );
+        let verifier = ShuffleVerifier::new(public_key, ROUNDS);
+        assert_eq!(
+            verifier.verify(&deck, &shuffled_deck[1..], &proof),
+            Err(ShuffleProofError::DeckSizeMismatch {
+                input: 52,
+                output: 51,
+            })
+        );
+
+        // An opening that uses a card twice is not a shuffle
+        let mut forged = proof.clone();
+        forged.openings[0].permutation[0] = forged.openings[0].permutation[1];
+        assert_eq!(
+            verifier.verify(&deck, &shuffled_deck, &forged),
+            Err(ShuffleProofError::InvalidPermutation(0))
+        );
+
+        // The shuffle has to be made under the key the verifier expects
+        let (_, other_key) = setup(&mut rng);
+        assert!(ShuffleVerifier::new(other_key, ROUNDS)
+            .verify(&deck, &shuffled_deck, &proof)
+            .is_err());
+    }
+}
@@ -3,7 +3,10 @@ use candid::{Decode, Encode};
 use ic_stable_structures::{storable::Bound, Storable};
 use std::borrow::Cow;
 
-use crate::poker::game::{table_functions::table::TableConfig, types::StorableTable};
+use crate::poker::game::{
+    table_functions::table::TableConfig,
+    types::{ArchivedRound, StorableTable},
+};
 
 const MAX_VALUE_SIZE_TABLE: u32 = 2_000_000_000;
 const MAX_VALUE_SIZE_CONFIG: u32 = 1000;
@@ -63,3 +66,26 @@ impl Storable for TableConfig {
         is_fixed_size: false,
     };
 }
+
+impl Storable for ArchivedRound {
+    /// Serializes the struct into a byte array.
+    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap_or_else(|e| {
+            ic_cdk::println!("Serialization error: {:?}", e);
+            vec![]
+        }))
+    }
+
+    /// Deserializes the struct from a byte array.
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap_or_else(|e| {
+            ic_cdk::println!("Deserialization error: {:?}", e);
+            ArchivedRound {
+                rng_metadata: Default::default(),
+                card_provenance: Vec::new(),
+            }
+        })
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
@@ -287,6 +287,27 @@ pub async fn start_new_betting_round_wrapper(table_id: TableId) -> Result<(), Ta
     }
 }
 
+pub async fn close_run_it_window_wrapper(table_id: TableId) -> Result<(), TableError> {
+    let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "close_run_it_window").await;
+
+    match call_result {
+        Ok(res) => match res.candid() {
+            Ok(res) => res,
+            Err(err) => {
+                ic_cdk::println!("Error closing run it window: {:?}", err);
+                Err(TableError::CanisterCallError(format!(
+                    "Failed to decode close_run_it_window response: {:?}",
+                    err
+                )))
+            }
+        },
+        Err(err) => {
+            ic_cdk::println!("Error in close_run_it_window call: {:?}", err);
+            Err(TableError::CanisterCallError(format!("{:?}", err)))
+        }
+    }
+}
+
 pub async fn pause_table_for_addon_wrapper(
     table_id: TableId,
     duration: u64,
@@ -334,6 +355,27 @@ pub async fn pause_table(table_id: TableId) -> Result<(), TableError> {
     }
 }
 
+pub async fn queue_bomb_pot_wrapper(table_id: TableId) -> Result<(), TableError> {
+    let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "queue_bomb_pot").await;
+
+    match call_result {
+        Ok(res) => match res.candid() {
+            Ok(res) => res,
+            Err(err) => {
+                ic_cdk::println!("Error queueing bomb pot: {:?}", err);
+                Err(TableError::CanisterCallError(format!(
+                    "Failed to decode queue_bomb_pot response: {:?}",
+                    err
+                )))
+            }
+        },
+        Err(err) => {
+            ic_cdk::println!("Error in queue_bomb_pot call: {:?}", err);
+            Err(TableError::CanisterCallError(format!("{:?}", err)))
+        }
+    }
+}
+
 pub async fn resume_table_wrapper(table_id: TableId) -> Result<(), TableError> {
     let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "resume_table").await;
 
@@ -427,6 +469,32 @@ pub async fn set_as_final_table_wrapper(table_id: TableId) -> Result<(), TableEr
     }
 }
 
+pub async fn set_hand_for_hand_wrapper(
+    table_id: TableId,
+    is_hand_for_hand: bool,
+) -> Result<(), TableError> {
+    let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "set_hand_for_hand")
+        .with_arg(is_hand_for_hand)
+        .await;
+
+    match call_result {
+        Ok(res) => match res.candid() {
+            Ok(res) => res,
+            Err(err) => {
+                ic_cdk::println!("Error setting hand-for-hand: {:?}", err);
+                Err(TableError::CanisterCallError(format!(
+                    "Failed to decode set_hand_for_hand response: {:?}",
+                    err
+                )))
+            }
+        },
+        Err(err) => {
+            ic_cdk::println!("Error in set_hand_for_hand call: {:?}", err);
+            Err(TableError::CanisterCallError(format!("{:?}", err)))
+        }
+    }
+}
+
 pub async fn kick_player_wrapper(
     table_id: TableId,
     users_canister_id: UsersCanisterId,
@@ -558,6 +626,34 @@ pub async fn handle_user_losing_wrapper(
     }
 }
 
+pub async fn handle_table_hand_finished_wrapper(
+    tournament_id: Principal,
+    id: TableId,
+    busted_players: Vec<(WalletPrincipalId, u64)>,
+) -> Result<(), TournamentError> {
+    let call_result =
+        ic_cdk::call::Call::unbounded_wait(tournament_id, "handle_table_hand_finished")
+            .with_args(&(id, busted_players))
+            .await;
+
+    match call_result {
+        Ok(res) => match res.candid() {
+            Ok(res) => res,
+            Err(err) => {
+                ic_cdk::println!("Error handling table hand finished: {:?}", err);
+                Err(TournamentError::CanisterCallError(format!(
+                    "Failed to decode handle_table_hand_finished response: {:?}",
+                    err
+                )))
+            }
+        },
+        Err(err) => {
+            ic_cdk::println!("Error in handle_table_hand_finished call: {:?}", err);
+            Err(TournamentError::CanisterCallError(format!("{:?}", err)))
+        }
+    }
+}
+
 pub async fn withdraw_rake_wrapper(table_id: TableId, rake_total: u64) -> Result<(), TableError> {
     let call_result = ic_cdk::call::Call::unbounded_wait(table_id.0, "withdraw_rake")
         .with_arg(rake_total)
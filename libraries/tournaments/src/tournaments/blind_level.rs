@@ -264,6 +264,7 @@ impl BlindLevel {
             AnteType::PercentageOfBigBlind(percentage) => {
                 (self.big_blind * percentage as u64) / 100
             }
+            AnteType::ButtonAnte(amount) => amount,
         }
     }
 
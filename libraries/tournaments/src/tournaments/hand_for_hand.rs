@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use table::poker::game::table_functions::table::TableId;
+use user::user::WalletPrincipalId;
+
+use super::{
+    payouts::calculate_dynamic_payout_structure,
+    tournament_type::{TournamentSizeType, TournamentType},
+    types::TournamentData,
+};
+
+/// Hand-for-hand play near the money, where every table finishes its hand
+/// before any of them starts the next one.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct HandForHand {
+    /// Whether the tables are playing hand-for-hand.
+    pub is_active: bool,
+    /// The tables that finished the current hand and are waiting for the others.
+    pub finished_tables: HashSet<TableId>,
+    /// The players the finished tables knocked out in the current hand,
+    /// with the stack each of them started it with.
+    pub busted_players: Vec<(WalletPrincipalId, u64)>,
+    /// The players knocked out together in the last hand, with their starting stacks.
+    pub eliminations: Vec<(WalletPrincipalId, u64)>,
+    /// Players knocked out in the same hand with the same starting stack, who share a place.
+    pub tied_finishes: Vec<Vec<WalletPrincipalId>>,
+}
+
+impl TournamentData {
+    /// The number of places paid with everyone who entered so far.
+    pub fn get_paid_places(&self) -> usize {
+        let entries = self
+            .all_players
+            .keys()
+            .chain(self.current_players.keys())
+            .collect::<HashSet<_>>()
+            .len();
+        calculate_dynamic_payout_structure(entries as u32, &self.tournament_type)
+            .payouts
+            .len()
+    }
+
+    /// The tables with enough players to deal a hand.
+    fn get_playing_tables(&self) -> Vec<TableId> {
+        self.tables
+            .iter()
+            .filter(|(_, table)| table.players.len() >= 2)
+            .map(|(table_id, _)| *table_id)
+            .collect()
+    }
+
+    /// Whether `remaining_players` are close enough to the money for hand-for-hand play.
+    fn is_near_the_money(&self, remaining_players: usize) -> bool {
+        let Some(threshold) = self.hand_for_hand_threshold else {
+            return false;
+        };
+        if !matches!(
+            self.tournament_type,
+            TournamentType::BuyIn(TournamentSizeType::MultiTable(_, _))
+                | TournamentType::Freeroll(TournamentSizeType::MultiTable(_, _))
+        ) || self.get_playing_tables().len() < 2
+        {
+            return false;
+        }
+        let paid_places = self.get_paid_places();
+        remaining_players > paid_places && remaining_players <= paid_places + threshold as usize
+    }
+
+    /// Whether the tables should be playing hand-for-hand.
+    pub fn should_play_hand_for_hand(&self) -> bool {
+        self.is_near_the_money(self.current_players.len())
+    }
+
+    /// Whether the tables are playing hand-for-hand.
+    pub fn is_hand_for_hand(&self) -> bool {
+        self.hand_for_hand
+            .as_ref()
+            .is_some_and(|hand_for_hand| hand_for_hand.is_active)
+    }
+
+    /// Starts hand-for-hand play once the players left are near the money.
+    ///
+    /// Returns whether it started, in which case every table has to be told.
+    pub fn start_hand_for_hand(&mut self) -> bool {
+        if self.is_hand_for_hand() || !self.should_play_hand_for_hand() {
+            return false;
+        }
+        let hand_for_hand = self.hand_for_hand.get_or_insert_with(HandForHand::default);
+        hand_for_hand.is_active = true;
+        hand_for_hand.finished_tables.clear();
+        hand_for_hand.busted_players.clear();
+        true
+    }
+
+    /// Records that a table finished its hand and knocked out `busted_players`.
+    ///
+    /// Once every table with a hand to play is done, the players knocked out on all
+    /// of them become one elimination, and hand-for-hand play ends if the bubble burst.
+    ///
+    /// Returns the tables to start the next hand on, empty while tables are still playing.
+    pub fn handle_table_hand_finished(
+        &mut self,
+        table_id: TableId,
+        busted_players: Vec<(WalletPrincipalId, u64)>,
+    ) -> Vec<TableId> {
+        if !self.is_hand_for_hand() {
+            return vec![table_id];
+        }
+        let playing_tables = self.get_playing_tables();
+        let Some(hand_for_hand) = self.hand_for_hand.as_mut() else {
+            return vec![table_id];
+        };
+
+        hand_for_hand.finished_tables.insert(table_id);
+        for (user_principal, starting_stack) in busted_players {
+            if !hand_for_hand
+                .busted_players
+                .iter()
+                .any(|(busted, _)| *busted == user_principal)
+            {
+                hand_for_hand
+                    .busted_players
+                    .push((user_principal, starting_stack));
+            }
+        }
+        if !playing_tables
+            .iter()
+            .all(|table_id| hand_for_hand.finished_tables.contains(table_id))
+        {
+            return Vec::new();
+        }
+
+        let finished_tables = hand_for_hand.finished_tables.drain().collect();
+        hand_for_hand.eliminations = std::mem::take(&mut hand_for_hand.busted_players);
+        let remaining_players = self
+            .current_players
+            .len()
+            .saturating_sub(hand_for_hand.eliminations.len());
+        if !self.is_near_the_money(remaining_players) {
+            if let Some(hand_for_hand) = self.hand_for_hand.as_mut() {
+                hand_for_hand.is_active = false;
+            }
+        }
+        finished_tables
+    }
+
+    /// Ranks the players knocked out together in the last hand-for-hand hand.
+    ///
+    /// `leaderboard` lists the knocked out players, first out first. The players of the
+    /// last elimination already on it are reordered so that a smaller starting stack
+    /// finishes lower, and players who started with the same stack share their place.
+    pub fn rank_hand_for_hand_eliminations(&mut self, leaderboard: &mut [WalletPrincipalId]) {
+        let Some(hand_for_hand) = self.hand_for_hand.as_mut() else {
+            return;
+        };
+        let starting_stack = |user_principal: &WalletPrincipalId| {
+            hand_for_hand
+                .eliminations
+                .iter()
+                .find(|(eliminated, _)| eliminated == user_principal)
+                .map(|(_, starting_stack)| *starting_stack)
+        };
+
+        let positions: Vec<usize> = leaderboard
+            .iter()
+            .enumerate()
+            .filter(|(_, user_principal)| starting_stack(user_principal).is_some())
+            .map(|(position, _)| position)
+            .collect();
+        let mut eliminated: Vec<(WalletPrincipalId, u64)> = positions
+            .iter()
+            .filter_map(|position| {
+                let user_principal = leaderboard[*position];
+                starting_stack(&user_principal).map(|stack| (user_principal, stack))
+            })
+            .collect();
+        eliminated.sort_by_key(|(_, starting_stack)| *starting_stack);
+        for (position, (user_principal, _)) in positions.iter().zip(&eliminated) {
+            leaderboard[*position] = *user_principal;
+        }
+
+        hand_for_hand.tied_finishes.retain(|tied| {
+            !tied
+                .iter()
+                .any(|user_principal| eliminated.iter().any(|(other, _)| other == user_principal))
+        });
+        let mut start = 0;
+        while start < eliminated.len() {
+            let end = eliminated[start..]
+                .iter()
+                .position(|(_, stack)| *stack != eliminated[start].1)
+                .map_or(eliminated.len(), |len| start + len);
+            if end - start > 1 {
+                hand_for_hand.tied_finishes.push(
+                    eliminated[start..end]
+                        .iter()
+                        .map(|(user_principal, _)| *user_principal)
+                        .collect(),
+                );
+            }
+            start = end;
+        }
+    }
+
+    /// Players who share a place because they were knocked out together with the same stack.
+    pub fn get_tied_finishes(&self) -> &[Vec<WalletPrincipalId>] {
+        self.hand_for_hand
+            .as_ref()
+            .map(|hand_for_hand| hand_for_hand.tied_finishes.as_slice())
+            .unwrap_or_default()
+    }
+}
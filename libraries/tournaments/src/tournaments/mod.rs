@@ -2,6 +2,7 @@
 pub mod tests;
 
 pub mod blind_level;
+pub mod hand_for_hand;
 pub mod payouts;
 pub mod spin_and_go;
 pub mod storable;
@@ -1,6 +1,8 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 
+use user::user::WalletPrincipalId;
+
 use crate::tournaments::tournament_type::TournamentType;
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -39,6 +41,51 @@ pub fn calculate_dynamic_payout_structure(
     }
 }
 
+/// The prize of every finishing position, best finish first.
+///
+/// Players in `tied_finishes` share a place and split the prizes of the
+/// positions they finished in equally, the best placed of them getting any remainder.
+pub fn calculate_prizes(
+    total_prize: u64,
+    payout_structure: &PayoutStructure,
+    positions: &[WalletPrincipalId],
+    tied_finishes: &[Vec<WalletPrincipalId>],
+) -> Vec<(WalletPrincipalId, u64)> {
+    let mut prizes: Vec<(WalletPrincipalId, u64)> = positions
+        .iter()
+        .enumerate()
+        .map(|(position, user_principal)| {
+            let prize = payout_structure
+                .payouts
+                .get(position)
+                .map_or(0, |payout| (total_prize * payout.percentage as u64) / 100);
+            (*user_principal, prize)
+        })
+        .collect();
+
+    for tied in tied_finishes {
+        let tied_positions: Vec<usize> = prizes
+            .iter()
+            .enumerate()
+            .filter(|(_, (user_principal, _))| tied.contains(user_principal))
+            .map(|(position, _)| position)
+            .collect();
+        if tied_positions.len() < 2 {
+            continue;
+        }
+        let shared: u64 = tied_positions
+            .iter()
+            .map(|position| prizes[*position].1)
+            .sum();
+        let share = shared / tied_positions.len() as u64;
+        let remainder = shared % tied_positions.len() as u64;
+        for (i, position) in tied_positions.iter().enumerate() {
+            prizes[*position].1 = if i == 0 { share + remainder } else { share };
+        }
+    }
+    prizes
+}
+
 fn get_standard_payout_percentages(total_players: u32) -> Vec<(u16, u8)> {
     match total_players {
         1..=3 => vec![(1, 100)],
@@ -257,4 +304,40 @@ mod tests {
             assert_eq!(payout.position, (i + 1) as u16);
         }
     }
+
+    #[test]
+    fn test_tied_finishes_split_their_prizes() {
+        let structure = calculate_dynamic_payout_structure(
+            9,
+            &TournamentType::BuyIn(TournamentSizeType::SingleTable(BuyInOptions::new_freezout())),
+        );
+        let players: Vec<WalletPrincipalId> = (0..4)
+            .map(|i| WalletPrincipalId(candid::Principal::from_slice(&[i])))
+            .collect();
+
+        let prizes = calculate_prizes(999, &structure, &players, &[]);
+        assert_eq!(
+            prizes.iter().map(|(_, prize)| *prize).collect::<Vec<_>>(),
+            vec![499, 299, 199, 0]
+        );
+
+        // Second and third share 299 + 199
+        let prizes = calculate_prizes(999, &structure, &players, &[vec![players[2], players[1]]]);
+        assert_eq!(
+            prizes.iter().map(|(_, prize)| *prize).collect::<Vec<_>>(),
+            vec![499, 249, 249, 0]
+        );
+
+        // The best placed of the tied players gets the remainder
+        let prizes = calculate_prizes(
+            999,
+            &structure,
+            &players,
+            &[vec![players[0], players[1], players[2]]],
+        );
+        assert_eq!(
+            prizes.iter().map(|(_, prize)| *prize).collect::<Vec<_>>(),
+            vec![333, 332, 332, 0]
+        );
+    }
 }
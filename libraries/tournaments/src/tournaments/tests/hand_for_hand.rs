@@ -0,0 +1,155 @@
+use candid::Principal;
+use table::poker::game::table_functions::table::TableId;
+use user::user::WalletPrincipalId;
+
+use crate::tournaments::{
+    blind_level::SpeedType,
+    table_balancing::TableBalancer,
+    tournament_type::{BuyInOptions, TournamentSizeType, TournamentType},
+    types::{TableInfo, TournamentData, UserTournamentData},
+};
+
+fn player(id: u8) -> WalletPrincipalId {
+    WalletPrincipalId(Principal::from_slice(&[id]))
+}
+
+fn table_id(id: u8) -> TableId {
+    TableId(Principal::from_slice(&[100, id]))
+}
+
+/// A multi-table tournament of 12 entries, which pays 5 places, with the players
+/// left seated at tables of the given sizes.
+fn setup_tournament(table_sizes: &[u8], hand_for_hand_threshold: Option<u32>) -> TournamentData {
+    let mut tournament = TournamentData {
+        tournament_type: TournamentType::BuyIn(TournamentSizeType::MultiTable(
+            BuyInOptions::new_freezout(),
+            TableBalancer::new(2, 8, &SpeedType::new_regular(1000, 10)),
+        )),
+        hand_for_hand_threshold,
+        ..Default::default()
+    };
+
+    let mut id = 0;
+    for (table, size) in table_sizes.iter().enumerate() {
+        let mut table_info = TableInfo::default();
+        for _ in 0..*size {
+            id += 1;
+            table_info.players.insert(player(id));
+            tournament
+                .current_players
+                .insert(player(id), UserTournamentData::default());
+        }
+        tournament.tables.insert(table_id(table as u8), table_info);
+    }
+    while id < 12 {
+        id += 1;
+        tournament
+            .all_players
+            .insert(player(id), UserTournamentData::default());
+    }
+    tournament
+}
+
+#[test]
+fn test_hand_for_hand_starts_near_the_money() {
+    let tournament = setup_tournament(&[4, 4], Some(2));
+    assert_eq!(tournament.get_paid_places(), 5);
+    assert!(!tournament.should_play_hand_for_hand());
+
+    let mut tournament = setup_tournament(&[4, 3], Some(2));
+    assert!(tournament.should_play_hand_for_hand());
+    assert!(tournament.start_hand_for_hand());
+    assert!(tournament.is_hand_for_hand());
+    // The tables are only told once
+    assert!(!tournament.start_hand_for_hand());
+
+    // Not without a threshold or once the players are at a single table
+    assert!(!setup_tournament(&[4, 3], None).should_play_hand_for_hand());
+    assert!(!setup_tournament(&[7], Some(2)).should_play_hand_for_hand());
+}
+
+fn sorted(mut table_ids: Vec<TableId>) -> Vec<TableId> {
+    table_ids.sort_by_key(|table_id| table_id.0);
+    table_ids
+}
+
+#[test]
+fn test_tables_wait_for_each_other_until_the_bubble_bursts() {
+    let mut tournament = setup_tournament(&[4, 3], Some(2));
+    assert!(tournament.start_hand_for_hand());
+
+    assert!(tournament
+        .handle_table_hand_finished(table_id(0), vec![(player(1), 100)])
+        .is_empty());
+    assert_eq!(
+        sorted(tournament.handle_table_hand_finished(table_id(1), Vec::new())),
+        vec![table_id(0), table_id(1)]
+    );
+    assert_eq!(
+        tournament.hand_for_hand.as_ref().unwrap().eliminations,
+        vec![(player(1), 100)]
+    );
+    // Six players are left, still one off the money
+    assert!(tournament.is_hand_for_hand());
+
+    let user_data = tournament.current_players.remove(&player(1)).unwrap();
+    tournament.all_players.insert(player(1), user_data);
+    tournament
+        .tables
+        .get_mut(&table_id(0))
+        .unwrap()
+        .players
+        .remove(&player(1));
+
+    assert!(tournament
+        .handle_table_hand_finished(table_id(1), vec![(player(5), 300)])
+        .is_empty());
+    // A table reporting twice does not count a player twice
+    assert!(tournament
+        .handle_table_hand_finished(table_id(1), vec![(player(5), 300)])
+        .is_empty());
+    assert_eq!(
+        sorted(tournament.handle_table_hand_finished(table_id(0), vec![(player(2), 200)])),
+        vec![table_id(0), table_id(1)]
+    );
+    assert_eq!(
+        tournament.hand_for_hand.as_ref().unwrap().eliminations,
+        vec![(player(5), 300), (player(2), 200)]
+    );
+    assert!(!tournament.is_hand_for_hand());
+
+    // Back to normal play every table starts its own next hand
+    assert_eq!(
+        tournament.handle_table_hand_finished(table_id(1), Vec::new()),
+        vec![table_id(1)]
+    );
+}
+
+#[test]
+fn test_simultaneous_eliminations_are_ranked_by_starting_stack() {
+    let mut tournament = setup_tournament(&[4, 3], Some(2));
+    assert!(tournament.start_hand_for_hand());
+    tournament.handle_table_hand_finished(table_id(0), vec![(player(1), 300), (player(2), 100)]);
+    tournament.handle_table_hand_finished(table_id(1), vec![(player(5), 100)]);
+
+    // The players are ranked as soon as each of them is knocked out
+    let mut leaderboard = vec![player(12), player(1)];
+    tournament.rank_hand_for_hand_eliminations(&mut leaderboard);
+    assert_eq!(leaderboard, vec![player(12), player(1)]);
+
+    let mut leaderboard = vec![player(12), player(1), player(2)];
+    tournament.rank_hand_for_hand_eliminations(&mut leaderboard);
+    assert_eq!(leaderboard, vec![player(12), player(2), player(1)]);
+    assert!(tournament.get_tied_finishes().is_empty());
+
+    let mut leaderboard = vec![player(12), player(2), player(1), player(5)];
+    tournament.rank_hand_for_hand_eliminations(&mut leaderboard);
+    assert_eq!(
+        leaderboard,
+        vec![player(12), player(2), player(5), player(1)]
+    );
+    assert_eq!(
+        tournament.get_tied_finishes(),
+        &[vec![player(2), player(5)]]
+    );
+}
@@ -1,2 +1,3 @@
 pub mod balance_moves;
 pub mod calculate_players_per_table;
+pub mod hand_for_hand;
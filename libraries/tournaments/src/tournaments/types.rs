@@ -14,6 +14,7 @@ use crate::tournaments::payouts::{calculate_dynamic_payout_structure, PayoutStru
 
 use super::{
     blind_level::{BlindLevel, SpeedType},
+    hand_for_hand::HandForHand,
     spin_and_go::{SpinGoMultiplier, SpinGoMultiplierDistribution},
     table_balancing::TableBalancer,
     tournament_type::{BuyInOptions, TournamentSizeType, TournamentType},
@@ -60,6 +61,10 @@ pub struct TournamentData {
     pub sorted_users: Option<Vec<(WalletPrincipalId, u64)>>,
 
     pub require_proof_of_humanity: bool,
+
+    /// How many players outside the paid places the tables start playing hand-for-hand.
+    pub hand_for_hand_threshold: Option<u32>,
+    pub hand_for_hand: Option<HandForHand>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -110,6 +115,8 @@ impl Default for TournamentData {
             tables: HashMap::new(),
             sorted_users: None,
             require_proof_of_humanity: false,
+            hand_for_hand_threshold: None,
+            hand_for_hand: None,
         }
     }
 }
@@ -183,6 +190,7 @@ pub struct NewTournament {
     pub tournament_type: TournamentType,
     pub start_time: u64,
     pub require_proof_of_humanity: bool,
+    pub hand_for_hand_threshold: Option<u32>,
 }
 
 impl TournamentData {
@@ -312,6 +320,8 @@ impl TournamentData {
             tables: HashMap::new(),
             sorted_users: None,
             require_proof_of_humanity: new_tournament_data.require_proof_of_humanity,
+            hand_for_hand_threshold: new_tournament_data.hand_for_hand_threshold,
+            hand_for_hand: None,
         };
 
         Ok(tournament)
@@ -7,8 +7,13 @@ compile_and_extract() {
   local canister=$1
 #   local canister_name=${canister%"_canister"}
 
+  local features=""
+  if [ "$canister" == "table_canister" ] && [ -n "$TABLE_CANISTER_FEATURES" ]; then
+    features="--features $TABLE_CANISTER_FEATURES"
+  fi
+
   echo "Compiling the Canister Wasm module for $canister..."
-  cargo build --release --target wasm32-unknown-unknown --package "$canister"
+  cargo build --release --target wasm32-unknown-unknown --package "$canister" $features
 
   if [ $? -ne 0 ]; then
     echo "Failed to compile $canister."
@@ -68,7 +73,7 @@ compile_and_extract() {
 # Find all Rust packages in the src/ directory
 canisters=$(cargo metadata --no-deps --format-version 1 | jq -r '.packages[] | select(.manifest_path | contains("/src/")) | .name')
 
-cargo build --release --target wasm32-unknown-unknown --package table_canister
+cargo build --release --target wasm32-unknown-unknown --package table_canister ${TABLE_CANISTER_FEATURES:+--features "$TABLE_CANISTER_FEATURES"}
 cargo build --release --target wasm32-unknown-unknown --package tournament_canister
 cargo build --release --target wasm32-unknown-unknown --package clans_canister
 cargo build --release --target wasm32-unknown-unknown --package users_canister
@@ -48,7 +48,8 @@ if ! [ -f $POCKET_IC_BIN ]; then
   echo "PocketIC download completed"
 fi
 
-./scripts/build-canisters.sh
+# Check that every table transition conserves chips in the tested canisters
+TABLE_CANISTER_FEATURES=chip_invariants ./scripts/build-canisters.sh
 
 # Download nns canisters
 download_nns_wasm icp_ledger ledger-canister
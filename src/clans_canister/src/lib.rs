@@ -21,7 +21,7 @@ use ic_cdk::management_canister::{canister_status, CanisterStatusArgs, DepositCy
 use intercanister_call_wrappers::{tournament_canister::create_tournament_wrapper, users_canister::get_user_wrapper};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
-use table::{poker::game::{table_functions::{table::{TableConfig, TableId}, types::CurrencyType}, types::PublicTable}, table_canister::create_table_wrapper};
+use table::{poker::game::{table_functions::{table::{TableConfig, TableId}, types::CurrencyType}, types::PublicTable}, table_canister::{create_table_wrapper, queue_bomb_pot_wrapper}};
 use tournaments::tournaments::types::{NewTournament, TournamentData, TournamentId};
 use user::user::{UsersCanisterId, WalletPrincipalId};
 use utils::handle_cycle_check;
@@ -802,6 +802,33 @@ async fn remove_clan_table(
     Ok(())
 }
 
+/// Make the next hand at a clan table a bomb pot (members who can create tables only)
+#[ic_cdk::update]
+async fn trigger_clan_bomb_pot(
+    table_id: TableId,
+    triggered_by: WalletPrincipalId,
+) -> Result<(), ClanError> {
+    handle_cycle_check();
+
+    {
+        let clan = CLAN.lock().map_err(|_| ClanError::LockError)?;
+        let clan = clan.as_ref().ok_or(ClanError::ClanNotFound)?;
+
+        if !clan.has_tier_access(&triggered_by, "can_create_tables")? {
+            return Err(ClanError::InsufficientPermissions);
+        }
+        if !clan.active_tables.contains(&table_id) {
+            return Err(ClanError::InvalidRequest(
+                "Table does not belong to the clan".to_string(),
+            ));
+        }
+    }
+
+    queue_bomb_pot_wrapper(table_id)
+        .await
+        .map_err(|e| ClanError::CanisterCallError(format!("Failed to queue bomb pot: {:?}", e)))
+}
+
 /// Remove a tournament from the clan (admin+ only)
 #[ic_cdk::update]
 async fn remove_clan_tournament(
@@ -6,6 +6,13 @@ use canister_functions::{rake_constants, rake_stats::RakeStats};
 use chat::{ChatHistory, ChatMessage, ChatMessageType};
 use currency::{state::TransactionState, types::currency_manager::CurrencyManager, Currency};
 use errors::{chat_error::ChatError, game_error::GameError, table_error::TableError};
+use fairness::{
+    merkle::{CardProof, DeckCommitment},
+    shuffle::{
+        calculate_deck_hash, generate_card_provenance, recreate_deck, recreate_shuffle_seed,
+        reshuffle_bytes_hash,
+    },
+};
 use ic_cdk::management_canister::{canister_status, CanisterStatusArgs, DepositCyclesArgs};
 use intercanister_call_wrappers::{
     log_store::log_actions_wrapper,
@@ -17,17 +24,26 @@ use intercanister_call_wrappers::{
 use lazy_static::lazy_static;
 use table::{
     poker::{
-        core::{Card, FlatDeck, Rank, Suit, Value},
+        core::{Card, Equity, EquityResult, Rank, Suit, Value},
         game::{
             table_functions::{
                 action_log::ActionType,
                 ante::AnteType,
+                auto_rebuy::AutoRebuy,
+                dead_button::MissedBlindsChoice,
+                pre_action::PreAction,
+                rng_archive::{
+                    paginate, DEFAULT_CARD_PROVENANCE_PAGE_SIZE, DEFAULT_RNG_HISTORY_PAGE_SIZE,
+                    MAX_CARD_PROVENANCE_PAGE_SIZE, MAX_RNG_HISTORY_PAGE_SIZE,
+                    MAX_RNG_HISTORY_ROUNDS, MAX_ROUNDS_ARCHIVED_PER_HAND,
+                },
+                shuffle_seed::{combine_shuffle_seeds, shuffle_commitment, CommittedShuffle},
                 table::{BigBlind, SmallBlind, Table, TableConfig, TableId, TableType},
                 types::{BetType, CurrencyType, DealStage, Notification, PlayerAction, SeatStatus},
             },
             types::{
-                CardProvenance, PlayerCardOwnership, PublicTable, QueueItem, RngMetadata, RngStats,
-                TableStatus,
+                CardProvenance, CardProvenancePage, PlayerCardOwnership, PublicTable, QueueItem,
+                RngHistoryPage, RngMetadata, RngStats, TableStatus,
             },
             utils::rank_hand,
         },
@@ -41,10 +57,9 @@ use user::user::{User, UsersCanisterId, WalletPrincipalId, REFERRAL_PERIOD};
 use utils::{
     get_user_index_principal, handle_cycle_check, handle_last_user_leaving,
     handle_table_validity_check, update_player_count_tournament, update_table_player_count,
+    update_table_waitlist_length,
 };
 
-use crate::utils::reshuffle_bytes_hash;
-
 mod memory;
 pub mod utils;
 
@@ -125,9 +140,8 @@ fn get_table() -> Result<PublicTable, TableError> {
     let table = table.as_mut().ok_or(TableError::TableNotFound)?;
     let caller = WalletPrincipalId(ic_cdk::api::msg_caller());
 
-    if table.deal_stage != DealStage::Showdown {
-        table.hide_cards(caller).map_err(|e| e.into_inner())?;
-    }
+    // Cards shown down or shown after the hand stay visible to everyone
+    table.hide_cards(caller).map_err(|e| e.into_inner())?;
     Ok(table.into())
 }
 
@@ -155,14 +169,7 @@ fn get_rng_metadata(round_id: u64) -> Result<RngMetadata, TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
 
-    let mut rng_metadata = table
-        .rng_history
-        .iter()
-        .find(|rng| rng.round_id == round_id)
-        .cloned()
-        .ok_or_else(|| {
-            TableError::InvalidRequest(format!("RNG metadata not found for round {}", round_id))
-        })?;
+    let mut rng_metadata = find_rng_metadata(table, round_id)?;
 
     // 🔒 SECURITY: Hide sensitive data during gameplay to prevent deck reconstruction
     // Only reveal after game ends for verification
@@ -173,9 +180,13 @@ fn get_rng_metadata(round_id: u64) -> Result<RngMetadata, TableError> {
         rng_metadata.timestamp_ns = 0; // Hide - same as time_seed
         // Hide shuffled_deck during gameplay (even though it's stored)
         rng_metadata.shuffled_deck = Vec::new();
+        hide_player_seeds(&mut rng_metadata);
                                        // ✅ Keep raw_random_bytes - visible from start for transparency
                                        // ✅ Keep deck_hash - commitment proof
     }
+    if table.keeps_mucked_cards_private() {
+        hide_shuffle(&mut rng_metadata);
+    }
 
     Ok(rng_metadata)
 }
@@ -201,14 +212,19 @@ fn get_current_rng_metadata() -> Result<RngMetadata, TableError> {
         rng_metadata.timestamp_ns = 0; // Hide - same as time_seed
         // Hide shuffled_deck during gameplay (even though it's stored)
         rng_metadata.shuffled_deck = Vec::new();
+        hide_player_seeds(&mut rng_metadata);
                                        // ✅ Keep raw_random_bytes - visible from start for transparency
                                        // ✅ Keep deck_hash - commitment proof
     }
+    if table.keeps_mucked_cards_private() {
+        hide_shuffle(&mut rng_metadata);
+    }
 
     Ok(rng_metadata)
 }
 
 /// Get all RNG history (paginated to avoid large responses)
+/// Only covers the rounds the table still holds, use `get_rng_history_page` for archived rounds
 #[ic_cdk::query]
 fn get_rng_history(
     limit: Option<u64>,
@@ -237,6 +253,10 @@ fn get_rng_history(
                 rng_clone.timestamp_ns = 0;
                 // ✅ Keep raw_random_bytes - visible from start for transparency
                 rng_clone.shuffled_deck = Vec::new();
+                hide_player_seeds(&mut rng_clone);
+            }
+            if table.keeps_mucked_cards_private() {
+                hide_shuffle(&mut rng_clone);
             }
             rng_clone
         })
@@ -245,13 +265,124 @@ fn get_rng_history(
     Ok(history)
 }
 
+/// Get RNG history oldest round first, starting at the `cursor` round ID (paginated to avoid large responses)
+/// Archived rounds come first, then the rounds the table still holds
+#[ic_cdk::query]
+fn get_rng_history_page(
+    cursor: Option<u64>,
+    limit: Option<u64>,
+) -> Result<RngHistoryPage, TableError> {
+    let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+
+    let limit = limit
+        .map_or(DEFAULT_RNG_HISTORY_PAGE_SIZE, |limit| limit as usize)
+        .min(MAX_RNG_HISTORY_PAGE_SIZE);
+
+    // 🔒 SECURITY: Hide time_seed for current round if game is ongoing
+    let game_ended = table.sorted_users.is_some();
+    let current_round = table.round_ticker;
+
+    // One more than the page, to know whether there is a next one
+    // The table can hold more than a page while an old history is still being archived
+    let history = memory::get_archived_rng_history(cursor, limit + 1)
+        .into_iter()
+        .chain(
+            table
+                .rng_history
+                .iter()
+                .filter(|rng| cursor.is_none_or(|cursor| rng.round_id >= cursor))
+                .take(limit + 1)
+                .cloned(),
+        );
+    let (mut rounds, next_cursor) = paginate(history, cursor, limit, |rng| rng.round_id);
+
+    for rng in rounds.iter_mut() {
+        // Hide sensitive data for current ongoing round
+        if !game_ended && rng.round_id == current_round {
+            rng.time_seed = 0;
+            rng.timestamp_ns = 0;
+            // ✅ Keep raw_random_bytes - visible from start for transparency
+            rng.shuffled_deck = Vec::new();
+            hide_player_seeds(rng);
+        }
+        if table.keeps_mucked_cards_private() {
+            hide_shuffle(rng);
+        }
+    }
+
+    Ok(RngHistoryPage {
+        rounds,
+        next_cursor,
+    })
+}
+
+/// RNG metadata of a round, from the table or the archive of older rounds
+fn find_rng_metadata(table: &Table, round_id: u64) -> Result<RngMetadata, TableError> {
+    table
+        .rng_history
+        .iter()
+        .find(|rng| rng.round_id == round_id)
+        .cloned()
+        .or_else(|| memory::get_archived_round(round_id).map(|round| round.rng_metadata))
+        .ok_or_else(|| {
+            TableError::InvalidRequest(format!("RNG metadata not found for round {}", round_id))
+        })
+}
+
+/// Card provenance of a round, from the table or the archive of older rounds
+fn find_round_provenance(table: &Table, round_id: u64) -> Vec<CardProvenance> {
+    let provenance: Vec<CardProvenance> = table
+        .card_provenance
+        .values()
+        .filter(|prov| prov.round_id == round_id)
+        .cloned()
+        .collect();
+    if !provenance.is_empty() {
+        return provenance;
+    }
+    memory::get_archived_round(round_id)
+        .map(|round| round.card_provenance)
+        .unwrap_or_default()
+}
+
+/// Hides the revealed player seeds of the hand being played, keeping the commitments.
+fn hide_player_seeds(rng_metadata: &mut RngMetadata) {
+    for player_seed in rng_metadata.player_seeds.iter_mut().flatten() {
+        player_seed.seed = None;
+    }
+}
+
+/// Hides everything the deck could be rebuilt from on tables that keep mucked cards private.
+///
+/// The random bytes go too, since the time seed is close to when the hand started.
+/// Cards are proven against the deck's Merkle root instead.
+fn hide_shuffle(rng_metadata: &mut RngMetadata) {
+    rng_metadata.raw_random_bytes = Vec::new();
+    rng_metadata.time_seed = 0;
+    rng_metadata.timestamp_ns = 0;
+    rng_metadata.shuffled_deck = Vec::new();
+    hide_player_seeds(rng_metadata);
+}
+
+/// The provenance of a card without the card, for cards the caller may not see.
+fn hide_card_value(provenance: &CardProvenance) -> CardProvenance {
+    CardProvenance {
+        card: Card {
+            value: Value::Two,
+            suit: Suit::Spade,
+        },
+        ..provenance.clone()
+    }
+}
+
 /// Get the total number of rounds with RNG data
 #[ic_cdk::query]
 fn get_rng_history_count() -> Result<u64, TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
 
-    Ok(table.rng_history.len() as u64)
+    Ok(memory::archived_round_count() + table.rng_history.len() as u64)
 }
 
 /// Get card provenance by card hash
@@ -264,50 +395,50 @@ fn get_card_provenance(card_hash: String) -> Result<CardProvenance, TableError>
         .card_provenance
         .get(&card_hash)
         .cloned()
+        .or_else(|| {
+            memory::get_archived_round_by_card_hash(&card_hash).and_then(|round| {
+                round
+                    .card_provenance
+                    .into_iter()
+                    .find(|prov| prov.card_hash == card_hash)
+            })
+        })
         .ok_or_else(|| {
             TableError::InvalidRequest(format!("Card provenance not found for hash {}", card_hash))
         })?;
 
     // 🔒 SECURITY: Hide card value during gameplay
-    let game_ended = table.sorted_users.is_some();
+    let game_ended = table.sorted_users.is_some() || provenance.round_id != table.round_ticker;
 
-    if !game_ended {
+    if !game_ended || !table.is_card_public_after_hand(&provenance) {
         // During gameplay: return ONLY hash and position
         // Hide the actual card value to prevent cheating
-        let dummy_card = Card {
-            value: Value::Two,
-            suit: Suit::Spade,
-        };
-
-        Ok(CardProvenance {
-            round_id: provenance.round_id,
-            card: dummy_card,
-            original_position: provenance.original_position,
-            shuffled_position: provenance.shuffled_position,
-            card_hash: provenance.card_hash,
-            dealt_to: provenance.dealt_to,
-            dealt_at_stage: provenance.dealt_at_stage,
-        })
+        Ok(hide_card_value(&provenance))
     } else {
         // After game: reveal actual card
         Ok(provenance)
     }
 }
 
-/// Get card provenance for a specific round (or current round if None)
+/// Get card provenance for a specific round (or current round if None), by shuffled position from `cursor` on
 /// Players can only see their own cards and community cards during gameplay
 #[ic_cdk::query]
 fn get_card_provenance_by_round_id(
     round_id: Option<u64>,
-) -> Result<Vec<CardProvenance>, TableError> {
+    cursor: Option<u8>,
+    limit: Option<u64>,
+) -> Result<CardProvenancePage, TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
 
     let target_round = round_id.unwrap_or(table.round_ticker);
     let caller = WalletPrincipalId(ic_cdk::api::msg_caller());
+    let limit = limit
+        .map_or(DEFAULT_CARD_PROVENANCE_PAGE_SIZE, |limit| limit as usize)
+        .min(MAX_CARD_PROVENANCE_PAGE_SIZE);
 
-    // 🔒 SECURITY: Check if game has ended
-    let game_ended = table.sorted_users.is_some();
+    // 🔒 SECURITY: Check if game has ended (only the hand being played is still going)
+    let game_ended = table.sorted_users.is_some() || target_round != table.round_ticker;
 
     // Get caller's cards (if they're playing)
     let caller_cards: Vec<Card> = table
@@ -319,13 +450,11 @@ fn get_card_provenance_by_round_id(
     // Get community cards
     let community_cards = table.community_cards.clone();
 
-    let mut provenance: Vec<CardProvenance> = table
-        .card_provenance
-        .values()
-        .filter(|prov| prov.round_id == target_round)
+    let mut provenance: Vec<CardProvenance> = find_round_provenance(table, target_round)
+        .iter()
         .map(|prov| {
-            if game_ended {
-                // After game: reveal all cards
+            if game_ended && table.is_card_public_after_hand(prov) {
+                // After game: reveal all cards, but mucked ones on private tables
                 return prov.clone();
             }
 
@@ -334,7 +463,8 @@ fn get_card_provenance_by_round_id(
                 format!("{:?}:{:?}", c1.value, c1.suit) == format!("{:?}:{:?}", c2.value, c2.suit)
             };
 
-            let is_callers_card = caller_cards.iter().any(|c| card_matches(c, &prov.card));
+            let is_callers_card = caller_cards.iter().any(|c| card_matches(c, &prov.card))
+                || prov.dealt_to == Some(caller);
             let is_community_card = community_cards.iter().any(|c| card_matches(c, &prov.card));
 
             if is_callers_card || is_community_card {
@@ -342,20 +472,7 @@ fn get_card_provenance_by_round_id(
                 prov.clone()
             } else {
                 // Hide this card (belongs to another player or not dealt yet)
-                let dummy_card = Card {
-                    value: Value::Two,
-                    suit: Suit::Spade,
-                };
-
-                CardProvenance {
-                    round_id: prov.round_id,
-                    card: dummy_card,
-                    original_position: prov.original_position,
-                    shuffled_position: prov.shuffled_position,
-                    card_hash: prov.card_hash.clone(),
-                    dealt_to: prov.dealt_to.clone(),
-                    dealt_at_stage: prov.dealt_at_stage.clone(),
-                }
+                hide_card_value(prov)
             }
         })
         .collect();
@@ -363,8 +480,12 @@ fn get_card_provenance_by_round_id(
     // Sort by shuffled_position to ensure consistent order (0-51)
     // Frontend displays cards by shuffled_position, so sorting here makes the response predictable
     provenance.sort_by_key(|p| p.shuffled_position);
+    let (provenance, next_cursor) = paginate(provenance, cursor, limit, |p| p.shuffled_position);
 
-    Ok(provenance)
+    Ok(CardProvenancePage {
+        provenance,
+        next_cursor,
+    })
 }
 
 /// Get other players' card ownership info for RNG dashboard
@@ -408,7 +529,7 @@ fn get_other_players_card_ownership(
     let mut orphaned_cards = 0;
 
     // Iterate through card provenance once - O(n) where n = cards in round
-    for prov in table.card_provenance.values() {
+    for prov in find_round_provenance(table, target_round) {
         // Skip cards from other rounds
         if prov.round_id != target_round {
             continue;
@@ -496,6 +617,7 @@ fn get_other_players_card_ownership(
 fn get_all_card_provenance(limit: Option<u64>) -> Result<Vec<CardProvenance>, TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+    let caller = WalletPrincipalId(ic_cdk::api::msg_caller());
 
     let limit = limit.unwrap_or(10).min(50); // Default 10, max 50
     let total_rounds = table.rng_history.len();
@@ -518,7 +640,14 @@ fn get_all_card_provenance(limit: Option<u64>) -> Result<Vec<CardProvenance>, Ta
         .card_provenance
         .values()
         .filter(|prov| recent_round_ids.contains(&prov.round_id))
-        .cloned()
+        .map(|prov| {
+            // Mucked cards stay hidden on private tables, except from their owner
+            if table.is_card_public_after_hand(prov) || prov.dealt_to == Some(caller) {
+                prov.clone()
+            } else {
+                hide_card_value(prov)
+            }
+        })
         .collect();
 
     Ok(provenance)
@@ -530,18 +659,27 @@ fn verify_shuffle(round_id: u64) -> Result<bool, TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
 
-    let rng_metadata = table
-        .rng_history
+    let rng_metadata = find_rng_metadata(table, round_id)?;
+
+    // Every revealed seed has to be the one the player committed to
+    if rng_metadata
+        .player_seeds
         .iter()
-        .find(|rng| rng.round_id == round_id)
-        .ok_or_else(|| {
-            TableError::InvalidRequest(format!("RNG metadata not found for round {}", round_id))
-        })?;
+        .flatten()
+        .any(|player_seed| player_seed.seed.is_some() && !player_seed.matches_commitment())
+    {
+        return Ok(false);
+    }
+    // And the randomness has to be what the canister committed to before the seeds were revealed
+    if let Some(commitment) = &rng_metadata.shuffle_commitment {
+        if *commitment != shuffle_commitment(&rng_metadata.raw_random_bytes, rng_metadata.time_seed)
+        {
+            return Ok(false);
+        }
+    }
 
     // Recreate the deck from the RNG metadata
-    let mut shuffled_bytes = rng_metadata.raw_random_bytes.clone();
-    reshuffle_bytes_hash(&mut shuffled_bytes, rng_metadata.time_seed);
-    let deck = FlatDeck::new(shuffled_bytes);
+    let deck = recreate_deck(&table.config.game_type, &rng_metadata);
 
     // Calculate hash and compare
     let calculated_hash = calculate_deck_hash(&deck);
@@ -550,6 +688,96 @@ fn verify_shuffle(round_id: u64) -> Result<bool, TableError> {
     Ok(matches)
 }
 
+/// Get Merkle proofs for the cards of a round (or current round if None) the caller may see
+/// Proves the caller's own cards, the board and the cards shown to the whole table,
+/// so mucked hands never have to be revealed
+#[ic_cdk::query]
+fn get_card_proofs(round_id: Option<u64>) -> Result<Vec<CardProof>, TableError> {
+    let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+
+    let target_round = round_id.unwrap_or(table.round_ticker);
+    let caller = WalletPrincipalId(ic_cdk::api::msg_caller());
+
+    let rng_metadata = find_rng_metadata(table, target_round)?;
+    if rng_metadata.deck_merkle_root.is_none() {
+        return Err(TableError::InvalidRequest(format!(
+            "Round {} was not committed to a Merkle root",
+            target_round
+        )));
+    }
+
+    let deck_commitment = DeckCommitment::new(
+        target_round,
+        &recreate_shuffle_seed(&rng_metadata),
+        &rng_metadata.shuffled_deck,
+    );
+    let mut card_proofs: Vec<CardProof> = find_round_provenance(table, target_round)
+        .iter()
+        .filter(|prov| prov.dealt_to == Some(caller) || prov.is_seen_by_table())
+        .filter_map(|prov| deck_commitment.prove(prov.shuffled_position))
+        .collect();
+    card_proofs.sort_by_key(|card_proof| card_proof.shuffled_position);
+
+    Ok(card_proofs)
+}
+
+/// Measure the instructions a zero-knowledge shuffle of the table's deck takes to prove and verify
+/// Only built with the `zk_shuffle` feature, to size the prototype against the limits of a message
+#[cfg(feature = "zk_shuffle")]
+#[ic_cdk::update]
+async fn measure_zk_shuffle(
+    rounds: Option<u64>,
+) -> Result<table::poker::zk_shuffle::ShuffleMeasurement, TableError> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err(TableError::AuthorizationError);
+    }
+    let deck_size = {
+        let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+        let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+        table.config.game_type.new_deck(vec![0; 32]).len()
+    };
+
+    let raw_bytes = ic_cdk::management_canister::raw_rand().await.map_err(|e| {
+        TableError::CanisterCallError(format!("Failed to generate random bytes: {:?}", e))
+    })?;
+    let seed: [u8; 32] = raw_bytes.try_into().map_err(|_| {
+        TableError::CanisterCallError("Expected 32 random bytes".to_string())
+    })?;
+
+    let rounds = rounds.map_or(table::poker::zk_shuffle::DEFAULT_ROUNDS, |rounds| rounds as usize);
+    table::poker::zk_shuffle::measure_shuffle(deck_size, rounds, seed, || {
+        ic_cdk::api::performance_counter(0)
+    })
+    .map_err(|e| TableError::InvalidRequest(e.to_string()))
+}
+
+/// Measure the instructions the `Rankable` and lookup table hand evaluators take to rank `hands` hands
+/// Only built with the `hand_evaluator_bench` feature, to compare what ranking costs in a canister
+#[cfg(feature = "hand_evaluator_bench")]
+#[ic_cdk::update]
+async fn measure_hand_evaluator(
+    hands: Option<u64>,
+) -> Result<table::poker::core::HandEvaluatorMeasurement, TableError> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err(TableError::AuthorizationError);
+    }
+
+    let raw_bytes = ic_cdk::management_canister::raw_rand().await.map_err(|e| {
+        TableError::CanisterCallError(format!("Failed to generate random bytes: {:?}", e))
+    })?;
+    let seed: [u8; 32] = raw_bytes
+        .try_into()
+        .map_err(|_| TableError::CanisterCallError("Expected 32 random bytes".to_string()))?;
+
+    let hands = hands.map_or(10_000, |hands| hands as usize);
+    Ok(table::poker::core::measure_hand_evaluators(
+        hands,
+        seed,
+        || ic_cdk::api::performance_counter(0),
+    ))
+}
+
 /// Get RNG statistics summary
 #[ic_cdk::query]
 fn get_rng_stats() -> Result<RngStats, TableError> {
@@ -557,9 +785,10 @@ fn get_rng_stats() -> Result<RngStats, TableError> {
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
 
     Ok(RngStats {
-        total_rounds: table.rng_history.len() as u64,
-        total_cards_tracked: table.card_provenance.len() as u64,
-        oldest_round_id: table.rng_history.first().map(|r| r.round_id),
+        total_rounds: memory::archived_round_count() + table.rng_history.len() as u64,
+        total_cards_tracked: memory::archived_card_count() + table.card_provenance.len() as u64,
+        oldest_round_id: memory::oldest_archived_round_id()
+            .or_else(|| table.rng_history.first().map(|r| r.round_id)),
         latest_round_id: table.rng_history.last().map(|r| r.round_id),
         current_round_ticker: table.round_ticker,
     })
@@ -588,14 +817,18 @@ async fn join_table(
         table.as_ref().ok_or(TableError::TableNotFound)?.clone()
     };
 
-    if table.big_blind.0 > deposit_amount {
-        return Err(TableError::InsufficientFunds);
-    }
+    table
+        .validate_buy_in(user_id, deposit_amount, ic_cdk::api::time())
+        .map_err(|e| match e.into_inner() {
+            GameError::InsufficientFunds => TableError::InsufficientFunds,
+            e => e.into(),
+        })?;
 
+    let reserved_seat_index = table.get_reserved_seat_index(user_id);
     let seat_index = match seat_index {
         Some(seat_index) => seat_index as u8,
-        None => table
-            .get_free_seat_index()
+        None => reserved_seat_index
+            .or_else(|| table.get_free_seat_index())
             .ok_or(TableError::InvalidRequest("Table is full.".to_string()))?,
     };
 
@@ -638,7 +871,9 @@ async fn join_table(
     let mut user = user?;
     user.balance.0 = deposit_amount;
 
-    if table.users.users.contains_key(&user_id) || table.is_user_in_table(user_id) {
+    if table.users.users.contains_key(&user_id)
+        || (table.is_user_in_table(user_id) && reserved_seat_index.is_none())
+    {
         return Err(TableError::UserAlreadyInGame);
     }
 
@@ -724,6 +959,11 @@ async fn join_table(
     if let Err(e) = res {
         ic_cdk::println!("Error updating table player count: {:?}", e);
     }
+    if matches!(table_state.config.table_type, Some(TableType::Cash)) {
+        if let Err(e) = update_table_waitlist_length(table_state.waitlist.len()) {
+            ic_cdk::println!("Error updating table waitlist length: {:?}", e);
+        }
+    }
 
     Ok(table_state.into())
 }
@@ -825,6 +1065,11 @@ async fn kick_player(
     if let Err(e) = res {
         ic_cdk::println!("Error updating table player count: {:?}", e);
     }
+    if matches!(table.config.table_type, Some(TableType::Cash)) {
+        if let Err(e) = update_table_waitlist_length(table.waitlist.len()) {
+            ic_cdk::println!("Error updating table waitlist length: {:?}", e);
+        }
+    }
 
     if table.users.users.is_empty() {
         handle_last_user_leaving().await?;
@@ -949,6 +1194,11 @@ async fn leave_table(
     if let Err(e) = res {
         ic_cdk::println!("Error updating table player count: {:?}", e);
     }
+    if matches!(table.config.table_type, Some(TableType::Cash)) {
+        if let Err(e) = update_table_waitlist_length(table.waitlist.len()) {
+            ic_cdk::println!("Error updating table waitlist length: {:?}", e);
+        }
+    }
 
     if table.users.users.is_empty() {
         handle_last_user_leaving().await?;
@@ -1022,6 +1272,11 @@ async fn leave_table_for_table_balancing(
     if let Err(e) = res {
         ic_cdk::println!("Error updating table player count: {:?}", e);
     }
+    if matches!(table.config.table_type, Some(TableType::Cash)) {
+        if let Err(e) = update_table_waitlist_length(table.waitlist.len()) {
+            ic_cdk::println!("Error updating table waitlist length: {:?}", e);
+        }
+    }
 
     Ok(table.into())
 }
@@ -1048,6 +1303,7 @@ async fn withdraw_from_table(user_id: WalletPrincipalId, amount: u64) -> Result<
                 "Backend principal not found.".to_string(),
             ))?;
         validate_caller(vec![user_id.0, user.principal_id.0, backend_principal]);
+        table.validate_withdrawal().map_err(|e| e.into_inner())?;
 
         let user_table_data = table
             .get_user_table_data(user_id)
@@ -1083,129 +1339,523 @@ async fn withdraw_from_table(user_id: WalletPrincipalId, amount: u64) -> Result<
                     .clone()
             };
 
-            currency_manager
-                .withdraw(&currency, user_id.0, amount)
-                .await?;
-        }
-        CurrencyType::Fake => {}
+            currency_manager
+                .withdraw(&currency, user_id.0, amount)
+                .await?;
+        }
+        CurrencyType::Fake => {}
+    }
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn deposit_to_table(
+    users_canister_id: UsersCanisterId,
+    user_id: WalletPrincipalId,
+    amount: u64,
+    is_queued: bool,
+) -> Result<ReturnResult, TableError> {
+    handle_cycle_check().await;
+    if amount == 0 {
+        return Err(TableError::InvalidRequest(
+            "Deposit amount cannot be 0".to_string(),
+        ));
+    }
+    // Only the table itself takes queued top-ups, which were capped when they were taken
+    if is_queued && ic_cdk::api::msg_caller() != ic_cdk::api::canister_self() {
+        return Err(TableError::AuthorizationError);
+    }
+
+    {
+        let table = {
+            let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+            table.as_ref().ok_or(TableError::TableNotFound)?.clone()
+        };
+        if !is_queued {
+            table
+                .validate_top_up(user_id, amount)
+                .map_err(|e| e.into_inner())?;
+        }
+        match table.config.currency_type {
+            CurrencyType::Real(currency) => {
+                let currency_manager = {
+                    let currency_manager =
+                        CURRENCY_MANAGER.lock().map_err(|_| TableError::LockError)?;
+                    currency_manager
+                        .as_ref()
+                        .ok_or(TableError::StateNotInitialized)?
+                        .clone()
+                };
+
+                if let Err(e) = currency_manager
+                    .validate_allowance(&currency, user_id.0, amount)
+                    .await
+                {
+                    return Err(e.into());
+                }
+            }
+            CurrencyType::Fake => {}
+        }
+    }
+
+    let table = {
+        let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
+        let table = table.as_mut().ok_or(TableError::TableNotFound)?;
+
+        if table.is_game_ongoing()
+            && !is_queued
+            && table
+                .get_user_table_data(user_id)
+                .map_err(|e| e.into_inner())?
+                .player_action
+                != PlayerAction::Folded
+        {
+            table.append_to_queue(QueueItem::Deposit(user_id, users_canister_id, amount));
+            return Ok(ReturnResult::DepositQueued);
+        }
+
+        let user = table
+            .users
+            .get_mut(&user_id)
+            .ok_or(TableError::UserNotFound)?;
+        user.deposit(amount);
+        table.clone()
+    };
+
+    match table.config.currency_type {
+        CurrencyType::Real(currency) => {
+            let currency_manager = {
+                let currency_manager =
+                    CURRENCY_MANAGER.lock().map_err(|_| TableError::LockError)?;
+                currency_manager
+                    .as_ref()
+                    .ok_or(TableError::StateNotInitialized)?
+                    .clone()
+            };
+
+            let mut transaction_state = {
+                let transaction_state = TRANSACTION_STATE
+                    .lock()
+                    .map_err(|_| TableError::LockError)?;
+                transaction_state.clone()
+            };
+
+            match currency_manager
+                .deposit(&mut transaction_state, &currency, user_id.0, amount)
+                .await
+            {
+                Ok(_) => {
+                    *TRANSACTION_STATE
+                        .lock()
+                        .map_err(|_| TableError::LockError)? = transaction_state;
+                }
+                Err(e) => {
+                    ic_cdk::println!("Error depositing: {:?}", e);
+                    let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
+                    let table = table.as_mut().ok_or(TableError::TableNotFound)?;
+                    let user = table
+                        .users
+                        .get_mut(&user_id)
+                        .ok_or(TableError::UserNotFound)?;
+                    user.withdraw(amount);
+                    return Err(e.into());
+                }
+            }
+        }
+        CurrencyType::Fake => {}
+    }
+    Ok(ReturnResult::DepositSuccessful)
+}
+
+#[ic_cdk::update]
+async fn set_player_action(
+    user_principal: WalletPrincipalId,
+    player_action: PlayerAction,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_player_action(user_principal, player_action)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn set_run_it_times(user_principal: WalletPrincipalId, runs: u8) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_user_run_it_times(user_principal, runs)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn close_run_it_window() -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    let mut valid_callers = (*CONTROLLER_PRINCIPALS).clone();
+    valid_callers.push(backend_principal);
+    valid_callers.push(table_state.id.0);
+    validate_caller(valid_callers);
+
+    table_state
+        .close_run_it_window(ic_cdk::api::time())
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn set_straddle(
+    user_principal: WalletPrincipalId,
+    wants_to_straddle: bool,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_user_straddle(user_principal, wants_to_straddle)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+/// Get the commitment to the canister's randomness for the next shuffle on commit-reveal tables
+/// Players reveal their seeds once it is published
+#[ic_cdk::query]
+fn get_shuffle_commitment() -> Result<Option<Vec<u8>>, TableError> {
+    let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+    Ok(table.get_shuffle_commitment())
+}
+
+#[ic_cdk::update]
+async fn commit_shuffle_seed(
+    user_principal: WalletPrincipalId,
+    commitment: Vec<u8>,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    validate_caller(vec![user_principal.0, user.principal_id.0]);
+
+    table_state
+        .commit_shuffle_seed(user_principal, commitment)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn reveal_shuffle_seed(
+    user_principal: WalletPrincipalId,
+    seed: Vec<u8>,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    validate_caller(vec![user_principal.0, user.principal_id.0]);
+
+    table_state
+        .reveal_shuffle_seed(user_principal, seed)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn set_auto_rebuy(
+    user_principal: WalletPrincipalId,
+    auto_rebuy: Option<AutoRebuy>,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_auto_rebuy(user_principal, auto_rebuy)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn set_missed_blinds_choice(
+    user_principal: WalletPrincipalId,
+    choice: MissedBlindsChoice,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_user_missed_blinds_choice(user_principal, choice)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn use_time_bank(user_principal: WalletPrincipalId) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .request_time_bank(user_principal)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn set_pre_action(
+    user_principal: WalletPrincipalId,
+    pre_action: Option<PreAction>,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .set_user_pre_action(user_principal, pre_action)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn show_or_muck_cards(
+    user_principal: WalletPrincipalId,
+    shown_cards: Vec<bool>,
+) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .show_or_muck_cards(user_principal, shown_cards)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn rabbit_hunt(user_principal: WalletPrincipalId) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let user = table_state
+        .users
+        .get(&user_principal)
+        .ok_or(TableError::UserNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![
+        user_principal.0,
+        user.principal_id.0,
+        backend_principal,
+    ]);
+
+    table_state
+        .rabbit_hunt(user_principal)
+        .map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn join_waitlist(user_principal: WalletPrincipalId) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![user_principal.0, backend_principal]);
+
+    table_state
+        .join_waitlist(user_principal, ic_cdk::api::time())
+        .map_err(|e| e.into_inner())?;
+    if let Err(e) = update_table_waitlist_length(table_state.waitlist.len()) {
+        ic_cdk::println!("Error updating table waitlist length: {:?}", e);
     }
     Ok(())
 }
 
 #[ic_cdk::update]
-async fn deposit_to_table(
-    users_canister_id: UsersCanisterId,
-    user_id: WalletPrincipalId,
-    amount: u64,
-    is_queued: bool,
-) -> Result<ReturnResult, TableError> {
+async fn leave_waitlist(user_principal: WalletPrincipalId) -> Result<(), TableError> {
     handle_cycle_check().await;
-    if amount == 0 {
-        return Err(TableError::InvalidRequest(
-            "Deposit amount cannot be 0".to_string(),
-        ));
-    }
-
-    {
-        let table = {
-            let table = TABLE.lock().map_err(|_| TableError::LockError)?;
-            table.as_ref().ok_or(TableError::TableNotFound)?.clone()
-        };
-        match table.config.currency_type {
-            CurrencyType::Real(currency) => {
-                let currency_manager = {
-                    let currency_manager =
-                        CURRENCY_MANAGER.lock().map_err(|_| TableError::LockError)?;
-                    currency_manager
-                        .as_ref()
-                        .ok_or(TableError::StateNotInitialized)?
-                        .clone()
-                };
+    let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+    validate_caller(vec![user_principal.0, backend_principal]);
 
-                if let Err(e) = currency_manager
-                    .validate_allowance(&currency, user_id.0, amount)
-                    .await
-                {
-                    return Err(e.into());
-                }
-            }
-            CurrencyType::Fake => {}
-        }
+    table_state
+        .leave_waitlist(user_principal, ic_cdk::api::time())
+        .map_err(|e| e.into_inner())?;
+    if let Err(e) = update_table_waitlist_length(table_state.waitlist.len()) {
+        ic_cdk::println!("Error updating table waitlist length: {:?}", e);
     }
+    Ok(())
+}
 
-    let table = {
-        let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
-        let table = table.as_mut().ok_or(TableError::TableNotFound)?;
-
-        if table.is_game_ongoing()
-            && !is_queued
-            && table
-                .get_user_table_data(user_id)
-                .map_err(|e| e.into_inner())?
-                .player_action
-                != PlayerAction::Folded
-        {
-            table.append_to_queue(QueueItem::Deposit(user_id, users_canister_id, amount));
-            return Ok(ReturnResult::DepositQueued);
-        }
-
-        let user = table
-            .users
-            .get_mut(&user_id)
-            .ok_or(TableError::UserNotFound)?;
-        user.deposit(amount);
-        table.clone()
-    };
-
-    match table.config.currency_type {
-        CurrencyType::Real(currency) => {
-            let currency_manager = {
-                let currency_manager =
-                    CURRENCY_MANAGER.lock().map_err(|_| TableError::LockError)?;
-                currency_manager
-                    .as_ref()
-                    .ok_or(TableError::StateNotInitialized)?
-                    .clone()
-            };
-
-            let mut transaction_state = {
-                let transaction_state = TRANSACTION_STATE
-                    .lock()
-                    .map_err(|_| TableError::LockError)?;
-                transaction_state.clone()
-            };
-
-            match currency_manager
-                .deposit(&mut transaction_state, &currency, user_id.0, amount)
-                .await
-            {
-                Ok(_) => {
-                    *TRANSACTION_STATE
-                        .lock()
-                        .map_err(|_| TableError::LockError)? = transaction_state;
-                }
-                Err(e) => {
-                    ic_cdk::println!("Error depositing: {:?}", e);
-                    let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
-                    let table = table.as_mut().ok_or(TableError::TableNotFound)?;
-                    let user = table
-                        .users
-                        .get_mut(&user_id)
-                        .ok_or(TableError::UserNotFound)?;
-                    user.withdraw(amount);
-                    return Err(e.into());
-                }
-            }
-        }
-        CurrencyType::Fake => {}
-    }
-    Ok(ReturnResult::DepositSuccessful)
+#[ic_cdk::query]
+fn get_waitlist_position(user_principal: WalletPrincipalId) -> Result<Option<u64>, TableError> {
+    let table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_ref().ok_or(TableError::TableNotFound)?;
+    Ok(table
+        .get_waitlist_position(user_principal)
+        .map(|position| position as u64))
 }
 
 #[ic_cdk::update]
-async fn set_player_action(
+async fn request_seat_change(
     user_principal: WalletPrincipalId,
-    player_action: PlayerAction,
+    seat_index: u64, // javascript can't send u8
 ) -> Result<(), TableError> {
     handle_cycle_check().await;
     let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
@@ -1226,9 +1876,14 @@ async fn set_player_action(
         backend_principal,
     ]);
 
+    let seat_index = u8::try_from(seat_index)
+        .map_err(|_| TableError::InvalidRequest("Seat index out of bounds".to_string()))?;
     table_state
-        .set_player_action(user_principal, player_action)
+        .request_seat_change(user_principal, seat_index, ic_cdk::api::time())
         .map_err(|e| e.into_inner())?;
+    if let Err(e) = update_table_waitlist_length(table_state.waitlist.len()) {
+        ic_cdk::println!("Error updating table waitlist length: {:?}", e);
+    }
     Ok(())
 }
 
@@ -1387,7 +2042,7 @@ async fn player_sitting_in(
         return Ok(());
     } else {
         table_state
-            .set_player_action(user_id, PlayerAction::None)
+            .user_sitting_in(user_id)
             .map_err(|e| e.into_inner())?;
         *TABLE.lock().map_err(|_| TableError::LockError)? = Some(table_state.clone());
     }
@@ -1548,10 +2203,25 @@ async fn handle_timer_expiration(user_id: WalletPrincipalId) -> Result<(), Table
     valid_callers.push(table_state.id.0);
     validate_caller(valid_callers);
 
+    // The time bank extends the turn before it times out
+    if let Some(seconds) = table_state
+        .start_time_bank(user_id, ic_cdk::api::time())
+        .map_err(|e| e.into_inner())?
+    {
+        table_state.start_turn_timer(user_id, seconds.into());
+        return Ok(());
+    }
+
     let res = if table_state.is_users_current_total_bet_equal_to_highest_bet(user_id) {
         table_state
             .user_check(user_id, true)
             .map_err(|e| e.into_inner())
+    } else if table_state
+        .protect_disconnected_user(user_id, ic_cdk::api::time())
+        .map_err(|e| e.into_inner())?
+    {
+        // Disconnect protection keeps the user in the hand for the chips they already put in
+        Ok(())
     } else {
         table_state.force_fold(user_id).map_err(|e| e.into_inner())
     };
@@ -1565,24 +2235,59 @@ async fn handle_timer_expiration(user_id: WalletPrincipalId) -> Result<(), Table
 async fn start_new_betting_round() -> Result<(), TableError> {
     handle_cycle_check().await;
 
+    // Take auto-rebuys from the players' wallets before anyone is kicked for a short stack
+    let due_auto_rebuys = {
+        let table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+        let table_state = table_state.as_ref().ok_or(TableError::TableNotFound)?;
+        let backend_principal = BACKEND_PRINCIPAL
+            .lock()
+            .map_err(|_| TableError::LockError)?
+            .ok_or(TableError::CanisterCallError(
+                "Backend principal not found.".to_string(),
+            ))?;
+        validate_caller(vec![backend_principal, table_state.id.0]);
+
+        if table_state.is_game_ongoing() {
+            Vec::new()
+        } else {
+            table_state.get_due_auto_rebuys()
+        }
+    };
+    for (users_canister_id, user_id, amount) in due_auto_rebuys {
+        if let Err(e) = deposit_to_table(users_canister_id, user_id, amount, true).await {
+            ic_cdk::println!("Auto-rebuy failed for {}: {:?}", user_id.0.to_text(), e);
+            let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+            let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
+            table_state
+                .handle_failed_auto_rebuy(user_id, e.to_string())
+                .map_err(|e| e.into_inner())?;
+        }
+    }
+
+    // Commit-reveal tables shuffle with randomness committed to a hand ahead,
+    // so the first hand needs a second draw to commit to for the next one
+    let needs_first_commitment = {
+        let table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+        let table_state = table_state.as_ref().ok_or(TableError::TableNotFound)?;
+        table_state.is_commit_reveal_shuffle() && table_state.committed_shuffle.is_none()
+    };
+
     let raw_bytes = ic_cdk::management_canister::raw_rand().await;
     let raw_bytes = raw_bytes.map_err(|e| {
         TableError::CanisterCallError(format!("Failed to generate random bytes: {:?}", e))
     })?;
-
-    // Store original bytes BEFORE reshuffling for transparency
-    let original_random_bytes = raw_bytes.clone();
+    let next_shuffle_bytes = if needs_first_commitment {
+        let next_shuffle_bytes = ic_cdk::management_canister::raw_rand().await;
+        Some(next_shuffle_bytes.map_err(|e| {
+            TableError::CanisterCallError(format!("Failed to generate random bytes: {:?}", e))
+        })?)
+    } else {
+        None
+    };
 
     // Get current time in nanoseconds as seed
     let time_seed = ic_cdk::api::time();
 
-    // Reshuffle the bytes using time as seed
-    let mut shuffled_bytes = raw_bytes;
-    reshuffle_bytes_hash(&mut shuffled_bytes, time_seed);
-
-    // Create deck to capture the shuffle result
-    let deck = FlatDeck::new(shuffled_bytes.clone());
-
     let (kicked_players, action_logs, table_id, seated_out_kicked_players, users) = {
         let mut table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
         let table_state = table_state.as_mut().ok_or(TableError::TableNotFound)?;
@@ -1616,12 +2321,62 @@ async fn start_new_betting_round() -> Result<(), TableError> {
             .into());
         }
 
+        // During hand-for-hand play the tournament starts the next hand once every table is done
+        if table_state.is_hand_for_hand && ic_cdk::api::msg_caller() != backend_principal {
+            if let Some(TableType::Tournament { tournament_id, .. }) =
+                table_state.config.table_type.clone()
+            {
+                table_state.report_hand_finished(tournament_id);
+            }
+            return Err(GameError::ActionNotAllowed {
+                reason: "Waiting for the other tables to finish their hand".to_string(),
+            }
+            .into());
+        }
+
         let action_logs = table_state.action_logs.clone();
 
+        // Commit-reveal tables use the randomness they committed to before the seeds were
+        // revealed, and commit to the fresh draw for the next hand
+        let (original_random_bytes, shuffle_time_seed, used_commitment, next_shuffle) =
+            match table_state.committed_shuffle.clone() {
+                Some(committed_shuffle) if table_state.is_commit_reveal_shuffle() => (
+                    committed_shuffle.random_bytes,
+                    committed_shuffle.time_seed,
+                    Some(committed_shuffle.commitment),
+                    Some(CommittedShuffle::new(raw_bytes, time_seed)),
+                ),
+                _ => (
+                    raw_bytes,
+                    time_seed,
+                    None,
+                    next_shuffle_bytes.map(|bytes| CommittedShuffle::new(bytes, time_seed)),
+                ),
+            };
+
+        // Reshuffle the bytes using time as seed
+        let mut shuffled_bytes = original_random_bytes.clone();
+        reshuffle_bytes_hash(&mut shuffled_bytes, shuffle_time_seed);
+
+        // Seeds the players revealed after the randomness was committed go into the shuffle
+        let player_seeds = table_state
+            .is_commit_reveal_shuffle()
+            .then(|| table_state.get_shuffle_player_seeds());
+        let shuffled_bytes = match &player_seeds {
+            Some(player_seeds) => combine_shuffle_seeds(&shuffled_bytes, player_seeds),
+            None => shuffled_bytes,
+        };
+
+        // Create deck to capture the shuffle result (the deck size follows the game type)
+        let deck = table_state
+            .config
+            .game_type
+            .new_deck(shuffled_bytes.clone());
+
         // ✅ Call start_betting_round FIRST (before storing RNG data)
         // This ensures we only store RNG data if the round successfully starts
         let (kicked_players, seated_out_kicked_players) =
-            match table_state.start_betting_round(shuffled_bytes) {
+            match table_state.start_betting_round(shuffled_bytes.clone()) {
                 Ok(kicked_players) => kicked_players,
                 Err(e) => {
                     ic_cdk::println!("Error starting betting round: {:?}", e);
@@ -1633,6 +2388,8 @@ async fn start_new_betting_round() -> Result<(), TableError> {
         // round_ticker has already been incremented in start_betting_round, so use it directly
         let round_id = table_state.round_ticker;
         let deck_hash = calculate_deck_hash(&deck);
+        // Commits to every card on its own, so single cards can be proven later
+        let deck_merkle_root = DeckCommitment::new(round_id, &shuffled_bytes, deck.cards()).root();
 
         // ✅ CRITICAL FIX: Store the FULL original shuffled deck BEFORE dealing any cards
         // This ensures we can reveal the complete 52-card deck after the game ends
@@ -1640,12 +2397,17 @@ async fn start_new_betting_round() -> Result<(), TableError> {
         let rng_metadata = RngMetadata {
             round_id,
             raw_random_bytes: original_random_bytes,
-            time_seed,
+            time_seed: shuffle_time_seed,
             timestamp_ns: time_seed,
             deck_hash,
             ic_transaction_id: None, // Can be enhanced later with actual transaction ID
             shuffled_deck: deck.cards().to_vec(), // ✅ Store full deck at creation time
+            player_seeds,
+            shuffle_commitment: used_commitment,
+            deck_merkle_root: Some(deck_merkle_root),
         };
+        table_state.clear_shuffle_seeds();
+        table_state.committed_shuffle = next_shuffle;
 
         // 🆕 GENERATE CARD PROVENANCE
         let card_provenance = generate_card_provenance(&deck, round_id);
@@ -1665,6 +2427,13 @@ async fn start_new_betting_round() -> Result<(), TableError> {
                 .unwrap_or_default()
         );
 
+        // Older rounds move to stable memory, so the table stays small enough to upgrade
+        // A few per hand, so a long history is worked off without hitting the instruction limit
+        memory::archive_rounds(
+            table_state
+                .take_archivable_rounds(MAX_RNG_HISTORY_ROUNDS, MAX_ROUNDS_ARCHIVED_PER_HAND),
+        );
+
         (
             kicked_players,
             action_logs,
@@ -1909,6 +2678,32 @@ fn rank_cards(hand: Vec<Card>) -> Result<Rank, TableError> {
     Ok(rank_hand(hand))
 }
 
+#[ic_cdk::query]
+fn get_all_in_equities() -> Result<Option<Vec<(String, Vec<Card>, Equity)>>, TableError> {
+    let table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_ref().ok_or(TableError::TableNotFound)?;
+
+    // Hole cards are only part of the log once the hand has been run out
+    if table_state.deal_stage != DealStage::Showdown {
+        return Ok(None);
+    }
+    Ok(table_state.get_all_in_equities())
+}
+
+#[ic_cdk::query]
+fn calculate_equity(
+    hands: Vec<Vec<Card>>,
+    board: Vec<Card>,
+    dead_cards: Vec<Card>,
+) -> Result<EquityResult, TableError> {
+    let table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table_state = table_state.as_ref().ok_or(TableError::TableNotFound)?;
+
+    table_state
+        .calculate_equity(&hands, &board, &dead_cards)
+        .map_err(|e| e.into_inner().into())
+}
+
 #[ic_cdk::query]
 fn get_notifications() -> Result<Vec<Notification>, TableError> {
     let table_state = TABLE.lock().map_err(|_| TableError::LockError)?;
@@ -2130,6 +2925,25 @@ async fn pause_table() -> Result<(), TableError> {
     Ok(())
 }
 
+#[ic_cdk::update]
+async fn queue_bomb_pot() -> Result<(), TableError> {
+    handle_cycle_check().await;
+
+    let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_mut().ok_or(TableError::TableNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+
+    validate_caller(vec![backend_principal, table.id.0]);
+
+    table.queue_bomb_pot().map_err(|e| e.into_inner())?;
+    Ok(())
+}
+
 #[ic_cdk::update]
 async fn pause_table_for_addon(duration: u64) -> Result<(), TableError> {
     handle_cycle_check().await;
@@ -2195,6 +3009,30 @@ async fn resume_table() -> Result<(), TableError> {
     Ok(())
 }
 
+#[ic_cdk::update]
+async fn set_hand_for_hand(is_hand_for_hand: bool) -> Result<(), TableError> {
+    handle_cycle_check().await;
+    let mut table = TABLE.lock().map_err(|_| TableError::LockError)?;
+    let table = table.as_mut().ok_or(TableError::TableNotFound)?;
+    let backend_principal = BACKEND_PRINCIPAL
+        .lock()
+        .map_err(|_| TableError::LockError)?
+        .ok_or(TableError::CanisterCallError(
+            "Backend principal not found.".to_string(),
+        ))?;
+
+    validate_caller(vec![backend_principal]);
+
+    if !matches!(table.config.table_type, Some(TableType::Tournament { .. })) {
+        return Err(TableError::InvalidRequest(
+            "Table is not a tournament table".to_string(),
+        ));
+    }
+
+    table.is_hand_for_hand = is_hand_for_hand;
+    Ok(())
+}
+
 #[ic_cdk::update]
 async fn set_as_final_table() -> Result<(), TableError> {
     handle_cycle_check().await;
@@ -2479,73 +3317,4 @@ async fn get_canister_status_formatted() -> Result<String, TableError> {
     Ok(formatted_status)
 }
 
-// ============================================================================
-// RNG Transparency Helper Functions
-// ============================================================================
-
-/// Calculate SHA-256 hash of the deck for cryptographic verification
-///
-/// Uses SHA-256 for cryptographic security and transparency.
-/// This ensures hashes can be independently verified by users.
-fn calculate_deck_hash(deck: &FlatDeck) -> String {
-    use sha2::{Digest, Sha256};
-
-    let mut hasher = Sha256::new();
-
-    // Hash each card in the deck deterministically
-    for card in deck.cards() {
-        // Serialize card as value:suit bytes
-        let card_bytes = format!("{:?}:{:?}", card.value, card.suit);
-        hasher.update(card_bytes.as_bytes());
-    }
-
-    // Convert to hex string (64 chars for SHA-256)
-    let result = hasher.finalize();
-    format!("{:x}", result)
-}
-
-/// Generate card provenance for all cards in the deck
-///
-/// Uses SHA-256 for each card hash to ensure cryptographic verification.
-/// Hash format: SHA-256(round_id || card || shuffled_position)
-fn generate_card_provenance(deck: &FlatDeck, round_id: u64) -> HashMap<String, CardProvenance> {
-    use sha2::{Digest, Sha256};
-
-    let mut provenance_map = HashMap::new();
-
-    for (shuffled_position, card) in deck.cards().iter().enumerate() {
-        // Calculate unique SHA-256 hash for this card
-        let mut hasher = Sha256::new();
-
-        // Hash round_id (8 bytes, little-endian)
-        hasher.update(round_id.to_le_bytes());
-
-        // Hash card (deterministic serialization)
-        let card_bytes = format!("{:?}:{:?}", card.value, card.suit);
-        hasher.update(card_bytes.as_bytes());
-
-        // Hash position (1 byte)
-        hasher.update(&[shuffled_position as u8]);
-
-        // Finalize to hex string
-        let result = hasher.finalize();
-        let card_hash = format!("{:x}", result);
-
-        // Create provenance record
-        let provenance = CardProvenance {
-            round_id,
-            card: *card,
-            original_position: shuffled_position as u8,
-            shuffled_position: shuffled_position as u8,
-            card_hash: card_hash.clone(),
-            dealt_to: None,
-            dealt_at_stage: None,
-        };
-
-        provenance_map.insert(card_hash, provenance);
-    }
-
-    provenance_map
-}
-
 ic_cdk::export_candid!();
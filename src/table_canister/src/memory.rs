@@ -4,11 +4,11 @@ use chat::ChatHistory;
 use currency::state::TransactionState;
 use currency::types::currency_manager::CurrencyManager;
 use ic_stable_structures::memory_manager::{MemoryId, MemoryManager, VirtualMemory};
-use ic_stable_structures::Cell;
 use ic_stable_structures::DefaultMemoryImpl;
+use ic_stable_structures::{Cell, StableBTreeMap};
 use std::cell::RefCell;
 use table::poker::game::table_functions::table::Table;
-use table::poker::game::types::StorableTable;
+use table::poker::game::types::{ArchivedRound, RngMetadata, StorableTable};
 
 use crate::{
     BACKEND_PRINCIPAL, CHAT_HISTORY, CURRENCY_MANAGER, RAKE_STATS, TABLE, TRANSACTION_STATE,
@@ -53,6 +53,73 @@ thread_local! {
             ChatHistory::new(1000)
         ).unwrap()
     );
+
+    // RNG data of rounds older than the ones the table keeps, keyed by round ID
+    static RNG_ARCHIVE: RefCell<StableBTreeMap<u64, ArchivedRound, Memory>> = RefCell::new(
+        StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(6))),
+        )
+    );
+
+    // Map card hashes of archived rounds to their round ID
+    static ARCHIVED_CARD_HASHES: RefCell<StableBTreeMap<String, u64, Memory>> = RefCell::new(
+        StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(7))),
+        )
+    );
+}
+
+/// Moves rounds taken out of the table into the archive.
+pub fn archive_rounds(rounds: Vec<ArchivedRound>) {
+    for round in rounds {
+        let round_id = round.rng_metadata.round_id;
+        ARCHIVED_CARD_HASHES.with(|hashes| {
+            let mut hashes = hashes.borrow_mut();
+            for provenance in &round.card_provenance {
+                hashes.insert(provenance.card_hash.clone(), round_id);
+            }
+        });
+        RNG_ARCHIVE.with(|archive| archive.borrow_mut().insert(round_id, round));
+    }
+}
+
+pub fn get_archived_round(round_id: u64) -> Option<ArchivedRound> {
+    RNG_ARCHIVE.with(|archive| archive.borrow().get(&round_id))
+}
+
+pub fn get_archived_round_by_card_hash(card_hash: &str) -> Option<ArchivedRound> {
+    let round_id =
+        ARCHIVED_CARD_HASHES.with(|hashes| hashes.borrow().get(&card_hash.to_string()))?;
+    get_archived_round(round_id)
+}
+
+/// The RNG metadata of up to `limit` archived rounds from `cursor` on, oldest first.
+pub fn get_archived_rng_history(cursor: Option<u64>, limit: usize) -> Vec<RngMetadata> {
+    RNG_ARCHIVE.with(|archive| {
+        archive
+            .borrow()
+            .range(cursor.unwrap_or(0)..)
+            .take(limit)
+            .map(|(_, round)| round.rng_metadata)
+            .collect()
+    })
+}
+
+pub fn archived_round_count() -> u64 {
+    RNG_ARCHIVE.with(|archive| archive.borrow().len())
+}
+
+pub fn archived_card_count() -> u64 {
+    ARCHIVED_CARD_HASHES.with(|hashes| hashes.borrow().len())
+}
+
+pub fn oldest_archived_round_id() -> Option<u64> {
+    RNG_ARCHIVE.with(|archive| {
+        archive
+            .borrow()
+            .first_key_value()
+            .map(|(round_id, _)| round_id)
+    })
 }
 
 #[ic_cdk::pre_upgrade]
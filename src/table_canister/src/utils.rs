@@ -1,12 +1,9 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
-
 use candid::Principal;
 use canister_functions::cycle::check_and_top_up_canister;
 use errors::table_error::TableError;
 use ic_ledger_types::{AccountIdentifier, Subaccount};
 use intercanister_call_wrappers::{
-    table_index::update_table_player_count_wrapper,
+    table_index::{update_table_player_count_wrapper, update_table_waitlist_length_wrapper},
     tournament_canister::update_player_count_tournament_wrapper,
 };
 use table::poker::game::table_functions::types::CurrencyType;
@@ -147,6 +144,37 @@ pub fn update_table_player_count(user_count: usize) -> Result<(), TableError> {
     Ok(())
 }
 
+pub fn update_table_waitlist_length(waitlist_length: usize) -> Result<(), TableError> {
+    let backend_principal = BACKEND_PRINCIPAL.lock();
+    let backend_principal = match backend_principal {
+        Ok(lock) => match *lock {
+            Some(principal) => principal,
+            None => {
+                ic_cdk::println!("Backend principal not found");
+                return Ok(());
+            }
+        },
+        Err(_) => {
+            ic_cdk::println!("Lock error occurred");
+            return Ok(());
+        }
+    };
+
+    ic_cdk::futures::spawn(async move {
+        if let Err(e) = update_table_waitlist_length_wrapper(
+            backend_principal,
+            ic_cdk::api::canister_self(),
+            waitlist_length,
+        )
+        .await
+        {
+            ic_cdk::println!("Failed to update table waitlist length: {:?}", e);
+        }
+    });
+
+    Ok(())
+}
+
 pub fn handle_table_validity_check() -> Result<(), TableError> {
     let table = TABLE.lock().map_err(|_| TableError::LockError)?;
     let table = table.as_ref().ok_or(TableError::TableNotFound)?;
@@ -227,28 +255,3 @@ pub async fn handle_last_user_leaving() -> Result<(), TableError> {
 
     Ok(())
 }
-
-pub fn reshuffle_bytes_hash(bytes: &mut [u8], seed: u64) {
-    if bytes.len() <= 1 {
-        return;
-    }
-
-    // Create indices to shuffle
-    let mut indices: Vec<usize> = (0..bytes.len()).collect();
-
-    // Shuffle indices using hash-based approach
-    for i in (1..indices.len()).rev() {
-        let mut hasher = DefaultHasher::new();
-        seed.hash(&mut hasher);
-        i.hash(&mut hasher);
-        let hash = hasher.finish();
-        let j = (hash as usize) % (i + 1);
-        indices.swap(i, j);
-    }
-
-    // Apply the shuffle to the original bytes
-    let original = bytes.to_vec();
-    for (new_pos, &old_pos) in indices.iter().enumerate() {
-        bytes[new_pos] = original[old_pos];
-    }
-}
@@ -63,6 +63,7 @@ lazy_static! {
     static ref PRIVATE_TABLE_INDEX_STATE: Mutex<PrivateTableIndex> =
         Mutex::new(PrivateTableIndex::new());
     static ref TABLE_PLAYER_COUNTS: Mutex<HashMap<TableId, usize>> = Mutex::new(HashMap::new());
+    static ref TABLE_WAITLIST_LENGTHS: Mutex<HashMap<TableId, usize>> = Mutex::new(HashMap::new());
     static ref CYCLE_DISPENSER_CANISTER_PROD: Principal =
         Principal::from_text("zuv6g-yaaaa-aaaam-qbeza-cai").unwrap();
     static ref CYCLE_DISPENSER_CANISTER_TEST: Principal =
@@ -342,6 +343,32 @@ async fn update_table_player_count(table_id: TableId, count: usize) -> Result<()
     Ok(())
 }
 
+#[ic_cdk::update]
+async fn update_table_waitlist_length(
+    table_id: TableId,
+    length: usize,
+) -> Result<(), TableIndexError> {
+    handle_cycle_check().await?;
+
+    TABLE_WAITLIST_LENGTHS
+        .lock()
+        .map_err(|_| TableIndexError::LockError)?
+        .insert(table_id, length);
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_table_waitlist_lengths() -> Result<Vec<(TableId, usize)>, TableIndexError> {
+    let waitlist_lengths = TABLE_WAITLIST_LENGTHS
+        .lock()
+        .map_err(|_| TableIndexError::LockError)?;
+    Ok(waitlist_lengths
+        .iter()
+        .filter(|(_, length)| **length > 0)
+        .map(|(table_id, length)| (*table_id, *length))
+        .collect())
+}
+
 #[ic_cdk::update]
 async fn get_tables(
     filter_options: Option<FilterOptions>,
@@ -440,6 +467,10 @@ async fn remove_table_from_indexes(table_principal: TableId) -> Result<(), Table
             .map_err(|_| TableIndexError::LockError)?
             .tables
             .remove(&table_principal);
+        TABLE_WAITLIST_LENGTHS
+            .lock()
+            .map_err(|_| TableIndexError::LockError)?
+            .remove(&table_principal);
     }
     Ok(())
 }
@@ -1071,6 +1102,9 @@ async fn update_table_player_counts() -> Result<(), TableIndexError> {
     let mut player_counts = TABLE_PLAYER_COUNTS
         .lock()
         .map_err(|_| TableIndexError::LockError)?;
+    let mut waitlist_lengths = TABLE_WAITLIST_LENGTHS
+        .lock()
+        .map_err(|_| TableIndexError::LockError)?;
 
     for table_id in all_tables {
         let table = get_table_wrapper(table_id).await.map_err(|e| {
@@ -1083,6 +1117,7 @@ async fn update_table_player_counts() -> Result<(), TableIndexError> {
 
         // Update player count
         player_counts.insert(table_id, table.users.len());
+        waitlist_lengths.insert(table_id, table.waitlist.len());
     }
 
     Ok(())
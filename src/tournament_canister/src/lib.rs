@@ -31,10 +31,14 @@ use table::{
         },
         types::PublicTable,
     },
-    table_canister::{clear_table, get_table_wrapper, join_table, leave_table_wrapper},
+    table_canister::{
+        clear_table, get_table_wrapper, join_table, leave_table_wrapper,
+        start_new_betting_round_wrapper,
+    },
 };
 use table_balancing::{check_and_balance_tables, move_player_to_table};
 use tournaments::tournaments::{
+    payouts::calculate_prizes,
     table_balancing::get_balance_interval,
     tournament_type::{TournamentSizeType, TournamentType},
     types::{
@@ -46,8 +50,8 @@ use user::user::{UsersCanisterId, WalletPrincipalId};
 use utils::{
     add_to_tournament_prize_pool, handle_addon, handle_cycle_check_async, handle_invalid_join,
     handle_lost_user_rebuy_availability, handle_rebuy, handle_reentry, handle_refund,
-    handle_tournament_deposit, transfer_cycles_to_tournament_index, update_live_leaderboard,
-    update_tournament_state, LEADERBOARD_UPDATE_INTERVAL,
+    handle_tournament_deposit, set_hand_for_hand_on_tables, transfer_cycles_to_tournament_index,
+    update_live_leaderboard, update_tournament_state, LEADERBOARD_UPDATE_INTERVAL,
 };
 
 pub mod heartbeat;
@@ -829,7 +833,7 @@ async fn distribute_winnings(table: PublicTable) -> Result<(), TournamentError>
         leaderboard.iter().rev().copied().collect()
     };
 
-    {
+    let prizes = {
         let mut tournament = TOURNAMENT.lock().map_err(|_| TournamentError::LockError)?;
         let tournament = tournament
             .as_mut()
@@ -841,14 +845,17 @@ async fn distribute_winnings(table: PublicTable) -> Result<(), TournamentError>
             ));
         }
 
-        let mut positions = positions.iter().map(|p| (*p, 0)).collect::<Vec<_>>();
-        for (i, payout) in tournament.payout_structure.payouts.iter().enumerate() {
-            let prize_amount = (total_prize * payout.percentage as u64) / 100;
-            positions[i].1 = prize_amount;
-        }
+        // Players who tied for a place split the prizes of the places they share
+        let prizes = calculate_prizes(
+            total_prize,
+            &tournament.payout_structure,
+            &positions,
+            tournament.get_tied_finishes(),
+        );
 
-        tournament.sorted_users = Some(positions);
-    }
+        tournament.sorted_users = Some(prizes.clone());
+        prizes
+    };
 
     if let CurrencyType::Real(currency) = tournament.currency {
         let currency_manager = {
@@ -859,28 +866,22 @@ async fn distribute_winnings(table: PublicTable) -> Result<(), TournamentError>
         };
 
         // Distribute according to payout structure
-        for (position, payout) in tournament.payout_structure.payouts.iter().enumerate() {
-            ic_cdk::println!(
-                "Distributing {}% to position {}",
-                payout.percentage,
-                position + 1
-            );
-            if position < positions.len() {
-                let user_id = positions[position];
-
-                let prize_amount = (total_prize * payout.percentage as u64) / 100;
+        for (position, (user_id, prize_amount)) in prizes.into_iter().enumerate() {
+            if prize_amount == 0 {
+                continue;
+            }
+            ic_cdk::println!("Distributing {} to position {}", prize_amount, position + 1);
 
-                currency_manager
-                    .withdraw(&currency, user_id.0, prize_amount)
-                    .await
-                    .map_err(|e| TournamentError::CanisterCallError(format!("{:?}", e)))?;
+            currency_manager
+                .withdraw(&currency, user_id.0, prize_amount)
+                .await
+                .map_err(|e| TournamentError::CanisterCallError(format!("{:?}", e)))?;
 
-                ic_cdk::println!(
-                    "Distributed {} to user {}",
-                    prize_amount,
-                    user_id.0.to_text()
-                );
-            }
+            ic_cdk::println!(
+                "Distributed {} to user {}",
+                prize_amount,
+                user_id.0.to_text()
+            );
         }
 
         let rake = RAKE_AMOUNT.load(Ordering::SeqCst);
@@ -1200,6 +1201,62 @@ async fn handle_user_losing(
         ic_cdk::println!("Error balancing tables: {:?}", e);
     }
 
+    let tables = {
+        let mut tournament = TOURNAMENT.lock().map_err(|_| TournamentError::LockError)?;
+        let tournament = tournament
+            .as_mut()
+            .ok_or(TournamentError::TournamentNotFound)?;
+        if tournament.start_hand_for_hand() {
+            tournament.tables.keys().copied().collect()
+        } else {
+            Vec::new()
+        }
+    };
+    set_hand_for_hand_on_tables(tables, true).await;
+
+    Ok(())
+}
+
+#[ic_cdk::update]
+async fn handle_table_hand_finished(
+    table_id: TableId,
+    busted_players: Vec<(WalletPrincipalId, u64)>,
+) -> Result<(), TournamentError> {
+    handle_cycle_check_async().await;
+
+    let (tables_to_start, tables_to_release) = {
+        let mut tournament = TOURNAMENT.lock().map_err(|_| TournamentError::LockError)?;
+        let tournament = tournament
+            .as_mut()
+            .ok_or(TournamentError::TournamentNotFound)?;
+        validate_caller(vec![table_id.0]);
+        if !tournament.tables.contains_key(&table_id) {
+            return Err(TournamentError::TableNotFound);
+        }
+
+        let tables_to_start = tournament.handle_table_hand_finished(table_id, busted_players);
+        let tables_to_release = if tournament.is_hand_for_hand() {
+            Vec::new()
+        } else {
+            tournament.tables.keys().copied().collect()
+        };
+        (tables_to_start, tables_to_release)
+    };
+
+    // The bubble burst, so the tables go back to playing at their own pace
+    set_hand_for_hand_on_tables(tables_to_release, false).await;
+
+    for table_id in tables_to_start {
+        ic_cdk::futures::spawn(async move {
+            if let Err(e) = start_new_betting_round_wrapper(table_id).await {
+                ic_cdk::println!(
+                    "Error starting the next hand on table {}: {:?}",
+                    table_id.0.to_text(),
+                    e
+                );
+            }
+        });
+    }
     Ok(())
 }
 
@@ -1354,7 +1411,14 @@ async fn move_player_from_to_table(
         }
     }
 
-    *TOURNAMENT.lock().map_err(|_| TournamentError::LockError)? = Some(tournament);
+    {
+        let mut current = TOURNAMENT.lock().map_err(|_| TournamentError::LockError)?;
+        // Tables keep reporting finished hand-for-hand hands while we move the player
+        if let Some(current) = current.as_mut() {
+            tournament.hand_for_hand = current.hand_for_hand.take();
+        }
+        *current = Some(tournament);
+    }
     Ok(())
 }
 
@@ -123,7 +123,14 @@ pub async fn check_and_balance_tables(skip_check: bool) -> Result<(), Tournament
         return Ok(());
     }
 
-    *TOURNAMENT.lock().map_err(|_| TournamentError::LockError)? = Some(tournament);
+    {
+        let mut current = TOURNAMENT.lock().map_err(|_| TournamentError::LockError)?;
+        // Tables keep reporting finished hand-for-hand hands while we balance
+        if let Some(current) = current.as_mut() {
+            tournament.hand_for_hand = current.hand_for_hand.take();
+        }
+        *current = Some(tournament);
+    }
     LAST_BALANCE_TIMESTAMP.store(ic_cdk::api::time(), std::sync::atomic::Ordering::Relaxed);
 
     Ok(())
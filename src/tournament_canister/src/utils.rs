@@ -25,7 +25,7 @@ use table::{
     },
     table_canister::{
         create_table_wrapper, deposit_to_table, get_table_wrapper, is_game_ongoing_wrapper,
-        join_table, leave_table_wrapper, return_all_cycles_to_index,
+        join_table, leave_table_wrapper, return_all_cycles_to_index, set_hand_for_hand_wrapper,
     },
 };
 use tournaments::tournaments::{
@@ -663,6 +663,14 @@ pub async fn handle_user_kick(
             if !leaderboard.contains(&user_principal) {
                 leaderboard.push(user_principal)
             }
+            // Players knocked out in the same hand-for-hand hand finish by their starting stacks
+            if let Some(tournament) = TOURNAMENT
+                .lock()
+                .map_err(|_| TournamentError::LockError)?
+                .as_mut()
+            {
+                tournament.rank_hand_for_hand_eliminations(&mut leaderboard);
+            }
         }
         Err(e) => {
             ic_cdk::println!("Error getting leaderboard: {:?}", e);
@@ -696,6 +704,19 @@ pub async fn handle_user_kick(
     Ok(())
 }
 
+/// Turns hand-for-hand play on or off on every given table.
+pub async fn set_hand_for_hand_on_tables(tables: Vec<TableId>, is_hand_for_hand: bool) {
+    for table_id in tables {
+        if let Err(e) = set_hand_for_hand_wrapper(table_id, is_hand_for_hand).await {
+            ic_cdk::println!(
+                "Error setting hand-for-hand on table {}: {:?}",
+                table_id.0.to_text(),
+                e
+            );
+        }
+    }
+}
+
 pub fn move_player_from_current_players_to_all_players(
     tournament: &mut TournamentData,
     user_principals: &Vec<WalletPrincipalId>,
@@ -204,6 +204,7 @@ pub async fn create_spin_go_tournament(buy_in: u64) -> Result<TournamentId, Tour
         starting_chips: template.starting_chips,
         late_registration_duration_ns: 0,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig::default_spin_and_go(100, tournament_canister.0);
@@ -34,6 +34,13 @@ fn test_create_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table = test_env
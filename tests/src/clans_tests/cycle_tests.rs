@@ -169,6 +169,7 @@ fn test_cycles_clan_tournament_creation() {
         ),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = table::poker::game::table_functions::table::TableConfig {
@@ -193,6 +194,13 @@ fn test_cycles_clan_tournament_creation() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let tournament = test_env
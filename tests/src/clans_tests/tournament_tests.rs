@@ -44,6 +44,7 @@ fn test_create_clan_tournament_success() {
         )),
         start_time: u64::MAX, // Manual start
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     // Get table config for tournament
@@ -69,6 +70,13 @@ fn test_create_clan_tournament_success() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     // Create clan tournament
@@ -122,6 +130,7 @@ fn test_create_multiple_clan_tournaments() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     // Create second tournament (high stakes)
@@ -142,6 +151,7 @@ fn test_create_multiple_clan_tournaments() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -166,6 +176,13 @@ fn test_create_multiple_clan_tournaments() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     // Create both tournaments
@@ -225,6 +242,7 @@ fn test_create_clan_tournament_insufficient_permissions() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -249,6 +267,13 @@ fn test_create_clan_tournament_insufficient_permissions() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     // Regular member tries to create tournament (should fail)
@@ -295,6 +320,7 @@ fn test_remove_clan_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -319,6 +345,13 @@ fn test_remove_clan_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let tournament_id = test_env
@@ -367,6 +400,7 @@ fn test_remove_tournament_insufficient_permissions() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -391,6 +425,13 @@ fn test_remove_tournament_insufficient_permissions() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let tournament_id = test_env
@@ -452,6 +493,7 @@ fn test_admin_can_manage_tournaments() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -476,6 +518,13 @@ fn test_admin_can_manage_tournaments() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let tournament_id = test_env
@@ -513,6 +562,7 @@ fn test_clan_members_can_join_clan_tournaments() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -537,6 +587,13 @@ fn test_clan_members_can_join_clan_tournaments() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let tournament_id = test_env
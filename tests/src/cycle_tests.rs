@@ -43,6 +43,13 @@ fn test_cycles_create_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let cycles_before = test_env
@@ -116,6 +123,7 @@ fn test_cycles_create_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -141,6 +149,13 @@ fn test_cycles_create_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let cycles_before = test_env
@@ -202,6 +217,13 @@ fn test_cycles_create_tournament() {
 //         is_shared_rake: None,
 //         require_proof_of_humanity: None,
 //         is_paused: None,
+//         straddle_type: None,
+//         time_bank: None,
+//         bomb_pot: None,
+//         disconnect_protection: None,
+//         buy_in: None,
+//         commit_reveal_shuffle: None,
+//         keep_mucked_cards_private: None,
 //     };
 
 //     // Create a tournament configuration
@@ -218,6 +240,7 @@ fn test_cycles_create_tournament() {
 //         description: "Tournament for cycle request test".to_string(),
 //         is_private: false,
 //         registration_cut_off: None,
+//         hand_for_hand_threshold: None,
 //     };
 
 //     let tournament_id = test_env
@@ -1,7 +1,11 @@
+use errors::table_error::TableError;
 use serial_test::serial;
-use table::poker::game::{
-    table_functions::types::{BetType, DealStage},
-    utils::convert_to_e8s,
+use table::{
+    poker::game::{
+        table_functions::types::{BetType, DealStage},
+        utils::convert_to_e8s,
+    },
+    types::ReturnResult,
 };
 use user::user::WalletPrincipalId;
 
@@ -144,3 +148,39 @@ fn deposit_mid_game() {
     );
     assert!(public_table.users.get(&deposit_player).unwrap().balance > convert_to_e8s(150.0));
 }
+
+#[test]
+#[serial]
+fn queued_deposit_from_player_is_rejected() {
+    let test_env = TestEnv::get();
+
+    let (public_table, _) = test_env.get_test_icp_table();
+    let (user_1, user_1_id, _) = test_env.create_test_user_with_icp_approval(
+        "user1queueddeposittest".to_string(),
+        1000.0,
+        public_table,
+    );
+    let public_table = test_env
+        .join_test_table(
+            public_table,
+            user_1,
+            user_1_id,
+            convert_to_e8s(100.0),
+            0,
+            false,
+        )
+        .unwrap();
+
+    // Queued top-ups skip the buy-in check, so only the table may send them
+    let result = test_env
+        .pocket_ic
+        .update_call(
+            public_table.id.0,
+            user_1_id.0,
+            "deposit_to_table",
+            candid::encode_args((user_1, user_1_id, convert_to_e8s(10_000.0), true)).unwrap(),
+        )
+        .unwrap();
+    let result: Result<ReturnResult, TableError> = candid::decode_one(&result).unwrap();
+    assert_eq!(result, Err(TableError::AuthorizationError));
+}
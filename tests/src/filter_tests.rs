@@ -30,6 +30,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_1 = test_env
@@ -58,6 +65,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_2 = test_env
@@ -86,6 +100,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_3 = test_env
@@ -112,6 +133,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_4 = test_env
@@ -138,6 +166,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_5 = test_env
@@ -164,6 +199,13 @@ fn join_table() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let public_table_6 = test_env
@@ -88,6 +88,7 @@ impl TestEnv {
             })),
             start_time,
             require_proof_of_humanity: false,
+            hand_for_hand_threshold: None,
         };
 
         // Create table configuration
@@ -115,6 +116,13 @@ impl TestEnv {
             is_shared_rake: None,
             require_proof_of_humanity: None,
             is_paused: None,
+            straddle_type: None,
+            time_bank: None,
+            bomb_pot: None,
+            disconnect_protection: None,
+            buy_in: None,
+            commit_reveal_shuffle: None,
+            keep_mucked_cards_private: None,
         };
 
         // Create the tournament and return its ID
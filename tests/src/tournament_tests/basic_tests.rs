@@ -69,6 +69,7 @@ fn create_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -94,6 +95,13 @@ fn create_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
@@ -124,6 +132,7 @@ fn join_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -149,6 +158,13 @@ fn join_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
@@ -191,6 +207,7 @@ fn duplicate_join_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -216,6 +233,13 @@ fn duplicate_join_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
@@ -265,6 +289,7 @@ fn invalid_join_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -290,6 +315,13 @@ fn invalid_join_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
@@ -331,6 +363,7 @@ fn leave_tournament() {
         )),
         start_time: u64::MAX,
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
     let table_config = TableConfig {
         name: "Test Table".to_string(),
@@ -356,6 +389,13 @@ fn leave_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
@@ -413,6 +453,7 @@ fn start_tournament() {
         )),
         start_time: current_time + 1_000_000_000, // 1 second in future
         require_proof_of_humanity: false,
+        hand_for_hand_threshold: None,
     };
 
     let table_config = TableConfig {
@@ -439,6 +480,13 @@ fn start_tournament() {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     };
 
     let id = test_env
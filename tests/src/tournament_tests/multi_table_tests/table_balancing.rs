@@ -56,6 +56,7 @@ impl TestEnv {
                 ),
             )),
             start_time: current_time + 1_000_000_000, // 1 second in future
+            hand_for_hand_threshold: None,
         };
 
         let table_config = TableConfig {
@@ -82,6 +83,13 @@ impl TestEnv {
             is_shared_rake: None,
             require_proof_of_humanity: None,
             is_paused: None,
+            straddle_type: None,
+            time_bank: None,
+            bomb_pot: None,
+            disconnect_protection: None,
+            buy_in: None,
+            commit_reveal_shuffle: None,
+            keep_mucked_cards_private: None,
         };
 
         let id = self
@@ -82,6 +82,7 @@ fn create_test_tournament_config() -> NewTournament {
         ),
         require_proof_of_humanity: false,
         min_players: 2,
+        hand_for_hand_threshold: None,
     }
 }
 
@@ -108,6 +109,13 @@ fn create_test_table_config() -> TableConfig {
         is_shared_rake: None,
         require_proof_of_humanity: None,
         is_paused: None,
+        straddle_type: None,
+        time_bank: None,
+        bomb_pot: None,
+        disconnect_protection: None,
+        buy_in: None,
+        commit_reveal_shuffle: None,
+        keep_mucked_cards_private: None,
     }
 }
 